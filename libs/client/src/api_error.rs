@@ -0,0 +1,170 @@
+use std::{collections::BTreeMap, fmt};
+
+use reqwest::StatusCode;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The key Elation uses for validation errors that do not belong to a single field.
+const NON_FIELD_ERRORS: &str = "non_field_errors";
+
+/// The key Elation uses for errors that are not about validation, e.g. `"Not found."`.
+const DETAIL: &str = "detail";
+
+/// The header carrying the id Elation assigns to each request.
+pub(crate) const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// The error body of a non-2xx response from the Elation EMR API.
+///
+/// Elation reports validation errors as a JSON object mapping each invalid field to a
+/// list of messages, with errors about the request as a whole under `non_field_errors`
+/// or `detail`. Errors on nested objects are flattened into dotted field names, e.g.
+/// `content.tests.0.code`.
+///
+/// # Example
+///
+/// ```rust
+/// use client::Error;
+///
+/// fn describe(error: &Error) {
+///     if let Error::BadRequest(body) = error {
+///         for message in body.field("patient") {
+///             println!("patient: {message}");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ApiErrorBody {
+    /// The HTTP status code of the response.
+    pub status: u16,
+
+    /// The endpoint the request was sent to.
+    pub endpoint: String,
+
+    /// The id Elation assigned to the request, if the response carried one.
+    pub request_id: Option<String>,
+
+    /// The messages for each invalid field, keyed by field name.
+    pub field_errors: BTreeMap<String, Vec<String>>,
+
+    /// The messages that do not belong to a single field.
+    pub non_field_errors: Vec<String>,
+
+    /// The response body, with PHI fields masked by the client's `Redaction`.
+    ///
+    /// Bodies that are not JSON cannot be masked field by field and are replaced entirely.
+    /// Validation messages, here and in the parsed fields, are kept as sent, except for any
+    /// PHI of the request they echo.
+    pub raw: String,
+
+    /// A human readable description of the error.
+    pub message: String,
+}
+
+impl ApiErrorBody {
+    /// Parses the body of an error response.
+    ///
+    /// Bodies that are not JSON are kept in `raw` only.
+    ///
+    /// * `status` - The status code of the response.
+    /// * `endpoint` - The endpoint the request was sent to.
+    /// * `request_id` - The value of the `X-Request-Id` response header, if any.
+    /// * `raw` - The response body.
+    /// * `message` - A human readable description of the error.
+    pub fn parse(
+        status: StatusCode,
+        endpoint: &str,
+        request_id: Option<String>,
+        raw: String,
+        message: String,
+    ) -> Self {
+        let mut body = Self {
+            status: status.as_u16(),
+            endpoint: endpoint.to_owned(),
+            request_id,
+            message,
+            ..Default::default()
+        };
+
+        match serde_json::from_str::<Value>(&raw) {
+            Ok(Value::Object(fields)) => {
+                for (name, value) in fields {
+                    match name.as_str() {
+                        NON_FIELD_ERRORS | DETAIL => body.non_field_errors.extend(messages(&value)),
+                        _ => body.collect_field(name, &value),
+                    }
+                }
+            }
+            Ok(value @ (Value::Array(_) | Value::String(_))) => {
+                body.non_field_errors = messages(&value);
+            }
+            _ => {}
+        }
+
+        body.raw = raw;
+        body
+    }
+
+    /// Creates a body for an error that was detected before any response was received.
+    pub(crate) fn local(status: StatusCode, endpoint: &str, message: impl Into<String>) -> Self {
+        Self {
+            status: status.as_u16(),
+            endpoint: endpoint.to_owned(),
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the messages for `field`, or an empty slice if the field is valid.
+    pub fn field(&self, field: &str) -> &[String] {
+        self.field_errors.get(field).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns whether the response reported any field or non-field errors.
+    pub fn has_errors(&self) -> bool {
+        !self.field_errors.is_empty() || !self.non_field_errors.is_empty()
+    }
+
+    fn collect_field(&mut self, name: String, value: &Value) {
+        match value {
+            Value::Object(fields) => {
+                for (child, value) in fields {
+                    self.collect_field(format!("{name}.{child}"), value);
+                }
+            }
+            Value::Array(items) if items.iter().any(|item| item.is_object()) => {
+                for (index, item) in items.iter().enumerate() {
+                    match item {
+                        Value::Object(_) => self.collect_field(format!("{name}.{index}"), item),
+                        _ => self
+                            .field_errors
+                            .entry(name.clone())
+                            .or_default()
+                            .extend(messages(item)),
+                    }
+                }
+            }
+            _ => self
+                .field_errors
+                .entry(name)
+                .or_default()
+                .extend(messages(value)),
+        }
+    }
+}
+
+impl fmt::Display for ApiErrorBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Flattens a JSON error value into its messages.
+fn messages(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(message) => vec![message.clone()],
+        Value::Array(items) => items.iter().flat_map(messages).collect(),
+        Value::Null => vec![],
+        Value::Object(_) | Value::Bool(_) | Value::Number(_) => vec![value.to_string()],
+    }
+}
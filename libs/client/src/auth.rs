@@ -0,0 +1,316 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::sync::RwLock;
+use tokio_retry::{strategy::ExponentialBackoff, Retry};
+use url::Url;
+
+use crate::Result;
+
+/// How long before its expiry a cached token is considered stale and refreshed.
+///
+/// Tokens that live less than twice as long are refreshed halfway through their lifetime
+/// instead, so that a short-lived token is still reused for a while.
+const DEFAULT_REFRESH_BEFORE: Duration = Duration::from_secs(60);
+
+/// How long a token from the token service is trusted, matching its 30 minute refresh schedule.
+const DEFAULT_TOKEN_SERVICE_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// A source of bearer tokens for authenticating requests to the Elation EMR API.
+///
+/// The `Client` asks its provider for a token before every request, so implementations
+/// are expected to cache tokens rather than fetch a fresh one each time. When the API
+/// rejects a token with `401 Unauthorized`, the `Client` calls [`TokenProvider::invalidate`]
+/// with that token and retries the request once with whatever token the provider hands
+/// out next.
+///
+/// # Example
+///
+/// ```rust
+/// use client::{async_trait, Result, TokenProvider};
+///
+/// #[derive(Debug)]
+/// struct VaultToken;
+///
+/// #[async_trait]
+/// impl TokenProvider for VaultToken {
+///     async fn token(&self) -> Result<String> {
+///         Ok("token-from-vault".to_owned())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait TokenProvider: Debug + Send + Sync {
+    /// Returns a bearer token to attach to the next request.
+    async fn token(&self) -> Result<String>;
+
+    /// Discards `token`, which the API rejected, so that the next call to `token` obtains
+    /// a new one.
+    ///
+    /// Several requests may be rejected with the same token at once, so implementations
+    /// should keep a token that was refreshed in the meantime rather than discard it too.
+    /// The default implementation does nothing.
+    async fn invalidate(&self, _token: &str) {}
+}
+
+/// A `TokenProvider` that always returns the same token.
+///
+/// Useful for tests and for tokens managed entirely outside of the SDK.
+#[derive(Debug, Clone)]
+pub struct StaticToken(String);
+
+impl StaticToken {
+    /// Creates a provider that always hands out `token`.
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+#[async_trait]
+impl TokenProvider for StaticToken {
+    async fn token(&self) -> Result<String> {
+        Ok(self.0.clone())
+    }
+}
+
+/// A token together with the instant after which it should be refreshed.
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: String,
+    refresh_at: Option<Instant>,
+}
+
+impl CachedToken {
+    /// Caches `token`, which expires after `ttl` if it expires at all.
+    fn new(token: String, ttl: Option<Duration>) -> Self {
+        let refresh_at = ttl.map(|ttl| {
+            let refresh_before = DEFAULT_REFRESH_BEFORE.min(ttl / 2);
+            Instant::now() + (ttl - refresh_before)
+        });
+        Self { token, refresh_at }
+    }
+
+    fn is_fresh(&self) -> bool {
+        self.refresh_at
+            .is_none_or(|refresh_at| Instant::now() < refresh_at)
+    }
+}
+
+/// A token cache shared by the refreshing providers.
+///
+/// Only one refresh runs at a time; concurrent callers wait for it and reuse its result.
+#[derive(Debug)]
+struct TokenCache {
+    cached: RwLock<Option<CachedToken>>,
+}
+
+impl TokenCache {
+    fn new() -> Self {
+        Self {
+            cached: RwLock::new(None),
+        }
+    }
+
+    async fn get_or_refresh<F, Fut>(&self, fetch: F) -> Result<String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<CachedToken>>,
+    {
+        if let Some(cached) = self.cached.read().await.as_ref() {
+            if cached.is_fresh() {
+                return Ok(cached.token.clone());
+            }
+        }
+
+        let mut cached = self.cached.write().await;
+        if let Some(cached) = cached.as_ref() {
+            if cached.is_fresh() {
+                return Ok(cached.token.clone());
+            }
+        }
+
+        let fresh = fetch().await?;
+        let token = fresh.token.clone();
+        *cached = Some(fresh);
+        Ok(token)
+    }
+
+    /// Discards the cached token, unless it was already replaced by one other than `token`.
+    async fn clear(&self, token: &str) {
+        let mut cached = self.cached.write().await;
+        if cached.as_ref().is_some_and(|cached| cached.token == token) {
+            *cached = None;
+        }
+    }
+}
+
+/// A `TokenProvider` backed by the `token-service` HTTP endpoint.
+///
+/// The token is fetched from `{url}token` on first use and cached for its TTL
+/// (30 minutes by default). It is refreshed shortly before the TTL runs out, or
+/// immediately after the API rejects it.
+#[derive(Debug)]
+pub struct TokenServiceProvider {
+    url: Url,
+    http: reqwest::Client,
+    ttl: Duration,
+    cache: TokenCache,
+}
+
+impl TokenServiceProvider {
+    /// Creates a provider that fetches tokens from the token service at `url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `url` cannot be parsed.
+    pub fn new(url: &str) -> Result<Self> {
+        Ok(Self {
+            url: Url::parse(url)?,
+            http: reqwest::Client::new(),
+            ttl: DEFAULT_TOKEN_SERVICE_TTL,
+            cache: TokenCache::new(),
+        })
+    }
+
+    /// Sets how long a fetched token is trusted before it is refreshed.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Retrieves an access token from the token service.
+    ///
+    /// This function uses an exponential backoff retry strategy to handle transient errors.
+    async fn fetch(&self) -> Result<CachedToken> {
+        let retry_strategy = ExponentialBackoff::from_millis(10).take(3);
+
+        let response = Retry::start(retry_strategy, || async {
+            self.http.get(format!("{}token", self.url)).send().await
+        })
+        .await?
+        .error_for_status()?;
+
+        Ok(CachedToken::new(response.text().await?, Some(self.ttl)))
+    }
+}
+
+#[async_trait]
+impl TokenProvider for TokenServiceProvider {
+    async fn token(&self) -> Result<String> {
+        self.cache.get_or_refresh(|| self.fetch()).await
+    }
+
+    async fn invalidate(&self, token: &str) {
+        self.cache.clear(token).await;
+    }
+}
+
+/// The body returned by Elation's `/oauth2/token/` endpoint.
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+/// A `TokenProvider` that performs the OAuth2 client-credentials grant directly
+/// against Elation's `/oauth2/token/` endpoint, without a separate token service.
+///
+/// Tokens are cached until shortly before the `expires_in` returned with them,
+/// and refreshed immediately after the API rejects one.
+///
+/// # Example
+///
+/// ```rust
+/// use client::{Client, ClientCredentialsProvider};
+///
+/// let provider = ClientCredentialsProvider::new(
+///     "https://sandbox.elationemr.com/api/2.0/oauth2/token/",
+///     "my-client-id",
+///     "my-client-secret",
+/// )
+/// .unwrap();
+///
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token_provider(provider)
+///     .build()
+///     .unwrap();
+/// ```
+pub struct ClientCredentialsProvider {
+    token_url: Url,
+    client_id: String,
+    client_secret: String,
+    http: reqwest::Client,
+    cache: TokenCache,
+}
+
+impl Debug for ClientCredentialsProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientCredentialsProvider")
+            .field("token_url", &self.token_url.as_str())
+            .field("client_id", &self.client_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClientCredentialsProvider {
+    /// Creates a provider that exchanges `client_id` and `client_secret` for tokens at `token_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `token_url` cannot be parsed.
+    pub fn new(
+        token_url: &str,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Result<Self> {
+        Ok(Self {
+            token_url: Url::parse(token_url)?,
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            http: reqwest::Client::new(),
+            cache: TokenCache::new(),
+        })
+    }
+
+    /// Requests a new access token using the client-credentials grant.
+    async fn fetch(&self) -> Result<CachedToken> {
+        let mut params = HashMap::new();
+
+        params.insert("grant_type", "client_credentials");
+        params.insert("client_id", &self.client_id);
+        params.insert("client_secret", &self.client_secret);
+
+        let response = self
+            .http
+            .post(self.token_url.clone())
+            .form(&params)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<TokenResponse>()
+            .await?;
+
+        Ok(CachedToken::new(
+            response.access_token,
+            response.expires_in.map(Duration::from_secs),
+        ))
+    }
+}
+
+#[async_trait]
+impl TokenProvider for ClientCredentialsProvider {
+    async fn token(&self) -> Result<String> {
+        self.cache.get_or_refresh(|| self.fetch()).await
+    }
+
+    async fn invalidate(&self, token: &str) {
+        self.cache.clear(token).await;
+    }
+}
@@ -0,0 +1,252 @@
+//! A synchronous facade over [`Client`](crate::Client) for scripts and batch jobs without a
+//! tokio runtime of their own.
+//!
+//! Enabled by the `blocking` feature. A blocking [`Client`] owns a single-threaded runtime
+//! and drives the async client on it, so every request still goes through the same
+//! authorization, rate limiting, retries, caching, error mapping and tracing. Its methods must
+//! not be called from within an async context, where they would panic.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use client::blocking;
+//!
+//! # fn run() -> client::Result<()> {
+//! let client = client::Client::builder()
+//!     .base_url("https://sandbox.elationemr.com/api/2.0/")
+//!     .token("my-access-token")
+//!     .build_blocking()?;
+//!
+//! let patient: serde_json::Value = client.get("/patients/7/", ())?;
+//! println!("{}", patient["first_name"]);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, future::Future, sync::Arc};
+
+use futures::StreamExt;
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use tokio::runtime::Runtime;
+
+use crate::{PageStream, Params, RequestOptions, Result};
+
+/// A synchronous client for the Elation EMR API.
+///
+/// Clones share the runtime and the state of the async client, such as its rate limiter and cache.
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Creates a blocking client configured from the environment, like [`crate::Client::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime cannot be started, no access token can be obtained, or
+    /// the `reqwest::Client` cannot be built.
+    pub fn new() -> Result<Self> {
+        let runtime = Self::runtime()?;
+        let inner = runtime.block_on(crate::Client::new())?;
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Wraps an async client built with [`crate::Client::builder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime cannot be started.
+    pub fn from_async(inner: crate::Client) -> Result<Self> {
+        Ok(Self {
+            inner,
+            runtime: Arc::new(Self::runtime()?),
+        })
+    }
+
+    fn runtime() -> Result<Runtime> {
+        Ok(tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?)
+    }
+
+    /// Returns the async client this client drives, e.g. to build services with it.
+    pub fn as_async(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs `future` to completion on the client's runtime.
+    ///
+    /// This is what every method of the blocking client does; use it for async APIs the
+    /// facade does not cover.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Sends a GET request and deserializes the response, like [`crate::Client::get`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be deserialized into `R`.
+    pub fn get<P: Params, R: DeserializeOwned>(&self, endpoint: &str, params: P) -> Result<R> {
+        self.request(Method::GET, endpoint, params, None::<&()>)
+    }
+
+    /// Sends a POST request and deserializes the response, like [`crate::Client::post`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be deserialized into `R`.
+    pub fn post<B, R>(&self, endpoint: &str, body: &B) -> Result<R>
+    where
+        B: Serialize + Debug,
+        R: DeserializeOwned,
+    {
+        self.request(Method::POST, endpoint, (), Some(body))
+    }
+
+    /// Sends a PUT request and deserializes the response, like [`crate::Client::put`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be deserialized into `R`.
+    pub fn put<B, R>(&self, endpoint: &str, body: &B) -> Result<R>
+    where
+        B: Serialize + Debug,
+        R: DeserializeOwned,
+    {
+        self.request(Method::PUT, endpoint, (), Some(body))
+    }
+
+    /// Sends a PATCH request and deserializes the response, like [`crate::Client::patch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be deserialized into `R`.
+    pub fn patch<B, R>(&self, endpoint: &str, body: &B) -> Result<R>
+    where
+        B: Serialize + Debug,
+        R: DeserializeOwned,
+    {
+        self.request(Method::PATCH, endpoint, (), Some(body))
+    }
+
+    /// Sends a DELETE request, like [`crate::Client::delete`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub fn delete(&self, endpoint: &str) -> Result<()> {
+        self.block_on(self.inner.delete(endpoint)).map(drop)
+    }
+
+    /// Sends a request and deserializes the response, like [`crate::Client::request`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be deserialized into `R`.
+    pub fn request<Q, B, R>(
+        &self,
+        method: Method,
+        path: &str,
+        query: Q,
+        body: Option<&B>,
+    ) -> Result<R>
+    where
+        Q: Params,
+        B: Serialize + Debug,
+        R: DeserializeOwned,
+    {
+        self.block_on(self.inner.request(method, path, query, body))
+    }
+
+    /// Sends a request like [`Client::request`], applying `options` to this call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, is cancelled, or misses its deadline, or if
+    /// the response cannot be deserialized into `R`.
+    pub fn request_with<Q, B, R>(
+        &self,
+        method: Method,
+        path: &str,
+        query: Q,
+        body: Option<&B>,
+        options: &RequestOptions,
+    ) -> Result<R>
+    where
+        Q: Params,
+        B: Serialize + Debug,
+        R: DeserializeOwned,
+    {
+        self.block_on(self.inner.request_with(method, path, query, body, options))
+    }
+
+    /// Sends a request and returns the response as untyped JSON, like [`crate::Client::request_value`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is not JSON.
+    pub fn request_value<Q, B>(
+        &self,
+        method: Method,
+        path: &str,
+        query: Q,
+        body: Option<&B>,
+    ) -> Result<Value>
+    where
+        Q: Params,
+        B: Serialize + Debug,
+    {
+        self.request(method, path, query, body)
+    }
+
+    /// Fetches every item of a paginated resource, like [`crate::Client::get_all_pages`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a page cannot be fetched or deserialized.
+    pub fn get_all_pages<T: DeserializeOwned + Debug>(&self, endpoint: &str) -> Result<Vec<T>> {
+        self.block_on(self.inner.get_all_pages(endpoint))
+    }
+
+    /// Iterates over every item of a paginated resource, fetching one page at a time.
+    pub fn stream_pages<T>(&self, endpoint: &str) -> Iter<'static, T>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        self.iter(self.inner.stream_pages(endpoint))
+    }
+
+    /// Turns a `PageStream` into an iterator that fetches pages on the client's runtime.
+    pub fn iter<'a, T, E>(&self, stream: PageStream<'a, T, E>) -> Iter<'a, T, E> {
+        Iter {
+            stream,
+            runtime: Arc::clone(&self.runtime),
+        }
+    }
+}
+
+/// An `Iterator` over every item of a paginated result set, see [`Client::iter`].
+pub struct Iter<'a, T, E = crate::Error> {
+    stream: PageStream<'a, T, E>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T, E> Iterator for Iter<'_, T, E>
+where
+    T: DeserializeOwned + Send + 'static,
+    E: From<crate::Error>,
+{
+    type Item = core::result::Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
@@ -0,0 +1,266 @@
+use std::{sync::Arc, time::Duration};
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE},
+    Certificate, Proxy,
+};
+use url::Url;
+
+use crate::{
+    CircuitBreaker, CircuitBreakerConfig, Client, Error, FaultInjector, Metrics, Middleware,
+    RateLimit, RateLimiter, Redaction, ReqwestTransport, ResponseCache, Result, RetryPolicy,
+    StaticToken, TokenProvider, Transport,
+};
+
+/// A builder for configuring a `Client`.
+///
+/// Every setting is held by the builder itself, so several differently-configured
+/// clients can live side by side in one process without touching environment variables.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use client::Client;
+///
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token("my-access-token")
+///     .timeout(Duration::from_secs(30))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct ClientBuilder {
+    base_url: Option<String>,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxies: Vec<Proxy>,
+    root_certificates: Vec<Certificate>,
+    default_headers: HeaderMap,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    practice: Option<i64>,
+    retry_policy: Option<RetryPolicy>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    redaction: Option<Redaction>,
+    cache: Option<ResponseCache>,
+    transport: Option<Arc<dyn Transport>>,
+    faults: Option<FaultInjector>,
+    metrics: Option<Metrics>,
+}
+
+impl ClientBuilder {
+    /// Creates a new `ClientBuilder` with no base URL and no token provider.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the base URL of the Elation EMR API, e.g. `https://sandbox.elationemr.com/api/2.0/`.
+    ///
+    /// Resource endpoints are appended to the path of this URL.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the `TokenProvider` used to authenticate every request.
+    pub fn token_provider(mut self, provider: impl TokenProvider + 'static) -> Self {
+        self.token_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Authenticates every request with a fixed bearer token.
+    ///
+    /// This is shorthand for `token_provider(StaticToken::new(token))`.
+    pub fn token(self, token: impl Into<String>) -> Self {
+        self.token_provider(StaticToken::new(token))
+    }
+
+    /// Sets a timeout for the whole request, from connecting until the response body is read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a timeout for the connect phase only.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Routes requests through `proxy`. May be called more than once.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Trusts an additional root certificate, e.g. for a corporate TLS-intercepting proxy.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Adds a header that is sent with every request.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// Throttles requests with a new rate limiter owned by this client.
+    pub fn rate_limit(self, config: RateLimit) -> Self {
+        self.rate_limiter(Arc::new(RateLimiter::new(config)))
+    }
+
+    /// Throttles requests with an existing rate limiter, which may be shared with other clients.
+    pub fn rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Fails requests fast with a new circuit breaker owned by this client while the API is down.
+    pub fn circuit_breaker(self, config: CircuitBreakerConfig) -> Self {
+        self.shared_circuit_breaker(Arc::new(CircuitBreaker::new(config)))
+    }
+
+    /// Fails requests fast with an existing circuit breaker, which may be shared with other clients.
+    pub fn shared_circuit_breaker(mut self, breaker: Arc<CircuitBreaker>) -> Self {
+        self.circuit_breaker = Some(breaker);
+        self
+    }
+
+    /// Sets the practice this client acts for.
+    ///
+    /// With a per-practice `RateLimit`, clients for different practices draw from separate buckets.
+    pub fn practice(mut self, practice: i64) -> Self {
+        self.practice = Some(practice);
+        self
+    }
+
+    /// Retries failed requests according to `policy`.
+    ///
+    /// Without a policy, every request is attempted exactly once.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Adds `middleware` to run around every request. May be called more than once.
+    ///
+    /// `before_request` hooks run in the order the middleware was added,
+    /// `after_response` hooks in reverse order.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Controls which request and response data may appear in `tracing` output.
+    ///
+    /// Without this, bodies are never emitted and the default PHI fields are masked in error messages.
+    pub fn redaction(mut self, redaction: Redaction) -> Self {
+        self.redaction = Some(redaction);
+        self
+    }
+
+    /// Serves `GET` requests for the resources configured in `cache` from it.
+    ///
+    /// Without a cache, every request is sent to the API.
+    pub fn cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Records request counts, latencies, retries, rate limit waits and cache hits in `metrics`.
+    ///
+    /// Without a registry, no metrics are kept.
+    pub fn metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Sends requests through `transport` instead of over HTTP with `reqwest`.
+    ///
+    /// Timeouts, proxies and root certificates only apply to the default `ReqwestTransport`.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Injects faults into requests according to the rules of `faults`, for resilience testing.
+    ///
+    /// Faults are injected in front of the transport, so they go through retries, rate
+    /// limiting and middleware like real failures do.
+    pub fn faults(mut self, faults: FaultInjector) -> Self {
+        self.faults = Some(faults);
+        self
+    }
+
+    /// Builds the `Client`.
+    ///
+    /// No network calls are made here; the token provider is first consulted when
+    /// the first request is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the base URL or token provider is missing, the base URL
+    /// cannot be parsed, or the underlying `reqwest::Client` cannot be built.
+    pub fn build(self) -> Result<Client> {
+        let base_url = Url::parse(&self.base_url.ok_or(Error::MissingBaseUrl)?)?;
+        let token_provider = self.token_provider.ok_or(Error::MissingTokenProvider)?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        headers.extend(self.default_headers);
+
+        let mut http = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        for proxy in self.proxies {
+            http = http.proxy(proxy);
+        }
+        for certificate in self.root_certificates {
+            http = http.add_root_certificate(certificate);
+        }
+
+        let http = http.build()?;
+        let transport = self
+            .transport
+            .unwrap_or_else(|| Arc::new(ReqwestTransport::new(http.clone())));
+        let transport = match self.faults {
+            Some(faults) => faults.wrap(transport),
+            None => transport,
+        };
+
+        Ok(Client {
+            client: http,
+            transport,
+            default_headers: headers,
+            base_url,
+            token_provider,
+            rate_limiter: self.rate_limiter,
+            circuit_breaker: self.circuit_breaker,
+            practice: self.practice,
+            retry_policy: self.retry_policy.unwrap_or_else(RetryPolicy::none),
+            middleware: self.middleware,
+            redaction: self.redaction.unwrap_or_default(),
+            cache: self.cache,
+            metrics: self.metrics,
+        })
+    }
+
+    /// Builds a synchronous [`blocking::Client`](crate::blocking::Client) with this configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `build` fails or the client's runtime cannot be started.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::Client> {
+        crate::blocking::Client::from_async(self.build()?)
+    }
+}
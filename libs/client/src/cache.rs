@@ -0,0 +1,293 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use reqwest::{header::CONTENT_TYPE, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+
+/// The number of responses kept in memory unless `ResponseCache::capacity` says otherwise.
+const DEFAULT_CAPACITY: usize = 1_000;
+
+/// An opt-in cache for `GET` responses of resources that rarely change.
+///
+/// Only resources registered with [`ResponseCache::ttl`] are cached. A cached response is
+/// served without contacting the API until its time to live has passed; after that, it is
+/// revalidated with `If-None-Match` when the API sent an `ETag` for it, and refetched otherwise.
+/// A successful `POST`, `PUT`, `PATCH` or `DELETE` through the client invalidates every cached
+/// response of the resource it targeted.
+///
+/// Responses are held in memory, evicting the least recently used one once `capacity` is
+/// reached, and are optionally written to a directory so they survive a restart.
+///
+/// Responses are keyed by their full URL. Clones share their entries, so a cache shared by
+/// clients acting for different practices must only hold data all of them may see.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use client::{Client, ResponseCache};
+///
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token("my-access-token")
+///     .cache(
+///         ResponseCache::new()
+///             .capacity(500)
+///             .ttl("/lab_vendors", Duration::from_secs(24 * 60 * 60))
+///             .ttl("/appointment_types", Duration::from_secs(60 * 60)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    ttls: HashMap<String, Duration>,
+    memory: Arc<Mutex<Lru>>,
+    disk: Option<PathBuf>,
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResponseCache {
+    /// Creates an in-memory cache for up to 1000 responses that caches no resource yet.
+    pub fn new() -> Self {
+        Self {
+            ttls: HashMap::new(),
+            memory: Arc::new(Mutex::new(Lru::new(DEFAULT_CAPACITY))),
+            disk: None,
+        }
+    }
+
+    /// Sets the maximum number of responses held in memory.
+    ///
+    /// This discards anything cached in memory so far.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.memory = Arc::new(Mutex::new(Lru::new(capacity)));
+        self
+    }
+
+    /// Caches the responses of `endpoint`, e.g. `"/lab_vendors"`, for `ttl`.
+    ///
+    /// This covers both single resources (`/lab_vendors/1/`) and searches (`/lab_vendors/?...`).
+    pub fn ttl(mut self, endpoint: &str, ttl: Duration) -> Self {
+        self.ttls.insert(resource_name(endpoint).to_owned(), ttl);
+        self
+    }
+
+    /// Additionally stores responses as files in `dir`, which is created if needed.
+    ///
+    /// Responses missing from memory are looked up there before the API is contacted.
+    /// Failing to read or write a file is not an error; the response is simply not cached.
+    pub fn on_disk(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.disk = Some(dir.into());
+        self
+    }
+
+    /// Returns the number of responses held in memory.
+    pub fn len(&self) -> usize {
+        self.memory().entries.len()
+    }
+
+    /// Returns whether no response is held in memory.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Discards every cached response of `endpoint`, e.g. `"/lab_vendors"`.
+    pub fn invalidate(&self, endpoint: &str) {
+        let resource = resource_name(endpoint);
+        self.memory()
+            .retain(|entry| key_resource(&entry.key) != resource);
+        self.remove_files(|name| name.starts_with(&format!("{resource}-")));
+    }
+
+    /// Discards every cached response.
+    pub fn invalidate_all(&self) {
+        self.memory().retain(|_| false);
+        self.remove_files(|_| true);
+    }
+
+    /// Returns the time to live of `resource`, or `None` if it is not cached.
+    pub(crate) fn ttl_of(&self, resource: &str) -> Option<Duration> {
+        self.ttls.get(resource).copied()
+    }
+
+    /// Looks up the response cached for `key`, in memory first and then on disk.
+    pub(crate) fn lookup(&self, key: &str) -> Option<CachedResponse> {
+        if let Some(entry) = self.memory().get(key) {
+            return Some(entry);
+        }
+
+        let entry: CachedResponse =
+            serde_json::from_slice(&fs::read(self.file(key)?).ok()?).ok()?;
+        if entry.key != key {
+            return None;
+        }
+        self.memory().insert(entry.clone());
+        Some(entry)
+    }
+
+    /// Caches `entry`, replacing whatever was cached for its key.
+    pub(crate) fn store(&self, entry: CachedResponse) {
+        if let (Some(dir), Some(file)) = (&self.disk, self.file(&entry.key)) {
+            let written = fs::create_dir_all(dir)
+                .and_then(|_| fs::write(&file, serde_json::to_vec(&entry).unwrap_or_default()));
+            if let Err(error) = written {
+                tracing::debug!(%error, "failed to write cached response");
+            }
+        }
+        self.memory().insert(entry);
+    }
+
+    fn memory(&self) -> std::sync::MutexGuard<'_, Lru> {
+        self.memory
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Returns the file a response is stored in, named after its resource and a hash of its key.
+    fn file(&self, key: &str) -> Option<PathBuf> {
+        let resource = key_resource(key);
+        self.disk
+            .as_ref()
+            .map(|dir| dir.join(format!("{resource}-{:016x}.json", fnv1a(key))))
+    }
+
+    fn remove_files(&self, matches: impl Fn(&str) -> bool) {
+        let Some(entries) = self.disk.as_ref().and_then(|dir| fs::read_dir(dir).ok()) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            if entry.file_name().to_str().is_some_and(&matches) {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+}
+
+/// A successful response as it is kept in a `ResponseCache`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedResponse {
+    /// The full URL the response was fetched from, prefixed by its resource.
+    pub key: String,
+
+    pub status: u16,
+    pub etag: Option<String>,
+    pub body: String,
+    pub stored_at: SystemTime,
+}
+
+impl CachedResponse {
+    /// Returns whether the response is younger than `ttl`.
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        self.stored_at.elapsed().is_ok_and(|age| age < ttl)
+    }
+
+    /// Builds a `Response` that reads like the one originally received.
+    pub fn to_response(&self) -> Response {
+        let mut response = http::Response::builder()
+            .status(StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK))
+            .header(CONTENT_TYPE, "application/json");
+        if let Some(etag) = &self.etag {
+            response = response.header(reqwest::header::ETAG, etag);
+        }
+        Response::from(
+            response
+                .body(self.body.clone())
+                .expect("a cached response is always valid"),
+        )
+    }
+}
+
+/// Builds the cache key of a request to `url` for `resource`.
+pub(crate) fn cache_key(resource: &str, url: &url::Url) -> String {
+    format!("{resource} {url}")
+}
+
+/// Returns the resource a path relative to the base URL belongs to, i.e. its first segment.
+pub(crate) fn resource_name(path: &str) -> &str {
+    path.trim_start_matches('/')
+        .split(['/', '?'])
+        .next()
+        .unwrap_or_default()
+}
+
+fn key_resource(key: &str) -> &str {
+    key.split(' ').next().unwrap_or_default()
+}
+
+/// A stable 64-bit FNV-1a hash, so file names stay the same across builds.
+fn fnv1a(key: &str) -> u64 {
+    key.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The in-memory part of a `ResponseCache`, evicting the least recently used entry when full.
+#[derive(Debug)]
+struct Lru {
+    capacity: usize,
+    entries: HashMap<String, (u64, CachedResponse)>,
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<CachedResponse> {
+        self.tick += 1;
+        let (used, entry) = self.entries.get_mut(key)?;
+        self.recency.remove(used);
+        self.recency.insert(self.tick, key.to_owned());
+        *used = self.tick;
+        Some(entry.clone())
+    }
+
+    fn insert(&mut self, entry: CachedResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.tick += 1;
+        let key = entry.key.clone();
+        if let Some((used, _)) = self.entries.insert(key.clone(), (self.tick, entry)) {
+            self.recency.remove(&used);
+        }
+        self.recency.insert(self.tick, key);
+
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+
+    fn retain(&mut self, keep: impl Fn(&CachedResponse) -> bool) {
+        let recency = &mut self.recency;
+        self.entries.retain(|_, (used, entry)| {
+            let kept = keep(entry);
+            if !kept {
+                recency.remove(used);
+            }
+            kept
+        });
+    }
+}
@@ -0,0 +1,401 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use reqwest::{
+    header::{HeaderMap, AUTHORIZATION, CONTENT_LENGTH, COOKIE, SET_COOKIE},
+    Method, Request, Response, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use crate::redaction::REDACTED;
+use crate::{Error, Middleware, Redaction, RequestParts, Result};
+
+/// The date PHI dates such as `dob` are replaced with.
+const PLACEHOLDER_DATE: &str = "1900-01-01";
+
+/// Headers whose values are never written to a cassette.
+const SECRET_HEADERS: &[reqwest::header::HeaderName] = &[AUTHORIZATION, COOKIE, SET_COOKIE];
+
+/// Whether a [`Cassette`] records responses or replays them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Requests are sent to the API and every interaction is written to the cassette file.
+    Record,
+
+    /// Requests are answered from the cassette file and never reach the network.
+    Replay,
+}
+
+/// Middleware that records HTTP interactions to a file and replays them in tests.
+///
+/// In [`CassetteMode::Record`], every request the client sends, e.g. against the Elation
+/// sandbox, is saved together with its response to a JSON cassette file, which is rewritten
+/// after each interaction. Auth headers are scrubbed, and every PHI field of the configured
+/// [`Redaction`] is replaced in query strings and JSON bodies: text with `[REDACTED]`, dates
+/// with `1900-01-01` and numbers with `0`. Bodies that are not JSON are saved as they are.
+///
+/// In [`CassetteMode::Replay`], requests are matched by method, path, query and body against
+/// the interactions that have not been played yet, in the order they were recorded, and the
+/// recorded response is returned without touching the network. A request without a match
+/// fails with `Error::UnmatchedRequest`. Since requests are scrubbed before being matched,
+/// a replayed client must be configured with the same `Redaction`, and with a base URL of the
+/// same path as the recording one.
+///
+/// Clones share their interactions, so a clone can be kept to inspect the cassette after
+/// the original was handed to `ClientBuilder::middleware`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use client::{Cassette, Client};
+///
+/// let cassette = Cassette::replay("tests/cassettes/lab_vendors.json").unwrap();
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token("unused-in-replay")
+///     .middleware(cassette.clone())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    redaction: Redaction,
+    tape: Arc<Mutex<Tape>>,
+}
+
+#[derive(Debug, Default)]
+struct Tape {
+    interactions: Vec<Interaction>,
+    played: Vec<bool>,
+}
+
+/// The contents of a cassette file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    query: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+impl RecordedRequest {
+    /// Returns whether both requests have the same method, path, query and body.
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method == other.method
+            && self.path == other.path
+            && self.query == other.query
+            && self.body == other.body
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+/// A body as saved in a cassette; JSON bodies are kept as JSON so cassettes stay readable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordedBody {
+    Json(Value),
+    Text(String),
+}
+
+impl Cassette {
+    /// Creates a cassette for the file at `path` in the given mode.
+    ///
+    /// # Errors
+    ///
+    /// In replay mode, returns an error if the file cannot be read or is not a cassette.
+    pub fn new(path: impl Into<PathBuf>, mode: CassetteMode) -> Result<Self> {
+        let path = path.into();
+        let interactions = match mode {
+            CassetteMode::Record => Vec::new(),
+            CassetteMode::Replay => {
+                serde_json::from_slice::<CassetteFile>(&fs::read(&path)?)?.interactions
+            }
+        };
+
+        Ok(Self {
+            path,
+            mode,
+            redaction: Redaction::default(),
+            tape: Arc::new(Mutex::new(Tape {
+                played: vec![false; interactions.len()],
+                interactions,
+            })),
+        })
+    }
+
+    /// Creates a cassette that records to the file at `path`, replacing it if it exists.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self::new(path, CassetteMode::Record).expect("starting a recording does not read the file")
+    }
+
+    /// Creates a cassette that replays the interactions recorded in the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a cassette.
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        Self::new(path, CassetteMode::Replay)
+    }
+
+    /// Sets the PHI fields scrubbed from recorded requests and responses.
+    ///
+    /// Without this, the default PHI fields of [`Redaction`] are scrubbed.
+    pub fn redaction(mut self, redaction: Redaction) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Returns the file this cassette records to or replays from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether this cassette records or replays.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Returns the number of recorded interactions that have not been replayed yet.
+    pub fn unplayed(&self) -> usize {
+        self.tape().played.iter().filter(|played| !**played).count()
+    }
+
+    fn tape(&self) -> MutexGuard<'_, Tape> {
+        self.tape
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Describes a request the way it is saved, with secrets and PHI scrubbed.
+    fn scrub_request(
+        &self,
+        method: &Method,
+        url: &Url,
+        headers: &HeaderMap,
+        body: Option<&[u8]>,
+    ) -> RecordedRequest {
+        let query = url.query().map(|_| {
+            let pairs = url.query_pairs().map(|(name, value)| {
+                let value = if self.redaction.is_phi(&name) {
+                    placeholder(&value).into()
+                } else {
+                    value
+                };
+                (name, value)
+            });
+            url::form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(pairs)
+                .finish()
+        });
+
+        RecordedRequest {
+            method: method.to_string(),
+            path: url.path().to_owned(),
+            query,
+            headers: scrub_headers(headers),
+            body: body.and_then(|body| self.scrub_body(body)),
+        }
+    }
+
+    fn scrub_body(&self, body: &[u8]) -> Option<RecordedBody> {
+        if body.is_empty() {
+            return None;
+        }
+        Some(match serde_json::from_slice::<Value>(body) {
+            Ok(mut value) => {
+                self.scrub_value(&mut value, None, false);
+                RecordedBody::Json(value)
+            }
+            Err(_) => RecordedBody::Text(String::from_utf8_lossy(body).into_owned()),
+        })
+    }
+
+    /// Replaces the PHI in `value` with placeholders of the same JSON type.
+    ///
+    /// Unlike `Redaction::mask`, this keeps dates parseable, so replayed bodies still
+    /// deserialize into the models. Everything below a PHI field is scrubbed as well.
+    fn scrub_value(&self, value: &mut Value, parent: Option<&str>, phi: bool) {
+        match value {
+            Value::Object(fields) => {
+                for (name, value) in fields {
+                    let phi = phi || self.redaction.is_phi_in(parent, name);
+                    self.scrub_value(value, Some(name), phi);
+                }
+            }
+            Value::Array(items) => items
+                .iter_mut()
+                .for_each(|item| self.scrub_value(item, parent, phi)),
+            Value::String(text) if phi => *text = placeholder(text).to_owned(),
+            Value::Number(_) if phi => *value = Value::from(0),
+            _ => {}
+        }
+    }
+
+    /// Adds an interaction and rewrites the cassette file.
+    fn save(&self, interaction: Interaction) -> Result<()> {
+        let mut tape = self.tape();
+        tape.interactions.push(interaction);
+        tape.played.push(true);
+
+        let file = CassetteFile {
+            interactions: tape.interactions.clone(),
+        };
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_vec_pretty(&file)?)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Middleware for Cassette {
+    async fn before_request(&self, request: &mut Request) -> Result<Option<Response>> {
+        if self.mode != CassetteMode::Replay {
+            return Ok(None);
+        }
+
+        let recorded = self.scrub_request(
+            request.method(),
+            request.url(),
+            request.headers(),
+            request.body().and_then(|body| body.as_bytes()),
+        );
+
+        let mut tape = self.tape();
+        let Tape {
+            interactions,
+            played,
+        } = &mut *tape;
+        let Some(index) = (0..interactions.len())
+            .find(|&index| !played[index] && interactions[index].request.matches(&recorded))
+        else {
+            return Err(Error::UnmatchedRequest {
+                method: recorded.method,
+                request: match recorded.query {
+                    Some(query) => format!("{}?{}", recorded.path, query),
+                    None => recorded.path,
+                },
+            });
+        };
+        played[index] = true;
+
+        Ok(Some(replayed_response(&interactions[index].response)))
+    }
+
+    async fn after_response(&self, request: &RequestParts, response: Response) -> Result<Response> {
+        if self.mode != CassetteMode::Record {
+            return Ok(response);
+        }
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        self.save(Interaction {
+            request: self.scrub_request(
+                &request.method,
+                &request.url,
+                &request.headers,
+                request.body.as_deref(),
+            ),
+            response: RecordedResponse {
+                status: status.as_u16(),
+                headers: scrub_headers(&headers),
+                body: self.scrub_body(&body),
+            },
+        })?;
+
+        let mut response = http::Response::new(body);
+        *response.status_mut() = status;
+        *response.headers_mut() = headers;
+        Ok(Response::from(response))
+    }
+}
+
+/// Returns the value PHI text is replaced with: a fixed date for dates, `[REDACTED]` otherwise.
+fn placeholder(text: &str) -> &'static str {
+    let bytes = text.as_bytes();
+    let is_date = bytes.len() == 10
+        && bytes.iter().enumerate().all(|(index, byte)| match index {
+            4 | 7 => *byte == b'-',
+            _ => byte.is_ascii_digit(),
+        });
+    if is_date {
+        PLACEHOLDER_DATE
+    } else {
+        REDACTED
+    }
+}
+
+/// Returns the headers as saved in a cassette, with secret values replaced.
+fn scrub_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SECRET_HEADERS.contains(name) {
+                REDACTED.to_owned()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.as_str().to_owned(), value)
+        })
+        .collect()
+}
+
+fn replayed_response(recorded: &RecordedResponse) -> Response {
+    let body = match &recorded.body {
+        Some(RecordedBody::Json(value)) => Bytes::from(value.to_string()),
+        Some(RecordedBody::Text(text)) => Bytes::from(text.clone()),
+        None => Bytes::new(),
+    };
+
+    let mut response = http::Response::builder()
+        .status(StatusCode::from_u16(recorded.status).unwrap_or(StatusCode::OK));
+    // The length of a scrubbed body differs from the one recorded
+    for (name, value) in recorded
+        .headers
+        .iter()
+        .filter(|(name, _)| *name != CONTENT_LENGTH.as_str())
+    {
+        response = response.header(name, value);
+    }
+    Response::from(
+        response
+            .body(body)
+            .unwrap_or_else(|_| http::Response::new(Bytes::new())),
+    )
+}
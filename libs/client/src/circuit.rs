@@ -0,0 +1,381 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use reqwest::Response;
+
+use crate::{Error, Result};
+
+/// Configuration for the client-side circuit breaker.
+///
+/// The breaker watches the outcome of every request sent in the last `window`. Once at least
+/// `minimum_requests` were sent and the share of them that failed with a 5xx status, a
+/// timeout or a connection error reaches `failure_rate`, the breaker opens: for `open_for`,
+/// requests fail immediately with `Error::CircuitOpen` instead of reaching the API. After
+/// that, the breaker half-opens and lets up to `probes` requests through. If all of them
+/// succeed it closes again; if one fails it opens for another `open_for`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use client::{CircuitBreakerConfig, Client};
+///
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token("my-access-token")
+///     .circuit_breaker(CircuitBreakerConfig {
+///         failure_rate: 0.5,
+///         minimum_requests: 20,
+///         open_for: Duration::from_secs(60),
+///         ..Default::default()
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// The share of failed requests, between 0 and 1, at which the breaker opens.
+    pub failure_rate: f64,
+
+    /// The number of requests the window must hold before the failure rate is considered.
+    pub minimum_requests: u32,
+
+    /// How far back the failure rate looks.
+    pub window: Duration,
+
+    /// How long the breaker stays open before letting probe requests through.
+    pub open_for: Duration,
+
+    /// The number of probe requests that must succeed, while half-open, to close the breaker.
+    ///
+    /// At least one probe is always let through, so 0 is treated as 1.
+    pub probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_rate: 0.5,
+            minimum_requests: 10,
+            window: Duration::from_secs(60),
+            open_for: Duration::from_secs(30),
+            probes: 1,
+        }
+    }
+}
+
+/// The state of a circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent, and their outcomes counted.
+    Closed,
+
+    /// Requests fail fast with `Error::CircuitOpen`.
+    Open,
+
+    /// A limited number of probe requests are sent to find out whether the API has recovered.
+    HalfOpen,
+}
+
+/// A snapshot of a circuit breaker, e.g. for a health check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitStats {
+    /// The current state.
+    pub state: CircuitState,
+
+    /// The number of requests in the current window.
+    pub requests: u32,
+
+    /// The number of those requests that failed.
+    pub failures: u32,
+
+    /// How long the breaker stays open, if it is open.
+    pub open_for: Option<Duration>,
+
+    /// The number of times the breaker has opened.
+    pub times_opened: u64,
+
+    /// The number of requests that failed fast because the breaker was open.
+    pub rejected_requests: u64,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { outcomes: VecDeque<(Instant, bool)> },
+    Open { until: Instant },
+    HalfOpen { in_flight: u32, successes: u32 },
+}
+
+#[derive(Debug)]
+struct Inner {
+    state: State,
+    generation: u64,
+    times_opened: u64,
+    rejected_requests: u64,
+}
+
+/// A circuit breaker that can be shared between clients.
+///
+/// Create one with [`CircuitBreaker::new`] and hand it to several clients through
+/// `ClientBuilder::shared_circuit_breaker` to make them trip together.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    /// Creates a new, closed breaker with the given configuration.
+    pub fn new(mut config: CircuitBreakerConfig) -> Self {
+        // Without a probe, a half-open breaker would never close again
+        config.probes = config.probes.max(1);
+        Self {
+            config,
+            inner: Mutex::new(Inner {
+                state: State::Closed {
+                    outcomes: VecDeque::new(),
+                },
+                generation: 0,
+                times_opened: 0,
+                rejected_requests: 0,
+            }),
+        }
+    }
+
+    /// Returns the breaker's configuration.
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// Returns the current state of the breaker.
+    pub fn state(&self) -> CircuitState {
+        self.stats().state
+    }
+
+    /// Returns the current state of the breaker together with its counters.
+    pub fn stats(&self) -> CircuitStats {
+        let mut inner = self.inner();
+        self.refresh(&mut inner);
+
+        let (state, requests, failures, open_for) = match &inner.state {
+            State::Closed { outcomes } => (
+                CircuitState::Closed,
+                outcomes.len() as u32,
+                outcomes.iter().filter(|(_, failed)| *failed).count() as u32,
+                None,
+            ),
+            State::Open { until } => (
+                CircuitState::Open,
+                0,
+                0,
+                Some(until.saturating_duration_since(Instant::now())),
+            ),
+            State::HalfOpen { .. } => (CircuitState::HalfOpen, 0, 0, None),
+        };
+        CircuitStats {
+            state,
+            requests,
+            failures,
+            open_for,
+            times_opened: inner.times_opened,
+            rejected_requests: inner.rejected_requests,
+        }
+    }
+
+    /// Closes the breaker and forgets every outcome, e.g. after the API was fixed manually.
+    pub fn reset(&self) {
+        let mut inner = self.inner();
+        Self::transition(
+            &mut inner,
+            State::Closed {
+                outcomes: VecDeque::new(),
+            },
+        );
+    }
+
+    /// Asks the breaker to let a request through.
+    ///
+    /// The returned permit must be given the request's outcome with `CircuitPermit::record`.
+    pub(crate) fn acquire(self: &Arc<Self>) -> Result<CircuitPermit> {
+        let mut inner = self.inner();
+        self.refresh(&mut inner);
+
+        let probe = match &mut inner.state {
+            State::Closed { .. } => false,
+            State::Open { until } => {
+                let retry_after = until.saturating_duration_since(Instant::now());
+                inner.rejected_requests += 1;
+                return Err(Error::CircuitOpen { retry_after });
+            }
+            State::HalfOpen {
+                in_flight,
+                successes,
+            } => {
+                if *in_flight + *successes >= self.config.probes {
+                    inner.rejected_requests += 1;
+                    return Err(Error::CircuitOpen {
+                        retry_after: Duration::ZERO,
+                    });
+                }
+                *in_flight += 1;
+                true
+            }
+        };
+
+        Ok(CircuitPermit {
+            breaker: Arc::clone(self),
+            generation: inner.generation,
+            probe,
+            recorded: false,
+        })
+    }
+
+    /// Moves an open breaker whose time is up to half-open.
+    fn refresh(&self, inner: &mut Inner) {
+        if let State::Open { until } = inner.state {
+            if until <= Instant::now() {
+                tracing::info!("circuit breaker half-open, sending probe requests");
+                Self::transition(
+                    inner,
+                    State::HalfOpen {
+                        in_flight: 0,
+                        successes: 0,
+                    },
+                );
+            }
+        }
+    }
+
+    fn record(&self, generation: u64, probe: bool, failed: bool) {
+        let mut inner = self.inner();
+        if inner.generation != generation {
+            // The outcome belongs to a state the breaker has already left
+            return;
+        }
+
+        let now = Instant::now();
+        let next = match &mut inner.state {
+            State::Closed { outcomes } => {
+                outcomes.push_back((now, failed));
+                while outcomes
+                    .front()
+                    .is_some_and(|(at, _)| now.duration_since(*at) > self.config.window)
+                {
+                    outcomes.pop_front();
+                }
+                let requests = outcomes.len() as u32;
+                let failures = outcomes.iter().filter(|(_, failed)| *failed).count() as u32;
+                let tripped = failed
+                    && requests >= self.config.minimum_requests.max(1)
+                    && f64::from(failures) / f64::from(requests) >= self.config.failure_rate;
+                tripped.then_some(CircuitState::Open)
+            }
+            State::HalfOpen {
+                in_flight,
+                successes,
+            } if probe => {
+                *in_flight -= 1;
+                *successes += u32::from(!failed);
+                if failed {
+                    Some(CircuitState::Open)
+                } else {
+                    (*successes >= self.config.probes).then_some(CircuitState::Closed)
+                }
+            }
+            _ => None,
+        };
+
+        match next {
+            Some(CircuitState::Open) => {
+                tracing::warn!(
+                    open_for_ms = self.config.open_for.as_millis() as u64,
+                    "circuit breaker opened, failing requests fast"
+                );
+                inner.times_opened += 1;
+                Self::transition(
+                    &mut inner,
+                    State::Open {
+                        until: now + self.config.open_for,
+                    },
+                );
+            }
+            Some(CircuitState::Closed) => {
+                tracing::info!("circuit breaker closed, the API has recovered");
+                Self::transition(
+                    &mut inner,
+                    State::Closed {
+                        outcomes: VecDeque::new(),
+                    },
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn release(&self, generation: u64) {
+        let mut inner = self.inner();
+        if inner.generation != generation {
+            return;
+        }
+        if let State::HalfOpen { in_flight, .. } = &mut inner.state {
+            *in_flight -= 1;
+        }
+    }
+
+    fn transition(inner: &mut Inner, state: State) {
+        inner.state = state;
+        inner.generation += 1;
+    }
+
+    fn inner(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Permission from a `CircuitBreaker` to send one request.
+///
+/// A permit dropped without an outcome, e.g. because its call was cancelled, frees its
+/// probe slot without counting as a success or a failure.
+#[derive(Debug)]
+pub(crate) struct CircuitPermit {
+    breaker: Arc<CircuitBreaker>,
+    generation: u64,
+    probe: bool,
+    recorded: bool,
+}
+
+impl CircuitPermit {
+    /// Reports the outcome of the request to the breaker.
+    pub(crate) fn record(mut self, result: &Result<Response>) {
+        self.recorded = true;
+        let failed = result.as_ref().err().is_some_and(trips_breaker);
+        self.breaker.record(self.generation, self.probe, failed);
+    }
+}
+
+impl Drop for CircuitPermit {
+    fn drop(&mut self) {
+        if self.probe && !self.recorded {
+            self.breaker.release(self.generation);
+        }
+    }
+}
+
+/// Returns whether an error suggests the API is down: a 5xx status, a timeout or a failed connection.
+fn trips_breaker(error: &Error) -> bool {
+    match error.last_error() {
+        Error::InternalServerError(_)
+        | Error::BadGateway(_)
+        | Error::ServiceUnavailable(_)
+        | Error::GatewayTimeout(_) => true,
+        Error::ReqwestError(e) => {
+            e.is_timeout()
+                || e.is_connect()
+                || e.status().is_some_and(|status| status.is_server_error())
+        }
+        _ => false,
+    }
+}
@@ -1,22 +1,37 @@
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    sync::Arc,
+    time::{Instant, SystemTime},
+};
 
 use config::elation_config;
 
 use reqwest::{
-    header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE},
-    Response, StatusCode,
+    header::{HeaderMap, HeaderValue, AUTHORIZATION, ETAG, IF_NONE_MATCH},
+    Request, RequestBuilder, Response, StatusCode,
 };
 use serde_with::serde_as;
-use tokio_retry::{strategy::ExponentialBackoff, Retry};
+use tracing::Instrument;
 use url::Url;
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 
 use reqwest::Method;
 
 pub use crate::{Error, Result};
 
+use crate::api_error::REQUEST_ID_HEADER;
+use crate::cache::{cache_key, resource_name, CachedResponse};
 use crate::config::{self};
+use crate::middleware::RequestParts;
+use crate::rate_limit::retry_after;
+use crate::redaction::{endpoint_template, relative_path};
+use crate::{
+    ApiErrorBody, CircuitBreaker, CircuitStats, ClientBuilder, Metrics, Middleware, PageStream,
+    RateLimitStats, RateLimiter, Redaction, RequestOptions, ResponseCache, RetryPolicy,
+    TokenProvider, TokenServiceProvider, Transport,
+};
 
 /// Trait for query parameter types
 pub trait Params: Serialize + Default + Debug {}
@@ -193,15 +208,62 @@ where
     }
 }
 
+impl<T> PaginatedResponse<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    /// Turns this page into a `Stream` over its items and those of every following page.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A reference to the `Client` instance to fetch the following pages with.
+    pub fn into_stream(self, client: &Client) -> PageStream<'static, T> {
+        PageStream::new(client, futures::future::ready(Ok(self)))
+    }
+}
+
 /// A client for interacting with the Elation EMR API.
 ///
 /// The `Client` struct provides methods to perform HTTP requests
 /// to the Elation EMR API, including GET, POST, PUT, PATCH, and DELETE operations.
-/// It handles authentication by obtaining an access token from a token service
-/// and sets up default headers for requests.
-#[derive(Debug)]
+/// It authenticates each request with a token obtained from its `TokenProvider`
+/// and sends requests relative to its configured base URL.
+///
+/// Use [`Client::builder`] to configure a client explicitly, or [`Client::new`]
+/// to configure one from the environment.
+#[derive(Clone)]
 pub struct Client {
-    client: reqwest::Client,
+    pub(crate) client: reqwest::Client,
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) default_headers: HeaderMap,
+    pub(crate) base_url: Url,
+    pub(crate) token_provider: Arc<dyn TokenProvider>,
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+    pub(crate) circuit_breaker: Option<Arc<CircuitBreaker>>,
+    pub(crate) practice: Option<i64>,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) middleware: Vec<Arc<dyn Middleware>>,
+    pub(crate) redaction: Redaction,
+    pub(crate) cache: Option<ResponseCache>,
+    pub(crate) metrics: Option<Metrics>,
+}
+
+impl Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("base_url", &self.base_url.as_str())
+            .field("transport", &self.transport)
+            .field("token_provider", &self.token_provider)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("practice", &self.practice)
+            .field("retry_policy", &self.retry_policy)
+            .field("middleware", &self.middleware.len())
+            .field("redaction", &self.redaction)
+            .field("cache", &self.cache.as_ref().map(ResponseCache::len))
+            .field("metrics", &self.metrics.is_some())
+            .finish()
+    }
 }
 
 ///// Enum representing the supported HTTP methods.
@@ -218,82 +280,235 @@ pub struct Client {
 //}
 
 impl Client {
-    /// Creates a new instance of `Client`.
+    /// Creates a new instance of `Client` configured from the environment.
+    ///
+    /// When `TEST_ENV` is set, the client targets `MOCK_SERVER_URL` (defaulting to
+    /// `http://localhost:1234`) with a dummy token. Otherwise it targets
+    /// `ELATION_API_URL` and obtains its token from `TOKEN_SERVICE_URL`.
     ///
-    /// This function initializes the client by obtaining an access token
-    /// from the token service and setting up default headers.
+    /// Prefer [`Client::builder`] when several differently-configured clients are needed.
     ///
     /// # Errors
     ///
     /// Returns an error if it fails to obtain an access token or
     /// if the `reqwest::Client` cannot be built.
     pub async fn new() -> Result<Self> {
-        let access_token = Self::get_access_token().await?;
+        let builder = if std::env::var("TEST_ENV").is_ok() {
+            Self::builder()
+                .base_url(
+                    std::env::var("MOCK_SERVER_URL")
+                        .unwrap_or_else(|_| "http://localhost:1234".to_string()),
+                )
+                .token("12345")
+        } else {
+            Self::builder()
+                .base_url(&elation_config().ELATION_API_URL)
+                .token_provider(TokenServiceProvider::new(
+                    &elation_config().TOKEN_SERVICE_URL,
+                )?)
+        };
 
-        let client = Self::create_client(&access_token)?;
+        let client = builder.build()?;
 
-        Ok(Self { client })
+        // Fail early, as before, if no token can be obtained.
+        client.token_provider.token().await?;
+
+        Ok(client)
     }
 
-    /// Retrieves an access token from the token service.
-    ///
-    /// This function uses an exponential backoff retry strategy to handle transient errors.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if it fails to obtain a response from the token service
-    /// or if it cannot read the access token from the response.
-    async fn get_access_token() -> Result<String> {
-        let retry_strategy = ExponentialBackoff::from_millis(10).take(3);
+    /// Returns a `ClientBuilder` for configuring a new `Client`.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
 
-        let base_url = if std::env::var("TEST_ENV").is_ok() {
-            return Ok("12345".to_owned());
-        } else {
-            // Use the real API URL
-            Url::parse(&elation_config().TOKEN_SERVICE_URL)?
-        };
+    /// Returns the base URL that endpoints are resolved against.
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
 
-        let response = Retry::spawn(retry_strategy, || async {
-            let request = reqwest::Client::new().get(format!("{}token", base_url));
+    /// Returns the current budget of the client's rate limiter, if it has one.
+    pub fn rate_limit_stats(&self) -> Option<RateLimitStats> {
+        self.rate_limiter
+            .as_ref()
+            .map(|limiter| limiter.stats(self.practice))
+    }
 
-            request.send().await
-        })
-        .await?;
+    /// Returns the state of the client's circuit breaker, if it has one, e.g. for a health check.
+    pub fn circuit_stats(&self) -> Option<CircuitStats> {
+        self.circuit_breaker.as_ref().map(|breaker| breaker.stats())
+    }
 
-        let access_token = response.text().await?;
+    /// Returns the client's response cache, e.g. to invalidate it, if it has one.
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_ref()
+    }
 
-        Ok(access_token)
+    /// Returns the registry the client records its metrics in, if it has one.
+    pub fn metrics(&self) -> Option<&Metrics> {
+        self.metrics.as_ref()
     }
 
-    /// Creates a new `reqwest::Client` with default headers.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if it fails to build the `reqwest::Client`.
-    fn create_client(access_token: &str) -> Result<reqwest::Client> {
-        let headers = Self::default_headers(access_token)?;
-        Ok(reqwest::Client::builder()
-            .default_headers(headers)
-            .build()?)
+    /// Resolves `endpoint` against the base URL, keeping any path the base URL already has.
+    fn endpoint_url(&self, endpoint: &str) -> Url {
+        let mut url = self.base_url.clone();
+        let path = format!(
+            "{}/{}",
+            url.path().trim_end_matches('/'),
+            endpoint.trim_start_matches('/')
+        );
+        url.set_path(&path);
+        url
     }
 
-    /// Constructs the default headers for the HTTP client.
+    /// Serializes query parameters into a query string.
+    ///
+    /// Sequences are encoded as repeated keys (`?patient=1&patient=2`) and `None`
+    /// values are omitted.
+    fn encode_query<P: Params>(params: &P) -> Result<String> {
+        fn push(pairs: &mut Vec<(String, String)>, key: &str, value: serde_json::Value) {
+            match value {
+                serde_json::Value::Null => {}
+                serde_json::Value::String(s) => pairs.push((key.to_owned(), s)),
+                serde_json::Value::Array(values) => {
+                    for value in values {
+                        push(pairs, key, value);
+                    }
+                }
+                other => pairs.push((key.to_owned(), other.to_string())),
+            }
+        }
+
+        let mut pairs = Vec::new();
+        if let serde_json::Value::Object(map) = serde_json::to_value(params)? {
+            for (key, value) in map {
+                push(&mut pairs, &key, value);
+            }
+        }
+
+        Ok(serde_urlencoded::to_string(pairs)?)
+    }
+
+    /// Builds a request, adding the client's default headers it does not set itself.
+    ///
+    /// The headers are added here rather than by `reqwest`, so that every `Transport` sees them.
+    fn build_request(&self, builder: RequestBuilder) -> Result<Request> {
+        let mut request = builder.build()?;
+        for (name, value) in &self.default_headers {
+            if !request.headers().contains_key(name) {
+                request.headers_mut().insert(name, value.clone());
+            }
+        }
+        Ok(request)
+    }
+
+    /// Attaches an `Authorization` header with `token` to a request.
+    fn authorize(&self, mut request: Request, token: &str) -> Result<Request> {
+        request.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {}", token))?,
+        );
+        Ok(request)
+    }
+
+    /// Sends a request, respecting the client's rate limiter.
     ///
-    /// Sets the `Content-Type` and `Accept` headers to `application/json`
-    /// and includes the `Authorization` header with the provided API key.
+    /// When a rate limiter is configured, the request first waits for a token, and a
+    /// `429 Too Many Requests` response pauses the limiter for the `Retry-After` period
+    /// before the request is resent, up to `RateLimit::max_throttle_retries` times.
+    async fn execute(
+        &self,
+        mut request: Request,
+        parts: Option<&RequestParts>,
+    ) -> Result<Response> {
+        let mut throttle_retries = 0;
+
+        loop {
+            let next = request.try_clone();
+
+            if let Some(limiter) = &self.rate_limiter {
+                let waited = limiter.acquire(self.practice).await;
+                if let Some(metrics) = self.metrics.as_ref().filter(|_| !waited.is_zero()) {
+                    metrics.record_rate_limit_wait(
+                        &endpoint_template(&self.base_url, request.url()),
+                        request.method(),
+                        waited,
+                    );
+                }
+            }
+
+            let response = self.send_authorized(request, parts).await?;
+
+            match (&self.rate_limiter, next) {
+                (Some(limiter), Some(next))
+                    if response.status() == StatusCode::TOO_MANY_REQUESTS
+                        && throttle_retries < limiter.config().max_throttle_retries =>
+                {
+                    limiter.pause(self.practice, retry_after(&response));
+                    throttle_retries += 1;
+                    request = next;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+
+    /// Authorizes and sends a request.
     ///
-    /// # Errors
+    /// If the API answers `401 Unauthorized`, the token provider is told to discard
+    /// the rejected token and the request is sent once more with a fresh one.
+    async fn send_authorized(
+        &self,
+        request: Request,
+        parts: Option<&RequestParts>,
+    ) -> Result<Response> {
+        let retry = request.try_clone();
+
+        let token = self.token_provider.token().await?;
+        let response = self
+            .send_through_middleware(self.authorize(request, &token)?, parts)
+            .await?;
+
+        match retry {
+            Some(retry) if response.status() == StatusCode::UNAUTHORIZED => {
+                self.token_provider.invalidate(&token).await;
+                let token = self.token_provider.token().await?;
+                self.send_through_middleware(self.authorize(retry, &token)?, parts)
+                    .await
+            }
+            _ => Ok(response),
+        }
+    }
+
+    /// Sends a request over the network, passing it through the client's middleware.
     ///
-    /// Returns an error if the `Authorization` header cannot be constructed.
-    fn default_headers(api_key: &str) -> Result<HeaderMap> {
-        let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        headers.insert("accept", HeaderValue::from_static("application/json"));
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", api_key))?,
-        );
-        Ok(headers)
+    /// `before_request` hooks run in order and may answer the request themselves, in which
+    /// case only the `after_response` hooks of the middleware before them see the response.
+    async fn send_through_middleware(
+        &self,
+        mut request: Request,
+        parts: Option<&RequestParts>,
+    ) -> Result<Response> {
+        let Some(parts) = parts else {
+            return self.transport.send(request).await;
+        };
+
+        let mut answered = None;
+        for (index, middleware) in self.middleware.iter().enumerate() {
+            if let Some(response) = middleware.before_request(&mut request).await? {
+                answered = Some((index, response));
+                break;
+            }
+        }
+
+        let (ran, mut response) = match answered {
+            Some(answered) => answered,
+            None => (self.middleware.len(), self.transport.send(request).await?),
+        };
+        for middleware in self.middleware[..ran].iter().rev() {
+            response = middleware.after_response(parts, response).await?;
+        }
+
+        Ok(response)
     }
 
     /// Sends a GET request to the specified endpoint with optional query parameters.
@@ -311,7 +526,22 @@ impl Client {
     ///
     /// Returns an error if the request fails.
     pub async fn get<P: Params>(&self, endpoint: &str, params: P) -> Result<Response> {
-        self.send_request(Method::GET, endpoint, None::<&()>, Some(&params))
+        self.get_with(endpoint, params, &RequestOptions::default())
+            .await
+    }
+
+    /// Sends a GET request like [`Client::get`], applying `options` to this call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, is cancelled, or misses its deadline.
+    pub async fn get_with<P: Params>(
+        &self,
+        endpoint: &str,
+        params: P,
+        options: &RequestOptions,
+    ) -> Result<Response> {
+        self.send_request(Method::GET, endpoint, None::<&()>, Some(&params), options)
             .await
     }
 
@@ -334,7 +564,22 @@ impl Client {
         endpoint: &str,
         body: &T,
     ) -> Result<Response> {
-        self.send_request(Method::POST, endpoint, Some(body), None::<&()>)
+        self.post_with(endpoint, body, &RequestOptions::default())
+            .await
+    }
+
+    /// Sends a POST request like [`Client::post`], applying `options` to this call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, is cancelled, or misses its deadline.
+    pub async fn post_with<T: Serialize + Sized + Debug>(
+        &self,
+        endpoint: &str,
+        body: &T,
+        options: &RequestOptions,
+    ) -> Result<Response> {
+        self.send_request(Method::POST, endpoint, Some(body), None::<&()>, options)
             .await
     }
 
@@ -349,7 +594,16 @@ impl Client {
     ///
     /// Returns an error if the request fails.
     pub async fn delete(&self, endpoint: &str) -> Result<Response> {
-        self.send_request(Method::DELETE, endpoint, None::<&()>, None::<&()>)
+        self.delete_with(endpoint, &RequestOptions::default()).await
+    }
+
+    /// Sends a DELETE request like [`Client::delete`], applying `options` to this call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, is cancelled, or misses its deadline.
+    pub async fn delete_with(&self, endpoint: &str, options: &RequestOptions) -> Result<Response> {
+        self.send_request(Method::DELETE, endpoint, None::<&()>, None::<&()>, options)
             .await
     }
 
@@ -372,7 +626,22 @@ impl Client {
         endpoint: &str,
         body: &T,
     ) -> Result<Response> {
-        self.send_request(Method::PUT, endpoint, Some(body), None::<&()>)
+        self.put_with(endpoint, body, &RequestOptions::default())
+            .await
+    }
+
+    /// Sends a PUT request like [`Client::put`], applying `options` to this call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, is cancelled, or misses its deadline.
+    pub async fn put_with<T: Serialize + Sized + Debug>(
+        &self,
+        endpoint: &str,
+        body: &T,
+        options: &RequestOptions,
+    ) -> Result<Response> {
+        self.send_request(Method::PUT, endpoint, Some(body), None::<&()>, options)
             .await
     }
 
@@ -395,7 +664,22 @@ impl Client {
         endpoint: &str,
         body: &T,
     ) -> Result<Response> {
-        self.send_request(Method::PATCH, endpoint, Some(body), None::<&()>)
+        self.patch_with(endpoint, body, &RequestOptions::default())
+            .await
+    }
+
+    /// Sends a PATCH request like [`Client::patch`], applying `options` to this call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, is cancelled, or misses its deadline.
+    pub async fn patch_with<T: Serialize + Sized + Debug>(
+        &self,
+        endpoint: &str,
+        body: &T,
+        options: &RequestOptions,
+    ) -> Result<Response> {
+        self.send_request(Method::PATCH, endpoint, Some(body), None::<&()>, options)
             .await
     }
 
@@ -410,6 +694,8 @@ impl Client {
     /// * `method` - The HTTP method to use for the request.
     /// * `endpoint` - The API endpoint to send the request to.
     /// * `body` - An optional reference to the request body to be serialized as JSON.
+    /// * `params` - Optional query parameters, only sent with GET requests.
+    /// * `options` - Per-call settings such as a timeout or extra headers.
     ///
     /// # Type Parameters
     ///
@@ -424,29 +710,22 @@ impl Client {
         endpoint: &str,
         body: Option<&T>,
         params: Option<&P>,
+        options: &RequestOptions,
     ) -> Result<Response>
     where
         T: Serialize + Sized + Debug,
         P: Params,
     {
-        // Check if we are in a testing environment
-        let base_url = if std::env::var("TEST_ENV").is_ok() {
-            // Use the mock server URL
-            Url::parse(
-                &std::env::var("MOCK_SERVER_URL")
-                    .unwrap_or_else(|_| "http://localhost:1234".to_string()),
-            )?
-        } else {
-            // Use the real API URL
-            Url::parse(&elation_config().ELATION_API_URL)?
-        };
-
-        let mut url = base_url.join(endpoint)?;
+        let mut url = self.endpoint_url(endpoint);
 
         let mut request_builder = match method {
             Method::GET => {
-                let query = serde_urlencoded::to_string(params)?;
-                url.set_query(Some(&query));
+                if let Some(params) = params {
+                    let query = Self::encode_query(params)?;
+                    if !query.is_empty() {
+                        url.set_query(Some(&query));
+                    }
+                }
 
                 self.client.get(url)
             }
@@ -454,30 +733,249 @@ impl Client {
             Method::POST => self.client.post(url),
             Method::PUT => self.client.put(url),
             Method::PATCH => self.client.patch(url),
-            _ => return Err(Error::NotFound("Method not available".to_owned())),
+            _ => {
+                return Err(Error::NotFound(Box::new(ApiErrorBody::local(
+                    StatusCode::METHOD_NOT_ALLOWED,
+                    endpoint,
+                    "Method not available",
+                ))))
+            }
         };
 
         if let Some(body) = body {
             request_builder = request_builder.json(body);
         }
 
-        let response = request_builder.send().await.map_err(Error::from)?;
+        let mut request = self.build_request(request_builder)?;
+        options.apply(&mut request);
+        self.dispatch(request, endpoint, body, options).await
+    }
 
-        match response.error_for_status_ref() {
-            Ok(_) => Ok(response),
-            Err(e) => {
-                let status = e.status();
-                let message = response.text().await?;
-                log::error!(
-                    "Method: {}\nEndpoint: {}\nMessage: {}\nBody: {:#?}",
-                    method,
-                    endpoint,
-                    message,
-                    body
-                );
-                Err(Self::map_error(status, endpoint, &message, body, e))
+    /// Sends a request inside an `elation.request` tracing span.
+    ///
+    /// The span carries the method, the endpoint template, and once the request completes,
+    /// the status code, Elation's request id, the number of attempts and the latency.
+    /// The whole call, retries included, is abandoned once `options` say so. Its outcome
+    /// and latency are recorded in the client's `Metrics`, if it has any, unless it was
+    /// answered from the `ResponseCache` without reaching the API.
+    async fn dispatch<T: Serialize>(
+        &self,
+        request: Request,
+        endpoint: &str,
+        body: Option<&T>,
+        options: &RequestOptions,
+    ) -> Result<Response> {
+        let method = request.method().clone();
+        let template = endpoint_template(&self.base_url, request.url());
+        let span = tracing::info_span!(
+            "elation.request",
+            http.method = %method,
+            endpoint = %template,
+            http.status_code = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+            attempts = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            cache = tracing::field::Empty,
+        );
+        let started = Instant::now();
+
+        let (result, cache_hit) = match options
+            .guard(self.dispatch_cached(request, endpoint, body, started))
+            .instrument(span.clone())
+            .await
+        {
+            Ok((response, cache_hit)) => (Ok(response), cache_hit),
+            Err(error) => (Err(error), false),
+        };
+
+        span.record("latency_ms", started.elapsed().as_millis() as u64);
+        if let Some(metrics) = self.metrics.as_ref().filter(|_| !cache_hit) {
+            metrics.record_request(&template, &method, &result, started.elapsed());
+        }
+        if let Err(error) = &result {
+            tracing::warn!(parent: &span, error = error.kind(), "request failed");
+        }
+        result
+    }
+
+    /// Sends a request, answering `GET` requests from the client's `ResponseCache` where possible.
+    ///
+    /// Stale responses that carry an `ETag` are revalidated with `If-None-Match`, and a
+    /// successful request with any other method invalidates the cached responses of its resource.
+    /// Whether the cache was used is recorded on the current span and in the client's `Metrics`,
+    /// and returned along with the response: `true` for a hit that never reached the API.
+    async fn dispatch_cached<T: Serialize>(
+        &self,
+        mut request: Request,
+        endpoint: &str,
+        body: Option<&T>,
+        started: Instant,
+    ) -> Result<(Response, bool)> {
+        let Some(cache) = &self.cache else {
+            return self
+                .dispatch_with_retries(request, endpoint, body, started)
+                .await
+                .map(|response| (response, false));
+        };
+        let resource = resource_name(relative_path(&self.base_url, request.url())).to_owned();
+
+        if request.method() != Method::GET {
+            let response = self
+                .dispatch_with_retries(request, endpoint, body, started)
+                .await?;
+            cache.invalidate(&resource);
+            return Ok((response, false));
+        }
+        let Some(ttl) = cache.ttl_of(&resource) else {
+            return self
+                .dispatch_with_retries(request, endpoint, body, started)
+                .await
+                .map(|response| (response, false));
+        };
+
+        let template = endpoint_template(&self.base_url, request.url());
+        let record = |result| {
+            tracing::Span::current().record("cache", result);
+            if let Some(metrics) = &self.metrics {
+                metrics.record_cache(&template, result);
+            }
+        };
+        let key = cache_key(&resource, request.url());
+        let cached = cache.lookup(&key);
+        match &cached {
+            Some(entry) if entry.is_fresh(ttl) => {
+                record("hit");
+                return Ok((entry.to_response(), true));
             }
+            Some(CachedResponse {
+                etag: Some(etag), ..
+            }) => {
+                request
+                    .headers_mut()
+                    .insert(IF_NONE_MATCH, HeaderValue::from_str(etag)?);
+            }
+            _ => {}
         }
+
+        let response = self
+            .dispatch_with_retries(request, endpoint, body, started)
+            .await?;
+
+        let entry = match cached {
+            Some(entry) if response.status() == StatusCode::NOT_MODIFIED => {
+                record("revalidated");
+                CachedResponse {
+                    stored_at: SystemTime::now(),
+                    ..entry
+                }
+            }
+            _ => {
+                record("miss");
+                CachedResponse {
+                    key,
+                    status: response.status().as_u16(),
+                    etag: response
+                        .headers()
+                        .get(ETAG)
+                        .and_then(|value| value.to_str().ok())
+                        .map(str::to_owned),
+                    body: response.text().await?,
+                    stored_at: SystemTime::now(),
+                }
+            }
+        };
+        let response = entry.to_response();
+        cache.store(entry);
+        Ok((response, false))
+    }
+
+    /// Sends a request and maps error responses, retrying according to the client's `RetryPolicy`.
+    ///
+    /// When at least one retry was made and the request still failed, the last error is
+    /// wrapped in `Error::RetriesExhausted` together with the number of attempts.
+    async fn dispatch_with_retries<T: Serialize>(
+        &self,
+        mut request: Request,
+        endpoint: &str,
+        body: Option<&T>,
+        started: Instant,
+    ) -> Result<Response> {
+        let method = request.method().clone();
+        let parts = (!self.middleware.is_empty()).then(|| RequestParts::from(&request));
+        let retryable = self.retry_policy.applies_to(&method);
+        let mut attempts = 1;
+
+        if let Some(body) = body.filter(|_| self.redaction.logs_bodies()) {
+            tracing::debug!(body = %self.redaction.mask(body), "request body");
+        }
+
+        loop {
+            let next = if retryable { request.try_clone() } else { None };
+            tracing::Span::current().record("attempts", attempts);
+
+            let error = match self.attempt(request, parts.as_ref(), endpoint, body).await {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+
+            if let Some(parts) = &parts {
+                for middleware in &self.middleware {
+                    middleware.on_error(parts, &error).await;
+                }
+            }
+
+            let backoff = self.retry_policy.next_backoff(&error, attempts, started);
+            match (next, backoff) {
+                (Some(next), Some(backoff)) => {
+                    tracing::warn!(
+                        attempt = attempts,
+                        backoff_ms = backoff.as_millis() as u64,
+                        error = error.kind(),
+                        "retrying request"
+                    );
+                    if let Some(metrics) = &self.metrics {
+                        metrics
+                            .record_retry(&endpoint_template(&self.base_url, next.url()), &method);
+                    }
+                    tokio::time::sleep(backoff).await;
+                    attempts += 1;
+                    request = next;
+                }
+                _ if attempts > 1 => {
+                    return Err(Error::RetriesExhausted {
+                        attempts,
+                        source: Box::new(error),
+                    })
+                }
+                _ => return Err(error),
+            }
+        }
+    }
+
+    /// Sends a request once and maps an error response, unless the client's circuit breaker is open.
+    ///
+    /// The outcome is reported to the circuit breaker.
+    async fn attempt<T: Serialize>(
+        &self,
+        request: Request,
+        parts: Option<&RequestParts>,
+        endpoint: &str,
+        body: Option<&T>,
+    ) -> Result<Response> {
+        let permit = match &self.circuit_breaker {
+            Some(breaker) => Some(breaker.acquire()?),
+            None => None,
+        };
+
+        let result = match self.execute(request, parts).await {
+            Ok(response) => self.handle_response(response, endpoint, body).await,
+            Err(error) => Err(error),
+        };
+
+        if let Some(permit) = permit {
+            permit.record(&result);
+        }
+        result
     }
 
     /// Maps HTTP status codes to custom error types.
@@ -489,40 +987,60 @@ impl Client {
     ///
     /// * `status` - The HTTP status code of the response, if available.
     /// * `endpoint` - The API endpoint that was called.
-    /// * `message` - The error message from the response body.
-    /// * `body` - An optional reference to the request body that was sent.
+    /// * `request_id` - The id Elation assigned to the request, if any.
+    /// * `text` - The response body, with the PHI of the request it echoes masked.
+    /// * `message` - The response body, with PHI masked except for validation messages.
+    /// * `body` - The request body that was sent, with PHI masked.
     /// * `e` - The original `reqwest::Error`.
-    ///
-    /// # Type Parameters
-    ///
-    /// * `T` - The type of the request body.
-    fn map_error<T: Debug>(
+    fn map_error(
         status: Option<StatusCode>,
         endpoint: &str,
-        message: &str,
-        body: Option<&T>,
+        request_id: Option<String>,
+        text: String,
+        message: String,
+        body: &str,
         e: reqwest::Error,
     ) -> Error {
-        match status {
-            Some(StatusCode::BAD_REQUEST) => Error::BadRequest(format!("Bad Request at '{}': Unable to understand the request. Error: {}. Body: {:?}", endpoint, message, body)),
-            Some(StatusCode::CONFLICT) => Error::Conflict(format!("Conflict at '{}': Elation probably thinks this is a malformed resource. Error: {}. Body: {:?}", endpoint, message, body)),
-            Some(StatusCode::UNAUTHORIZED) => Error::Unauthorized(format!("Unauthorized at '{}': {}. Body: {:?}", endpoint, message, body)),
-            Some(StatusCode::FORBIDDEN) => Error::Forbidden(format!("Forbidden at '{}': Access to the requested resource is denied. Error: {}. Body: {:?}", endpoint, message, body)),
-            Some(StatusCode::NOT_FOUND) => Error::NotFound(format!("Not Found at '{}': The requested resource could not be found. Error: {}. Body: {:?}", endpoint, message, body)),
-            Some(StatusCode::UNSUPPORTED_MEDIA_TYPE) => Error::UnsupportedMediaType(format!("Unsupported Media Type at '{}': Ensure you are using application/json as content type. Error: {}. Body: {:?}", endpoint, message, body)),
-            Some(StatusCode::TOO_MANY_REQUESTS) => Error::TooManyRequests(format!("Too Many Requests at '{}': You have hit the rate limit. Try again later. Error: {}. Body: {:?}", endpoint, message, body)),
-            Some(StatusCode::INTERNAL_SERVER_ERROR) => Error::InternalServerError(format!("Internal Server Error at '{}': Something went wrong on the server side. Error: {}. Body: {:?}", endpoint, message, body)),
-            Some(StatusCode::SERVICE_UNAVAILABLE) => Error::ServiceUnavailable(format!("Service Unavailable at '{}': The server is currently too busy. Try again later. Error: {}. Body: {:?}", endpoint, message, body)),
-            Some(StatusCode::GATEWAY_TIMEOUT) => Error::GatewayTimeout(format!("Gateway Timeout at '{}': The request took too long to complete. Error: {}. Body: {:?}", endpoint, message, body)),
-            //Some(status) if status.is_client_error() => Error::ClientError(format!(
-            //    "Client error at '{}': {}. Body: {:?}",
+        let Some(status) = status else {
+            return Error::ReqwestError(e);
+        };
+        let (variant, description): (fn(Box<ApiErrorBody>) -> Error, String) = match status {
+            StatusCode::BAD_REQUEST => (Error::BadRequest, format!("Bad Request at '{}': Unable to understand the request. Error: {}. Body: {}", endpoint, message, body)),
+            StatusCode::CONFLICT => (Error::Conflict, format!("Conflict at '{}': Elation probably thinks this is a malformed resource. Error: {}. Body: {}", endpoint, message, body)),
+            StatusCode::UNAUTHORIZED => (Error::Unauthorized, format!("Unauthorized at '{}': {}. Body: {}", endpoint, message, body)),
+            StatusCode::FORBIDDEN => (Error::Forbidden, format!("Forbidden at '{}': Access to the requested resource is denied. Error: {}. Body: {}", endpoint, message, body)),
+            StatusCode::NOT_FOUND => (Error::NotFound, format!("Not Found at '{}': The requested resource could not be found. Error: {}. Body: {}", endpoint, message, body)),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE => (Error::UnsupportedMediaType, format!("Unsupported Media Type at '{}': Ensure you are using application/json as content type. Error: {}. Body: {}", endpoint, message, body)),
+            StatusCode::TOO_MANY_REQUESTS => (Error::TooManyRequests, format!("Too Many Requests at '{}': You have hit the rate limit. Try again later. Error: {}. Body: {}", endpoint, message, body)),
+            StatusCode::INTERNAL_SERVER_ERROR => (Error::InternalServerError, format!("Internal Server Error at '{}': Something went wrong on the server side. Error: {}. Body: {}", endpoint, message, body)),
+            StatusCode::BAD_GATEWAY => (Error::BadGateway, format!("Bad Gateway at '{}': The server received an invalid response upstream. Try again later. Error: {}. Body: {}", endpoint, message, body)),
+            StatusCode::SERVICE_UNAVAILABLE => (Error::ServiceUnavailable, format!("Service Unavailable at '{}': The server is currently too busy. Try again later. Error: {}. Body: {}", endpoint, message, body)),
+            StatusCode::GATEWAY_TIMEOUT => (Error::GatewayTimeout, format!("Gateway Timeout at '{}': The request took too long to complete. Error: {}. Body: {}", endpoint, message, body)),
+            //status if status.is_client_error() => Error::ClientError(format!(
+            //    "Client error at '{}': {}. Body: {}",
             //    endpoint, message, body
             //)),
-            //Some(status) if status.is_server_error() => Error::ServerError(format!(
-            //    "Server error at '{}': {}. Body: {:?}",
+            //status if status.is_server_error() => Error::ServerError(format!(
+            //    "Server error at '{}': {}. Body: {}",
             //    endpoint, message, body
             //)),
-            _ => Error::ReqwestError(e),
+            _ => return Error::ReqwestError(e),
+        };
+        // Validation messages are parsed from the response as sent, bar the request values
+        // it echoes, so those of PHI fields survive, but only the masked body is kept
+        let mut error_body = ApiErrorBody::parse(status, endpoint, request_id, text, description);
+        error_body.raw = message;
+        variant(Box::new(error_body))
+    }
+
+    /// Returns `endpoint` as errors quote it.
+    ///
+    /// Full URLs, such as the `next` links of paginated responses, are reduced to their path
+    /// relative to the base URL, since their query strings hold search parameters.
+    fn error_endpoint(&self, endpoint: &str) -> String {
+        match Url::parse(endpoint) {
+            Ok(url) => relative_path(&self.base_url, &url).to_owned(),
+            Err(_) => endpoint.to_owned(),
         }
     }
     /// Sends a GET request to a full URL.
@@ -537,20 +1055,203 @@ impl Client {
     ///
     /// Returns an error if the request fails.
     pub async fn get_full_url(&self, url: &str) -> Result<Response> {
-        let request = self.client.get(url);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.get_full_url_with(url, &RequestOptions::default())
+            .await
+    }
+
+    /// Sends a GET request to a full URL like [`Client::get_full_url`], applying `options` to this call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, is cancelled, or misses its deadline.
+    pub async fn get_full_url_with(&self, url: &str, options: &RequestOptions) -> Result<Response> {
+        let mut request = self.build_request(self.client.get(url))?;
+        options.apply(&mut request);
+        self.dispatch(request, url, None::<&()>, options).await
+    }
+
+    /// Sends a request to an endpoint the SDK does not model yet and deserializes the response.
+    ///
+    /// The request goes through the same authorization, rate limiting, retries, caching, error
+    /// mapping, tracing and metrics as every other call of the client. `path` is resolved
+    /// against the base URL like the endpoints of [`Client::get`], unless it is a full URL
+    /// such as a `next` link. `query` is encoded like [`Client::get`] encodes its parameters,
+    /// for any method. An empty response body, e.g. of a `204 No Content`, deserializes as
+    /// JSON `null`, so `R` can be `()` or an `Option`.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Q` - The query parameters; pass `()` for none.
+    /// * `B` - The request body, serialized as JSON; pass `None::<&()>` for none.
+    /// * `R` - The type the response body is deserialized into.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use client::{Client, Method};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Debug, Default, Serialize)]
+    /// struct CareGapQuery {
+    ///     patient: i64,
+    /// }
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct CareGap {
+    ///     id: i64,
+    ///     status: String,
+    /// }
+    ///
+    /// # async fn run(client: &Client) -> client::Result<()> {
+    /// let gaps: client::PaginatedResponse<CareGap> = client
+    ///     .request(Method::GET, "/care_gaps/", CareGapQuery { patient: 7 }, None::<&()>)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, or if the response body cannot be deserialized into `R`.
+    pub async fn request<Q, B, R>(
+        &self,
+        method: Method,
+        path: &str,
+        query: Q,
+        body: Option<&B>,
+    ) -> Result<R>
+    where
+        Q: Params,
+        B: Serialize + Debug,
+        R: DeserializeOwned,
+    {
+        self.request_with(method, path, query, body, &RequestOptions::default())
+            .await
+    }
+
+    /// Sends a request like [`Client::request`], applying `options` to this call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, is cancelled, or misses its deadline, or if
+    /// the response body cannot be deserialized into `R`.
+    pub async fn request_with<Q, B, R>(
+        &self,
+        method: Method,
+        path: &str,
+        query: Q,
+        body: Option<&B>,
+        options: &RequestOptions,
+    ) -> Result<R>
+    where
+        Q: Params,
+        B: Serialize + Debug,
+        R: DeserializeOwned,
+    {
+        let mut url = match Url::parse(path) {
+            Ok(url) => url,
+            Err(_) => self.endpoint_url(path),
+        };
+        let query = Self::encode_query(&query)?;
+        if !query.is_empty() {
+            url.set_query(Some(&query));
+        }
+
+        let mut request_builder = self.client.request(method, url);
+        if let Some(body) = body {
+            request_builder = request_builder.json(body);
+        }
+
+        let mut request = self.build_request(request_builder)?;
+        options.apply(&mut request);
+        let response = self.dispatch(request, path, body, options).await?;
+
+        let text = response.text().await?;
+        let text = if text.trim().is_empty() {
+            "null"
+        } else {
+            &text
+        };
+        Ok(serde_json::from_str(text)?)
+    }
+
+    /// Sends a request like [`Client::request`] and returns the response body as untyped JSON.
+    ///
+    /// Meant for exploring endpoints before modelling them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use client::{Client, Method};
+    ///
+    /// # async fn run(client: &Client) -> client::Result<()> {
+    /// let gap = client
+    ///     .request_value(Method::GET, "/care_gaps/12/", (), None::<&()>)
+    ///     .await?;
+    /// println!("{}", gap["status"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, or if the response body is not JSON.
+    pub async fn request_value<Q, B>(
+        &self,
+        method: Method,
+        path: &str,
+        query: Q,
+        body: Option<&B>,
+    ) -> Result<Value>
+    where
+        Q: Params,
+        B: Serialize + Debug,
+    {
+        self.request(method, path, query, body).await
     }
 
     /// Handles the response, checking for errors and mapping them appropriately.
-    async fn handle_response(&self, response: Response) -> Result<Response> {
+    ///
+    /// The status and request id are recorded on the current span. Bodies are only
+    /// emitted when the client's `Redaction` allows it, and then with PHI masked.
+    async fn handle_response<T: Serialize>(
+        &self,
+        response: Response,
+        endpoint: &str,
+        body: Option<&T>,
+    ) -> Result<Response> {
+        let span = tracing::Span::current();
+        let request_id = response
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        span.record("http.status_code", response.status().as_u16());
+        if let Some(request_id) = &request_id {
+            span.record("request_id", request_id.as_str());
+        }
+
         match response.error_for_status_ref() {
             Ok(_) => Ok(response),
             Err(e) => {
                 let status = e.status();
-                let message = response.text().await.unwrap_or_default();
-                log::error!("Request failed: {}", message);
-                Err(Self::map_error(status, "", &message, None::<&()>, e))
+                let url = response.url().clone();
+                let text = response.text().await.unwrap_or_default();
+                let text = self.redaction.mask_echoes(&text, body, &url);
+                let message = self.redaction.mask_error_text(&text);
+                if self.redaction.logs_bodies() {
+                    tracing::debug!(body = %message, "error response body");
+                }
+                let body = body.map_or_else(|| "None".to_owned(), |body| self.redaction.mask(body));
+                Err(Self::map_error(
+                    status,
+                    &self.error_endpoint(endpoint),
+                    request_id,
+                    text,
+                    message,
+                    &body,
+                    e,
+                ))
             }
         }
     }
@@ -568,7 +1269,7 @@ impl Client {
         T: DeserializeOwned + Debug,
     {
         let mut results = Vec::new();
-        let mut next_url = Some(format!("{}{}", elation_config().ELATION_API_URL, endpoint));
+        let mut next_url = Some(self.endpoint_url(endpoint).to_string());
 
         while let Some(url) = next_url {
             let response = self.get_full_url(&url).await?;
@@ -580,4 +1281,24 @@ impl Client {
 
         Ok(results)
     }
+
+    /// Streams the items of a paginated resource, fetching one page at a time.
+    ///
+    /// Unlike [`Client::get_all_pages`], this never holds more than a page or two in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The API endpoint to fetch.
+    pub fn stream_pages<T>(&self, endpoint: &str) -> PageStream<'static, T>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let client = self.clone();
+        let url = self.endpoint_url(endpoint).to_string();
+
+        PageStream::new(self, async move {
+            let response = client.get_full_url(&url).await?;
+            Ok(response.json::<PaginatedResponse<T>>().await?)
+        })
+    }
 }
@@ -4,6 +4,8 @@ use derive_more::From;
 use serde::Serialize;
 use serde_with::{serde_as, DisplayFromStr};
 
+use crate::ApiErrorBody;
+
 /// A specialized `Result` type for the SDK.
 ///
 /// This is defined as a convenience so that you don't have to write out
@@ -16,6 +18,9 @@ pub type Result<T> = core::result::Result<T, Error>;
 /// This enum represents all possible errors that can occur when using the SDK.
 /// It includes variants for common HTTP errors, as well as errors originating
 /// from external crates like `reqwest` and `url`.
+///
+/// The HTTP error variants carry the parsed [`ApiErrorBody`] of the response, so that
+/// validation errors can be inspected field by field.
 #[serde_as]
 #[derive(Debug, From, Serialize)]
 pub enum Error {
@@ -41,6 +46,22 @@ pub enum Error {
     #[from]
     QueryString(#[serde_as(as = "DisplayFromStr")] serde_urlencoded::ser::Error),
 
+    /// An error that occurs when converting a value to or from JSON fails.
+    ///
+    /// This variant wraps `serde_json::Error`.
+    #[from]
+    Json(#[serde_as(as = "DisplayFromStr")] serde_json::Error),
+
+    /// An error that occurs when reading or writing a file fails, e.g. a cassette.
+    #[from]
+    Io(#[serde_as(as = "DisplayFromStr")] std::io::Error),
+
+    /// Indicates that a `ClientBuilder` was built without a base URL.
+    MissingBaseUrl,
+
+    /// Indicates that a `ClientBuilder` was built without a token provider.
+    MissingTokenProvider,
+
     /// Indicates that a required request body was missing.
     ///
     /// This error occurs when a request expected a body but none was provided.
@@ -59,52 +80,185 @@ pub enum Error {
     /// Bad Request (HTTP 400).
     ///
     /// The server could not understand the request due to invalid syntax.
-    BadRequest(String), // 400
+    BadRequest(Box<ApiErrorBody>), // 400
 
     /// Unauthorized (HTTP 401).
     ///
     /// Authentication is required and has failed or has not yet been provided.
-    Unauthorized(String), // 401
+    Unauthorized(Box<ApiErrorBody>), // 401
 
     /// Forbidden (HTTP 403).
     ///
     /// The client does not have access rights to the content.
-    Forbidden(String), // 403
+    Forbidden(Box<ApiErrorBody>), // 403
 
     /// Not Found (HTTP 404).
     ///
     /// The server can not find the requested resource.
-    NotFound(String), // 404
+    NotFound(Box<ApiErrorBody>), // 404
 
     /// Conflict (HTTP 409).
     ///
     /// The request conflicts with the current state of the server.
-    Conflict(String), // 409
+    Conflict(Box<ApiErrorBody>), // 409
 
     /// Unsupported Media Type (HTTP 415).
     ///
     /// The media format of the requested data is not supported by the server.
-    UnsupportedMediaType(String), // 415
+    UnsupportedMediaType(Box<ApiErrorBody>), // 415
 
     /// Too Many Requests (HTTP 429).
     ///
     /// The client has sent too many requests in a given amount of time.
-    TooManyRequests(String), // 429
+    TooManyRequests(Box<ApiErrorBody>), // 429
 
     /// Internal Server Error (HTTP 500).
     ///
     /// The server encountered an unexpected condition that prevented it from fulfilling the request.
-    InternalServerError(String), // 500
+    InternalServerError(Box<ApiErrorBody>), // 500
+
+    /// Bad Gateway (HTTP 502).
+    ///
+    /// The server was acting as a gateway and received an invalid response from the upstream server.
+    BadGateway(Box<ApiErrorBody>), // 502
 
     /// Service Unavailable (HTTP 503).
     ///
     /// The server is not ready to handle the request, often due to maintenance or overload.
-    ServiceUnavailable(String), // 503
+    ServiceUnavailable(Box<ApiErrorBody>), // 503
 
     /// Gateway Timeout (HTTP 504).
     ///
     /// The server was acting as a gateway and did not receive a timely response from the upstream server.
-    GatewayTimeout(String), // 504
+    GatewayTimeout(Box<ApiErrorBody>), // 504
+
+    /// A request kept failing after being retried according to the client's `RetryPolicy`.
+    ///
+    /// Contains the number of attempts made and the error of the last attempt.
+    RetriesExhausted { attempts: u32, source: Box<Error> },
+
+    /// A replaying `Cassette` holds no unplayed interaction matching a request.
+    ///
+    /// Contains the method and the path and query of the request, with PHI masked.
+    UnmatchedRequest { method: String, request: String },
+
+    /// A call was abandoned because the `CancellationToken` of its `RequestOptions` was cancelled.
+    Cancelled,
+
+    /// A call did not complete by the deadline set in its `RequestOptions`.
+    DeadlineExceeded,
+
+    /// A request was not sent because the client's `CircuitBreaker` is open.
+    ///
+    /// Contains how long the breaker stays open before it lets probe requests through.
+    CircuitOpen { retry_after: std::time::Duration },
+}
+
+impl Error {
+    /// Returns whether the error is transient, so that repeating the request may succeed.
+    ///
+    /// This covers 502, 503 and 504 responses as well as timeouts and connection failures.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::BadGateway(_) | Error::ServiceUnavailable(_) | Error::GatewayTimeout(_) => true,
+            Error::ReqwestError(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            _ => false,
+        }
+    }
+
+    /// Returns whether a failed request may nonetheless have been carried out by the API.
+    ///
+    /// This covers timeouts and connections lost after the request was sent, responses that
+    /// could not be read, 502 and 504 responses from a gateway in front of the API, and calls
+    /// cancelled or past their deadline, which may have been abandoned mid-request. Looks
+    /// through `RetriesExhausted` to the error of the last attempt.
+    pub fn is_ambiguous(&self) -> bool {
+        match self.last_error() {
+            Error::BadGateway(_)
+            | Error::GatewayTimeout(_)
+            | Error::Cancelled
+            | Error::DeadlineExceeded => true,
+            Error::ReqwestError(e) => {
+                e.is_timeout()
+                    || e.is_body()
+                    || e.is_decode()
+                    || (e.is_request() && !e.is_connect())
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the parsed error body if the API answered with an error status.
+    ///
+    /// Looks through `RetriesExhausted` to the error of the last attempt.
+    pub fn api_error_body(&self) -> Option<&ApiErrorBody> {
+        match self.last_error() {
+            Error::BadRequest(body)
+            | Error::Unauthorized(body)
+            | Error::Forbidden(body)
+            | Error::NotFound(body)
+            | Error::Conflict(body)
+            | Error::UnsupportedMediaType(body)
+            | Error::TooManyRequests(body)
+            | Error::InternalServerError(body)
+            | Error::BadGateway(body)
+            | Error::ServiceUnavailable(body)
+            | Error::GatewayTimeout(body) => Some(body),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the variant, e.g. `"BadRequest"`.
+    ///
+    /// Unlike the `Display` output, this never contains request data, which makes it
+    /// safe to use as a log or metric label.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::ReqwestError(_) => "ReqwestError",
+            Error::HeaderValueError(_) => "HeaderValueError",
+            Error::ParseUrl(_) => "ParseUrl",
+            Error::QueryString(_) => "QueryString",
+            Error::Json(_) => "Json",
+            Error::Io(_) => "Io",
+            Error::MissingBaseUrl => "MissingBaseUrl",
+            Error::MissingTokenProvider => "MissingTokenProvider",
+            Error::BodyMissing => "BodyMissing",
+            Error::ApiError(_) => "ApiError",
+            Error::ParseFloat(_) => "ParseFloat",
+            Error::BadRequest(_) => "BadRequest",
+            Error::Unauthorized(_) => "Unauthorized",
+            Error::Forbidden(_) => "Forbidden",
+            Error::NotFound(_) => "NotFound",
+            Error::Conflict(_) => "Conflict",
+            Error::UnsupportedMediaType(_) => "UnsupportedMediaType",
+            Error::TooManyRequests(_) => "TooManyRequests",
+            Error::InternalServerError(_) => "InternalServerError",
+            Error::BadGateway(_) => "BadGateway",
+            Error::ServiceUnavailable(_) => "ServiceUnavailable",
+            Error::GatewayTimeout(_) => "GatewayTimeout",
+            Error::RetriesExhausted { .. } => "RetriesExhausted",
+            Error::UnmatchedRequest { .. } => "UnmatchedRequest",
+            Error::Cancelled => "Cancelled",
+            Error::DeadlineExceeded => "DeadlineExceeded",
+            Error::CircuitOpen { .. } => "CircuitOpen",
+        }
+    }
+
+    /// Returns the number of attempts made before this error was returned.
+    pub fn attempts(&self) -> u32 {
+        match self {
+            Error::RetriesExhausted { attempts, .. } => *attempts,
+            _ => 1,
+        }
+    }
+
+    /// Returns the error of the last attempt, looking through `RetriesExhausted`.
+    pub fn last_error(&self) -> &Error {
+        match self {
+            Error::RetriesExhausted { source, .. } => source.last_error(),
+            _ => self,
+        }
+    }
 }
 
 // region:    --- Error Boilerplate
@@ -112,7 +266,8 @@ pub enum Error {
 impl core::fmt::Display for Error {
     /// Formats the error using the `Display` trait.
     ///
-    /// This implementation delegates to the `Debug` representation,
+    /// HTTP errors are formatted as the variant name and the human readable message
+    /// of their [`ApiErrorBody`]. All other errors delegate to the `Debug` representation,
     /// which includes the error variant name and any associated data.
     ///
     /// # Example
@@ -120,12 +275,18 @@ impl core::fmt::Display for Error {
     /// ```
     /// use client::Error;
     ///
-    /// let error = Error::NotFound("Resource not found".into());
+    /// let error = Error::ApiError("Resource not found".into());
     /// println!("{}", error);
-    /// // Output: NotFound("Resource not found")
+    /// // Output: ApiError("Resource not found")
     /// ```
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
-        write!(fmt, "{self:?}")
+        match self {
+            Error::RetriesExhausted { .. } => write!(fmt, "{self:?}"),
+            _ => match self.api_error_body() {
+                Some(body) => write!(fmt, "{}({:?})", self.kind(), body.message),
+                None => write!(fmt, "{self:?}"),
+            },
+        }
     }
 }
 
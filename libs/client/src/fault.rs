@@ -0,0 +1,449 @@
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use reqwest::{
+    header::{CONTENT_LENGTH, CONTENT_TYPE, RETRY_AFTER},
+    Method, Request, Response, StatusCode,
+};
+use tokio::{
+    io::AsyncReadExt,
+    net::{TcpListener, TcpStream},
+    sync::OnceCell,
+};
+
+use crate::{Result, Transport};
+
+/// The body sent in place of the real one by [`Fault::MalformedJson`].
+const MALFORMED_JSON: &[u8] = br#"{"results": [{"id": 1,}"#;
+
+/// A failure a [`FaultInjector`] makes a request suffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// Delays the request by the given duration, then sends it as usual.
+    ///
+    /// Unlike the other faults, latency adds up with whatever other rule applies.
+    Latency(Duration),
+
+    /// Leaves the request unanswered, failing it like a `reqwest` timeout after the given duration.
+    Timeout(Duration),
+
+    /// Fails the request like a connection the server reset.
+    ConnectionReset,
+
+    /// Answers `429 Too Many Requests` with a `Retry-After` header of the given duration.
+    TooManyRequests { retry_after: Duration },
+
+    /// Answers with the given status, e.g. `503 Service Unavailable`, and a DRF-style `detail` body.
+    Status(StatusCode),
+
+    /// Sends the request, then cuts the body of the response in half.
+    TruncatedBody,
+
+    /// Sends the request, then replaces the body of the response with invalid JSON.
+    MalformedJson,
+}
+
+/// Decides which requests suffer a [`Fault`].
+///
+/// A rule applies to every request unless narrowed down by endpoint or method. Among the
+/// requests it applies to, it fires on the `nth` one only, if set, at most `times` times, if
+/// set, and each time with the given `probability`.
+#[derive(Debug, Clone)]
+pub struct FaultRule {
+    fault: Fault,
+    endpoint: Option<String>,
+    method: Option<Method>,
+    probability: f64,
+    nth: Option<u64>,
+    times: Option<u64>,
+    counters: Arc<RuleCounters>,
+}
+
+/// How often a rule applied and fired, shared by the clones of the injector it was added to.
+#[derive(Debug, Default)]
+struct RuleCounters {
+    calls: AtomicU64,
+    fired: AtomicU64,
+}
+
+impl FaultRule {
+    /// Creates a rule that injects `fault` into every request.
+    pub fn new(fault: Fault) -> Self {
+        Self {
+            fault,
+            endpoint: None,
+            method: None,
+            probability: 1.0,
+            nth: None,
+            times: None,
+            counters: Arc::default(),
+        }
+    }
+
+    /// Only applies to requests whose path ends with `pattern`, e.g. `"/patients/*"`.
+    ///
+    /// `*` matches any characters, including `/`, and a trailing slash is optional, so
+    /// `"/patients*"` covers both the collection and every single patient.
+    pub fn endpoint(mut self, pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        self.endpoint = Some(format!("/{}", pattern.trim_start_matches('/')));
+        self
+    }
+
+    /// Only applies to requests with `method`.
+    pub fn method(mut self, method: Method) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Fires with the given probability, between 0 and 1, instead of always.
+    pub fn probability(mut self, probability: f64) -> Self {
+        self.probability = probability.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Fires on the `nth` request the rule applies to only, counting from 1.
+    pub fn nth(mut self, nth: u64) -> Self {
+        self.nth = Some(nth);
+        self
+    }
+
+    /// Fires at most `times` times, e.g. to let a request succeed after a number of retries.
+    pub fn times(mut self, times: u64) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    fn applies_to(&self, request: &Request) -> bool {
+        self.method
+            .as_ref()
+            .is_none_or(|method| method == request.method())
+            && self
+                .endpoint
+                .as_deref()
+                .is_none_or(|pattern| path_matches(pattern, request.url().path()))
+    }
+}
+
+/// Makes a `Client` misbehave on purpose, to test retries and error handling.
+///
+/// Faults are injected beneath the middleware, retries and rate limiting, right where a
+/// request would go over the network, so the rest of the client reacts to them as it would
+/// to the real thing: timeouts and resets surface as `Error::ReqwestError`, injected
+/// statuses as the matching `Error` variant, and broken bodies fail when they are parsed.
+///
+/// Rules are evaluated in the order they were added and the first one that fires decides
+/// the fault, except for [`Fault::Latency`], which delays the request and lets evaluation
+/// go on. Clones share their counters and random number generator.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use client::{Client, Fault, FaultInjector, FaultRule, RetryPolicy};
+/// use reqwest::{Method, StatusCode};
+///
+/// let faults = FaultInjector::new()
+///     .seed(42)
+///     .rule(FaultRule::new(Fault::Status(StatusCode::SERVICE_UNAVAILABLE)).nth(1))
+///     .rule(
+///         FaultRule::new(Fault::Timeout(Duration::from_millis(100)))
+///             .endpoint("/patients/*")
+///             .method(Method::GET)
+///             .probability(0.1),
+///     );
+///
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token("my-access-token")
+///     .retry_policy(RetryPolicy::default())
+///     .faults(faults)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct FaultInjector {
+    rules: Vec<FaultRule>,
+    state: Arc<Mutex<FaultState>>,
+    sockets: Arc<OnceCell<FaultSockets>>,
+}
+
+#[derive(Debug)]
+struct FaultState {
+    rng: fastrand::Rng,
+    injected: u64,
+}
+
+impl Default for FaultInjector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FaultInjector {
+    /// Creates an injector without rules, which leaves every request alone.
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            state: Arc::new(Mutex::new(FaultState {
+                rng: fastrand::Rng::new(),
+                injected: 0,
+            })),
+            sockets: Arc::new(OnceCell::new()),
+        }
+    }
+
+    /// Adds a rule. May be called more than once.
+    pub fn rule(mut self, mut rule: FaultRule) -> Self {
+        // A rule counts its requests afresh in every injector it is added to
+        rule.counters = Arc::default();
+        self.rules.push(rule);
+        self
+    }
+
+    /// Seeds the random number generator, so that probabilistic rules fire on the same requests every run.
+    pub fn seed(self, seed: u64) -> Self {
+        self.state().rng.seed(seed);
+        self
+    }
+
+    /// Returns the number of faults injected so far, latency included.
+    pub fn injected(&self) -> u64 {
+        self.state().injected
+    }
+
+    /// Wraps `transport` so that requests sent through it suffer the injector's faults.
+    pub(crate) fn wrap(self, transport: Arc<dyn Transport>) -> Arc<dyn Transport> {
+        Arc::new(FaultyTransport {
+            inner: transport,
+            faults: self,
+        })
+    }
+
+    /// Returns the faults `request` suffers: the total latency, and the fault that fired, if any.
+    fn choose(&self, request: &Request) -> (Duration, Option<Fault>) {
+        let mut state = self.state();
+        let mut latency = Duration::ZERO;
+        let mut chosen = None;
+
+        for rule in &self.rules {
+            if !rule.applies_to(request) {
+                continue;
+            }
+            let calls = rule.counters.calls.fetch_add(1, Ordering::Relaxed) + 1;
+
+            let fires = chosen.is_none()
+                && rule.nth.is_none_or(|nth| nth == calls)
+                && rule
+                    .times
+                    .is_none_or(|times| rule.counters.fired.load(Ordering::Relaxed) < times)
+                && state.rng.f64() < rule.probability;
+            if !fires {
+                continue;
+            }
+            rule.counters.fired.fetch_add(1, Ordering::Relaxed);
+            state.injected += 1;
+
+            match &rule.fault {
+                Fault::Latency(delay) => latency += *delay,
+                fault => chosen = Some(fault.clone()),
+            }
+        }
+
+        (latency, chosen)
+    }
+
+    fn state(&self) -> MutexGuard<'_, FaultState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// The `Transport` installed by `ClientBuilder::faults`.
+#[derive(Debug)]
+struct FaultyTransport {
+    inner: Arc<dyn Transport>,
+    faults: FaultInjector,
+}
+
+#[async_trait]
+impl Transport for FaultyTransport {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let (latency, fault) = self.faults.choose(&request);
+        if !latency.is_zero() {
+            tokio::time::sleep(latency).await;
+        }
+
+        let Some(fault) = fault else {
+            return self.inner.send(request).await;
+        };
+        tracing::debug!(?fault, "injecting fault");
+
+        match fault {
+            Fault::Latency(_) => unreachable!("latency never ends rule evaluation"),
+            Fault::Timeout(after) => {
+                let sockets = self.faults.sockets().await?;
+                let result = sockets
+                    .http
+                    .get(format!("http://{}/", sockets.hang))
+                    .timeout(after)
+                    .send()
+                    .await;
+                match result {
+                    Err(error) => Err(error.into()),
+                    Ok(_) => Err(std::io::Error::new(
+                        std::io::ErrorKind::TimedOut,
+                        "the hanging socket answered",
+                    )
+                    .into()),
+                }
+            }
+            Fault::ConnectionReset => {
+                let sockets = self.faults.sockets().await?;
+                let result = sockets
+                    .http
+                    .get(format!("http://{}/", sockets.reset))
+                    .send()
+                    .await;
+                match result {
+                    Err(error) => Err(error.into()),
+                    Ok(_) => Err(std::io::Error::new(
+                        std::io::ErrorKind::ConnectionReset,
+                        "the resetting socket answered",
+                    )
+                    .into()),
+                }
+            }
+            Fault::TooManyRequests { retry_after } => {
+                let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+                let body = format!(
+                    r#"{{"detail":"Request was throttled. Expected available in {seconds} seconds."}}"#
+                );
+                Ok(response(
+                    StatusCode::TOO_MANY_REQUESTS,
+                    http::HeaderMap::from_iter([(RETRY_AFTER, seconds.into())]),
+                    Bytes::from(body),
+                ))
+            }
+            Fault::Status(status) => {
+                let body = serde_json::json!({
+                    "detail": status.canonical_reason().unwrap_or("Injected fault."),
+                });
+                Ok(response(
+                    status,
+                    http::HeaderMap::new(),
+                    Bytes::from(body.to_string()),
+                ))
+            }
+            Fault::TruncatedBody => {
+                let real = self.inner.send(request).await?;
+                let (status, headers) = (real.status(), real.headers().clone());
+                let body = real.bytes().await?;
+                Ok(response(status, headers, body.slice(..body.len() / 2)))
+            }
+            Fault::MalformedJson => {
+                let real = self.inner.send(request).await?;
+                let (status, headers) = (real.status(), real.headers().clone());
+                Ok(response(
+                    status,
+                    headers,
+                    Bytes::from_static(MALFORMED_JSON),
+                ))
+            }
+        }
+    }
+}
+
+/// Builds a JSON response, dropping a `Content-Length` that would no longer match the body.
+fn response(status: StatusCode, mut headers: http::HeaderMap, body: Bytes) -> Response {
+    headers.remove(CONTENT_LENGTH);
+    headers.insert(
+        CONTENT_TYPE,
+        "application/json".parse().expect("valid header"),
+    );
+
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    *response.headers_mut() = headers;
+    Response::from(response)
+}
+
+/// Local sockets that fail connections the way a misbehaving network does, so that timeouts
+/// and resets are genuine `reqwest` errors.
+#[derive(Debug)]
+struct FaultSockets {
+    /// Accepts connections and never answers.
+    hang: SocketAddr,
+    /// Reads the request, then resets the connection.
+    reset: SocketAddr,
+    http: reqwest::Client,
+}
+
+impl FaultInjector {
+    async fn sockets(&self) -> Result<&FaultSockets> {
+        self.sockets
+            .get_or_try_init(|| async {
+                let hang = TcpListener::bind("127.0.0.1:0").await?;
+                let reset = TcpListener::bind("127.0.0.1:0").await?;
+                let sockets = FaultSockets {
+                    hang: hang.local_addr()?,
+                    reset: reset.local_addr()?,
+                    // Without pooling, every request gets a connection of its own
+                    http: reqwest::Client::builder()
+                        .pool_max_idle_per_host(0)
+                        .no_proxy()
+                        .build()?,
+                };
+
+                tokio::spawn(async move {
+                    while let Ok((stream, _)) = hang.accept().await {
+                        tokio::spawn(drain(stream));
+                    }
+                });
+                tokio::spawn(async move {
+                    while let Ok((mut stream, _)) = reset.accept().await {
+                        let _ = stream.read(&mut [0; 1024]).await;
+                        // A zero linger resets the connection on drop instead of blocking
+                        #[allow(deprecated)]
+                        let _ = stream.set_linger(Some(Duration::ZERO));
+                    }
+                });
+
+                Ok(sockets)
+            })
+            .await
+    }
+}
+
+/// Reads from `stream` until the client gives up on it.
+async fn drain(mut stream: TcpStream) {
+    let _ = tokio::io::copy(&mut stream, &mut tokio::io::sink()).await;
+}
+
+/// Returns whether some trailing part of `path`, starting at a `/`, matches `pattern`.
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_end_matches('/');
+    let path = path.trim_end_matches('/');
+    path.match_indices('/')
+        .any(|(start, _)| glob(pattern.as_bytes(), &path.as_bytes()[start..]))
+}
+
+/// Matches `text` against `pattern`, in which `*` stands for any run of bytes.
+fn glob(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|skip| glob(rest, &text[skip..])),
+        Some((byte, rest)) => text
+            .split_first()
+            .is_some_and(|(first, text)| first == byte && glob(rest, text)),
+    }
+}
@@ -1,7 +1,45 @@
+mod api_error;
+mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod builder;
+mod cache;
+mod cassette;
+mod circuit;
 mod client;
 mod config;
 mod error;
+mod fault;
+mod metrics;
+mod middleware;
+mod options;
+mod paged;
+mod rate_limit;
+mod redaction;
+mod retry;
+mod stream;
+mod transport;
 
+pub use api_error::ApiErrorBody;
+pub use async_trait::async_trait;
+pub use auth::*;
+pub use builder::*;
+pub use cache::ResponseCache;
+pub use cassette::{Cassette, CassetteMode};
+pub use circuit::{CircuitBreaker, CircuitBreakerConfig, CircuitState, CircuitStats};
 pub use client::*;
 pub use config::*;
 pub use error::*;
+pub use fault::{Fault, FaultInjector, FaultRule};
+pub use metrics::Metrics;
+pub use middleware::{Middleware, RequestParts};
+pub use options::RequestOptions;
+pub use paged::{Paged, ParallelPages};
+pub use rate_limit::{RateLimit, RateLimitStats, RateLimiter};
+pub use redaction::Redaction;
+pub use retry::*;
+pub use stream::PageStream;
+pub use transport::{MemoryResponse, MemoryTransport, ReqwestTransport, Transport};
+
+pub use reqwest::{Method, Request, Response};
+pub use tokio_util::sync::CancellationToken;
@@ -0,0 +1,339 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use reqwest::{Method, Response};
+
+use crate::{Error, Result};
+
+/// The bucket bounds of the latency histogram, in seconds, unless `Metrics::buckets` says otherwise.
+const DEFAULT_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// The counters kept by `Metrics`, with their help texts, in the order they are rendered.
+const COUNTERS: [(&str, &str); 5] = [
+    (
+        "elation_requests_total",
+        "Calls to the Elation API by endpoint, method and status class.",
+    ),
+    (
+        "elation_retries_total",
+        "Requests repeated according to the client's retry policy.",
+    ),
+    (
+        "elation_rate_limit_waits_total",
+        "Requests that waited for the client's rate limiter.",
+    ),
+    (
+        "elation_rate_limit_wait_seconds_total",
+        "Time spent waiting for the client's rate limiter.",
+    ),
+    (
+        "elation_cache_requests_total",
+        "GET requests for cached resources, by whether the response cache answered them.",
+    ),
+];
+
+/// The name and help text of the latency histogram.
+const DURATION: (&str, &str) = (
+    "elation_request_duration_seconds",
+    "Latency of calls to the Elation API, retries and waits included.",
+);
+
+/// The label names and values of one series.
+type Labels = Vec<(&'static str, String)>;
+
+/// An opt-in, in-process registry of API usage metrics in the Prometheus text format.
+///
+/// A client built with [`ClientBuilder::metrics`](crate::ClientBuilder::metrics) records:
+///
+/// * `elation_requests_total` and the `elation_request_duration_seconds` histogram, by
+///   `endpoint`, `method` and `status_class`, e.g. `2xx`, or `error` when no response arrived.
+///   Cache hits never reach the API and are only counted below.
+/// * `elation_retries_total`, by `endpoint` and `method`.
+/// * `elation_rate_limit_waits_total` and `elation_rate_limit_wait_seconds_total`, by
+///   `endpoint` and `method`.
+/// * `elation_cache_requests_total`, by `endpoint` and `result`, which is `hit`,
+///   `revalidated` or `miss`.
+///
+/// Endpoints are recorded as templates such as `/patients/{id}/`, without query strings, so
+/// no PHI ends up in the metrics. Clones share their series, so one registry can collect the
+/// metrics of every client of an application and be served from a single scrape endpoint.
+///
+/// # Example
+///
+/// ```rust
+/// use client::{Client, Metrics};
+///
+/// let metrics = Metrics::new();
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token("my-access-token")
+///     .metrics(metrics.clone())
+///     .build()
+///     .unwrap();
+///
+/// // Serve this from the `/metrics` route of your service
+/// let body = metrics.render();
+/// assert!(body.contains("# TYPE elation_requests_total counter"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Metrics {
+    buckets: Arc<[f64]>,
+    registry: Arc<Mutex<Registry>>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    /// The content type of the text `render` returns.
+    pub const CONTENT_TYPE: &'static str = "text/plain; version=0.0.4; charset=utf-8";
+
+    /// Creates an empty registry with the default latency buckets, from 5ms to 10s.
+    pub fn new() -> Self {
+        Self {
+            buckets: Arc::new(DEFAULT_BUCKETS),
+            registry: Arc::default(),
+        }
+    }
+
+    /// Sets the upper bounds of the latency histogram's buckets, in seconds.
+    ///
+    /// This discards anything recorded so far.
+    pub fn buckets(mut self, mut buckets: Vec<f64>) -> Self {
+        buckets.retain(|bound| bound.is_finite());
+        buckets.sort_by(f64::total_cmp);
+        buckets.dedup();
+        self.buckets = buckets.into();
+        self.registry = Arc::default();
+        self
+    }
+
+    /// Returns the value of a counter series, or zero if it was never incremented.
+    ///
+    /// `labels` must list every label of the series, in any order.
+    pub fn counter(&self, name: &str, labels: &[(&str, &str)]) -> f64 {
+        self.registry()
+            .counters
+            .iter()
+            .find(|((counter, series), _)| *counter == name && same_labels(series, labels))
+            .map_or(0.0, |(_, value)| *value)
+    }
+
+    /// Renders every series in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let registry = self.registry();
+        let mut out = String::new();
+
+        for (name, help) in COUNTERS {
+            family_header(&mut out, name, help, "counter");
+            let series = registry
+                .counters
+                .iter()
+                .filter(|((counter, _), _)| *counter == name);
+            for ((_, labels), value) in series {
+                let _ = writeln!(out, "{name}{} {value}", format_labels(labels, None));
+            }
+        }
+
+        let (name, help) = DURATION;
+        family_header(&mut out, name, help, "histogram");
+        for (labels, histogram) in &registry.durations {
+            let mut cumulative = 0;
+            for (bound, count) in self.buckets.iter().zip(&histogram.buckets) {
+                cumulative += count;
+                let le = bound.to_string();
+                let _ = writeln!(
+                    out,
+                    "{name}_bucket{} {cumulative}",
+                    format_labels(labels, Some(&le))
+                );
+            }
+            let _ = writeln!(
+                out,
+                "{name}_bucket{} {}",
+                format_labels(labels, Some("+Inf")),
+                histogram.count
+            );
+            let _ = writeln!(
+                out,
+                "{name}_sum{} {}",
+                format_labels(labels, None),
+                histogram.sum
+            );
+            let _ = writeln!(
+                out,
+                "{name}_count{} {}",
+                format_labels(labels, None),
+                histogram.count
+            );
+        }
+
+        out
+    }
+
+    /// Records a completed call with its outcome and latency.
+    pub(crate) fn record_request(
+        &self,
+        endpoint: &str,
+        method: &Method,
+        result: &Result<Response>,
+        latency: Duration,
+    ) {
+        let labels = vec![
+            ("endpoint", endpoint.to_owned()),
+            ("method", method.to_string()),
+            ("status_class", status_class(result)),
+        ];
+        let mut registry = self.registry();
+        registry.increment("elation_requests_total", labels.clone(), 1.0);
+        registry
+            .durations
+            .entry(labels)
+            .or_insert_with(|| Histogram::new(self.buckets.len()))
+            .observe(&self.buckets, latency.as_secs_f64());
+    }
+
+    /// Records that a request is about to be retried.
+    pub(crate) fn record_retry(&self, endpoint: &str, method: &Method) {
+        self.registry().increment(
+            "elation_retries_total",
+            request_labels(endpoint, method),
+            1.0,
+        );
+    }
+
+    /// Records that a request waited `waited` for the rate limiter.
+    pub(crate) fn record_rate_limit_wait(&self, endpoint: &str, method: &Method, waited: Duration) {
+        let labels = request_labels(endpoint, method);
+        let mut registry = self.registry();
+        registry.increment("elation_rate_limit_waits_total", labels.clone(), 1.0);
+        registry.increment(
+            "elation_rate_limit_wait_seconds_total",
+            labels,
+            waited.as_secs_f64(),
+        );
+    }
+
+    /// Records how the response cache handled a `GET` request.
+    pub(crate) fn record_cache(&self, endpoint: &str, result: &'static str) {
+        let labels = vec![
+            ("endpoint", endpoint.to_owned()),
+            ("result", result.to_owned()),
+        ];
+        self.registry()
+            .increment("elation_cache_requests_total", labels, 1.0);
+    }
+
+    fn registry(&self) -> MutexGuard<'_, Registry> {
+        self.registry
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// The series recorded so far, sorted so that rendering is deterministic.
+#[derive(Debug, Default)]
+struct Registry {
+    counters: BTreeMap<(&'static str, Labels), f64>,
+    durations: BTreeMap<Labels, Histogram>,
+}
+
+impl Registry {
+    fn increment(&mut self, name: &'static str, labels: Labels, by: f64) {
+        *self.counters.entry((name, labels)).or_default() += by;
+    }
+}
+
+/// The observations of one histogram series, with per-bucket rather than cumulative counts.
+#[derive(Debug)]
+struct Histogram {
+    buckets: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(buckets: usize) -> Self {
+        Self {
+            buckets: vec![0; buckets],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, bounds: &[f64], value: f64) {
+        if let Some(index) = bounds.iter().position(|bound| value <= *bound) {
+            self.buckets[index] += 1;
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+/// Returns the status class of a call, e.g. `"2xx"`, or `"error"` if no response arrived.
+fn status_class(result: &Result<Response>) -> String {
+    let status = match result {
+        Ok(response) => Some(response.status().as_u16()),
+        Err(error) => match error.last_error() {
+            Error::ReqwestError(e) => e.status().map(|status| status.as_u16()),
+            error => error.api_error_body().map(|body| body.status),
+        },
+    };
+    match status {
+        Some(status) => format!("{}xx", status / 100),
+        None => "error".to_owned(),
+    }
+}
+
+fn request_labels(endpoint: &str, method: &Method) -> Labels {
+    vec![
+        ("endpoint", endpoint.to_owned()),
+        ("method", method.to_string()),
+    ]
+}
+
+fn same_labels(series: &Labels, labels: &[(&str, &str)]) -> bool {
+    series.len() == labels.len()
+        && labels.iter().all(|(name, value)| {
+            series
+                .iter()
+                .any(|(series_name, series_value)| series_name == name && series_value == value)
+        })
+}
+
+fn family_header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+/// Formats labels as `{name="value",...}`, appending `le` for histogram buckets.
+fn format_labels(labels: &Labels, le: Option<&str>) -> String {
+    let pairs = labels
+        .iter()
+        .map(|(name, value)| (*name, value.as_str()))
+        .chain(le.map(|le| ("le", le)))
+        .map(|(name, value)| format!("{name}=\"{}\"", escape(value)))
+        .collect::<Vec<_>>();
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+/// Escapes a label value as the text format requires.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', r"\\")
+        .replace('"', "\\\"")
+        .replace('\n', r"\n")
+}
@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use reqwest::{header::HeaderMap, Method, Request, Response};
+use url::Url;
+
+use crate::{Error, Result};
+
+/// A description of a request passed to the hooks of a [`Middleware`].
+///
+/// This is the request as built by the `Client`, before it was authorized and before
+/// any `before_request` hook changed it.
+#[derive(Debug, Clone)]
+pub struct RequestParts {
+    /// The HTTP method of the request.
+    pub method: Method,
+
+    /// The full URL of the request, including the query string.
+    pub url: Url,
+
+    /// The headers set on the request.
+    pub headers: HeaderMap,
+
+    /// The body of the request, if it has one.
+    pub body: Option<Bytes>,
+}
+
+impl From<&Request> for RequestParts {
+    fn from(request: &Request) -> Self {
+        Self {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(Bytes::copy_from_slice),
+        }
+    }
+}
+
+/// Cross-cutting behavior that runs around every request a `Client` sends.
+///
+/// Middleware is added with `ClientBuilder::middleware` and runs in the order it was added:
+/// `before_request` hooks run first to last, `after_response` hooks last to first. The hooks
+/// run for every attempt, so a request that is retried passes through them once per attempt.
+///
+/// Every hook has a default implementation that does nothing, so implementors only
+/// override the hooks they need.
+///
+/// # Example
+///
+/// ```rust
+/// use client::{async_trait, Client, Middleware, Request, Response, Result};
+/// use reqwest::header::HeaderValue;
+///
+/// struct CorrelationId(&'static str);
+///
+/// #[async_trait]
+/// impl Middleware for CorrelationId {
+///     async fn before_request(&self, request: &mut Request) -> Result<Option<Response>> {
+///         request
+///             .headers_mut()
+///             .insert("X-Correlation-Id", HeaderValue::from_static(self.0));
+///         Ok(None)
+///     }
+/// }
+///
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token("my-access-token")
+///     .middleware(CorrelationId("checkout-42"))
+///     .build()
+///     .unwrap();
+/// ```
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Called right before a request is sent, after the `Authorization` header was set.
+    ///
+    /// The request may be modified freely, e.g. to add headers or sign it. Returning a
+    /// response answers the request without sending it; the `after_response` hooks of the
+    /// middleware added before this one still see that response.
+    async fn before_request(&self, _request: &mut Request) -> Result<Option<Response>> {
+        Ok(None)
+    }
+
+    /// Called with every response, including error responses, before it is turned into a `Result`.
+    ///
+    /// The response may be inspected or replaced.
+    async fn after_response(
+        &self,
+        _request: &RequestParts,
+        response: Response,
+    ) -> Result<Response> {
+        Ok(response)
+    }
+
+    /// Called whenever an attempt to send a request fails, including with an error status.
+    async fn on_error(&self, _request: &RequestParts, _error: &Error) {}
+}
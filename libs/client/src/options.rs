@@ -0,0 +1,114 @@
+use std::{future::Future, time::Duration};
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Request,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::{Error, Result};
+
+/// Settings that apply to a single call rather than to every request of a `Client`.
+///
+/// Pass them to the `*_with` variants of the client and service methods, e.g.
+/// `Client::get_with` or `GetService::get_with`. The default options change nothing.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::{Duration, Instant};
+/// use client::{CancellationToken, Client, RequestOptions};
+/// use reqwest::header::{HeaderName, HeaderValue};
+///
+/// # async fn run(client: &Client) -> client::Result<()> {
+/// let cancel = CancellationToken::new();
+/// let options = RequestOptions::new()
+///     .timeout(Duration::from_secs(60))
+///     .deadline(Instant::now() + Duration::from_secs(120))
+///     .cancellation(cancel.clone())
+///     .header(
+///         HeaderName::from_static("x-correlation-id"),
+///         HeaderValue::from_static("report-42"),
+///     );
+///
+/// let report = client.get_with("/reports/42/", (), &options).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    timeout: Option<Duration>,
+    deadline: Option<std::time::Instant>,
+    cancellation: Option<CancellationToken>,
+    headers: HeaderMap,
+}
+
+impl RequestOptions {
+    /// Creates options that change nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a timeout for each attempt, replacing the timeout of the `ClientBuilder`.
+    ///
+    /// Like the client's timeout, this only applies to the default `ReqwestTransport`. A
+    /// timed out attempt is retried according to the client's `RetryPolicy`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Fails the call with `Error::DeadlineExceeded` if it has not completed by `deadline`,
+    /// counting every retry and rate limit wait.
+    pub fn deadline(mut self, deadline: std::time::Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Abandons the call with `Error::Cancelled` as soon as `token` is cancelled.
+    ///
+    /// A request already on the wire may still reach the API.
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Sends an additional header, replacing a default header of the same name.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Applies the headers and timeout to `request`.
+    pub(crate) fn apply(&self, request: &mut Request) {
+        for (name, value) in &self.headers {
+            request.headers_mut().insert(name, value.clone());
+        }
+        if let Some(timeout) = self.timeout {
+            *request.timeout_mut() = Some(timeout);
+        }
+    }
+
+    /// Runs `call`, giving up once the deadline passes or the call is cancelled.
+    pub(crate) async fn guard<T>(&self, call: impl Future<Output = Result<T>>) -> Result<T> {
+        let deadline = async {
+            match self.deadline {
+                Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                None => std::future::pending().await,
+            }
+        };
+        let cancelled = async {
+            match &self.cancellation {
+                Some(token) => token.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            biased;
+            _ = cancelled => Err(Error::Cancelled),
+            _ = deadline => Err(Error::DeadlineExceeded),
+            result = call => result,
+        }
+    }
+}
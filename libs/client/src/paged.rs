@@ -0,0 +1,178 @@
+use futures::{StreamExt, TryStreamExt};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{Client, PaginatedResponse, Params, RequestOptions, Result};
+
+/// Query parameters extended with `limit` and `offset`.
+///
+/// Most query parameter types have no pagination fields of their own. Wrapping them in
+/// `Paged` adds both, flattened next to the wrapped fields in the query string. When the
+/// wrapped type has `limit` or `offset` fields too, the values set on `Paged` take precedence.
+///
+/// # Example
+///
+/// ```rust
+/// use client::Paged;
+///
+/// #[derive(Debug, Default, serde::Serialize)]
+/// struct LabOrderQueryParams {
+///     patient: Option<i64>,
+/// }
+///
+/// let params = Paged::new(LabOrderQueryParams { patient: Some(42) })
+///     .with_limit(50)
+///     .with_offset(100);
+/// ```
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Paged<P> {
+    /// The wrapped query parameters.
+    #[serde(flatten)]
+    pub params: P,
+
+    /// The maximum number of results to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+
+    /// The number of results to skip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<usize>,
+}
+
+impl<P> Paged<P> {
+    /// Wraps `params` without a limit or offset.
+    pub fn new(params: P) -> Self {
+        Self {
+            params,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    /// Sets the maximum number of results to return.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the number of results to skip.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// Configuration for fetching the pages of a result set in parallel.
+#[derive(Debug, Clone)]
+pub struct ParallelPages {
+    /// The `limit` requested for each page.
+    ///
+    /// If the API caps pages at a smaller size, the size of the first page is used instead.
+    pub page_size: usize,
+
+    /// The maximum number of pages requested at the same time.
+    pub concurrency: usize,
+}
+
+impl Default for ParallelPages {
+    fn default() -> Self {
+        Self {
+            page_size: 100,
+            concurrency: 4,
+        }
+    }
+}
+
+impl Client {
+    /// Fetches all pages of a paginated resource, requesting several pages at a time.
+    ///
+    /// The first page is fetched on its own to learn the total `count`. The remaining
+    /// `limit`/`offset` windows are then fetched with at most `options.concurrency` requests
+    /// in flight, and their results are returned in the order the API lists them.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The API endpoint to fetch.
+    /// * `params` - The query parameters sent with every page.
+    /// * `options` - The page size and concurrency to use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any request fails.
+    pub async fn get_all_pages_parallel<T, P>(
+        &self,
+        endpoint: &str,
+        params: P,
+        options: &ParallelPages,
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+        P: Params + Clone,
+    {
+        self.get_all_pages_parallel_with(endpoint, params, options, &RequestOptions::default())
+            .await
+    }
+
+    /// Fetches all pages like [`Client::get_all_pages_parallel`], applying `request_options`
+    /// to every page request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any request fails, is cancelled, or misses its deadline.
+    pub async fn get_all_pages_parallel_with<T, P>(
+        &self,
+        endpoint: &str,
+        params: P,
+        options: &ParallelPages,
+        request_options: &RequestOptions,
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+        P: Params + Clone,
+    {
+        let first_page = Paged::new(params.clone())
+            .with_limit(options.page_size)
+            .with_offset(0);
+        let first = self
+            .get_page::<T, P>(endpoint, first_page, request_options)
+            .await?;
+        let page_size = first.results.len();
+        let mut results = first.results;
+
+        if first.next.is_none() || page_size == 0 {
+            return Ok(results);
+        }
+
+        let pages: Vec<PaginatedResponse<T>> =
+            futures::stream::iter((page_size..first.count).step_by(page_size))
+                .map(|offset| {
+                    let page = Paged::new(params.clone())
+                        .with_limit(page_size)
+                        .with_offset(offset);
+                    self.get_page::<T, P>(endpoint, page, request_options)
+                })
+                .buffered(options.concurrency.max(1))
+                .try_collect()
+                .await?;
+
+        results.reserve(first.count.saturating_sub(results.len()));
+        for page in pages {
+            results.extend(page.results);
+        }
+
+        Ok(results)
+    }
+
+    async fn get_page<T, P>(
+        &self,
+        endpoint: &str,
+        params: Paged<P>,
+        options: &RequestOptions,
+    ) -> Result<PaginatedResponse<T>>
+    where
+        T: DeserializeOwned,
+        P: Params,
+    {
+        let response = self.get_with(endpoint, params, options).await?;
+        Ok(response.json::<PaginatedResponse<T>>().await?)
+    }
+}
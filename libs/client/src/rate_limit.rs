@@ -0,0 +1,240 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime},
+};
+
+use reqwest::{header::RETRY_AFTER, Response};
+
+/// How long to back off after a `429 Too Many Requests` that carries no usable `Retry-After`.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// The slowest refill rate a limiter accepts, about one request every 17 minutes.
+const MIN_REQUESTS_PER_SECOND: f64 = 0.001;
+
+/// Configuration for the client-side rate limiter.
+///
+/// The limiter is a token bucket: it holds up to `burst` tokens, refills at
+/// `requests_per_second`, and every request takes one token, waiting when none are left.
+///
+/// # Example
+///
+/// ```rust
+/// use client::{Client, RateLimit};
+///
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token("my-access-token")
+///     .rate_limit(RateLimit {
+///         requests_per_second: 5.0,
+///         burst: 10,
+///         ..Default::default()
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    /// The sustained number of requests allowed per second.
+    pub requests_per_second: f64,
+
+    /// The number of requests that may be sent back to back before throttling starts.
+    pub burst: u32,
+
+    /// Whether each practice gets its own bucket instead of sharing one.
+    ///
+    /// Only has an effect for clients configured with `ClientBuilder::practice`.
+    pub per_practice: bool,
+
+    /// How many times a request answered with `429 Too Many Requests` is resent
+    /// after waiting out its `Retry-After`.
+    pub max_throttle_retries: u32,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 10.0,
+            burst: 10,
+            per_practice: false,
+            max_throttle_retries: 3,
+        }
+    }
+}
+
+/// A snapshot of a rate limiter bucket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitStats {
+    /// The number of requests that can be sent right now without waiting.
+    pub available: f64,
+
+    /// The maximum number of requests the bucket holds.
+    pub capacity: u32,
+
+    /// The sustained number of requests allowed per second.
+    pub requests_per_second: f64,
+
+    /// How long the bucket is paused for because of a `Retry-After`, if at all.
+    pub paused_for: Option<Duration>,
+
+    /// The number of requests that had to wait for a token.
+    pub throttled_requests: u64,
+
+    /// The total time requests have spent waiting for a token.
+    pub total_wait: Duration,
+
+    /// The number of `429 Too Many Requests` responses received.
+    pub rate_limited_responses: u64,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+    paused_until: Option<Instant>,
+    throttled_requests: u64,
+    total_wait: Duration,
+    rate_limited_responses: u64,
+}
+
+/// A token-bucket rate limiter that can be shared between clients.
+///
+/// Create one with [`RateLimiter::new`] and hand it to several clients through
+/// `ClientBuilder::rate_limiter` to make them draw from the same budget.
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimit,
+    buckets: Mutex<HashMap<Option<i64>, Bucket>>,
+}
+
+impl RateLimiter {
+    /// Creates a new limiter with the given configuration.
+    ///
+    /// A `burst` of 0 is raised to 1, and a `requests_per_second` that is not positive is
+    /// raised to the slowest rate the limiter supports, so that requests are never blocked
+    /// forever.
+    pub fn new(mut config: RateLimit) -> Self {
+        config.burst = config.burst.max(1);
+        config.requests_per_second = config.requests_per_second.max(MIN_REQUESTS_PER_SECOND);
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the limiter's configuration.
+    pub fn config(&self) -> &RateLimit {
+        &self.config
+    }
+
+    fn bucket_key(&self, practice: Option<i64>) -> Option<i64> {
+        practice.filter(|_| self.config.per_practice)
+    }
+
+    fn with_bucket<R>(&self, practice: Option<i64>, f: impl FnOnce(&mut Bucket) -> R) -> R {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let bucket = buckets
+            .entry(self.bucket_key(practice))
+            .or_insert_with(|| Bucket {
+                tokens: f64::from(self.config.burst),
+                last_refill: Instant::now(),
+                paused_until: None,
+                throttled_requests: 0,
+                total_wait: Duration::ZERO,
+                rate_limited_responses: 0,
+            });
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.config.requests_per_second)
+            .min(f64::from(self.config.burst));
+        bucket.last_refill = now;
+        if bucket.paused_until.is_some_and(|until| until <= now) {
+            bucket.paused_until = None;
+        }
+
+        f(bucket)
+    }
+
+    /// Waits until a request may be sent, then takes a token for it.
+    ///
+    /// Returns how long the caller had to wait.
+    pub async fn acquire(&self, practice: Option<i64>) -> Duration {
+        let mut waited = Duration::ZERO;
+
+        loop {
+            let wait = self.with_bucket(practice, |bucket| {
+                if let Some(until) = bucket.paused_until {
+                    return Some(until.saturating_duration_since(Instant::now()));
+                }
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    if !waited.is_zero() {
+                        bucket.throttled_requests += 1;
+                        bucket.total_wait += waited;
+                    }
+                    return None;
+                }
+                Some(Duration::from_secs_f64(
+                    (1.0 - bucket.tokens) / self.config.requests_per_second,
+                ))
+            });
+
+            match wait {
+                Some(wait) => {
+                    tokio::time::sleep(wait).await;
+                    waited += wait;
+                }
+                None => return waited,
+            }
+        }
+    }
+
+    /// Stops handing out tokens for `duration`, e.g. after a `429 Too Many Requests`.
+    pub fn pause(&self, practice: Option<i64>, duration: Duration) {
+        self.with_bucket(practice, |bucket| {
+            let until = Instant::now() + duration;
+            bucket.paused_until = Some(bucket.paused_until.map_or(until, |p| p.max(until)));
+            bucket.tokens = 0.0;
+            bucket.rate_limited_responses += 1;
+        });
+    }
+
+    /// Returns the current budget of the bucket used for `practice`.
+    pub fn stats(&self, practice: Option<i64>) -> RateLimitStats {
+        self.with_bucket(practice, |bucket| RateLimitStats {
+            available: bucket.tokens,
+            capacity: self.config.burst,
+            requests_per_second: self.config.requests_per_second,
+            paused_for: bucket
+                .paused_until
+                .map(|until| until.saturating_duration_since(Instant::now())),
+            throttled_requests: bucket.throttled_requests,
+            total_wait: bucket.total_wait,
+            rate_limited_responses: bucket.rate_limited_responses,
+        })
+    }
+}
+
+/// Reads how long to wait from the `Retry-After` header of a response.
+///
+/// Both the delay-seconds and HTTP-date forms are understood.
+pub(crate) fn retry_after(response: &Response) -> Duration {
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            value
+                .trim()
+                .parse::<u64>()
+                .map(Duration::from_secs)
+                .ok()
+                .or_else(|| {
+                    httpdate::parse_http_date(value)
+                        .ok()
+                        .map(|at| at.duration_since(SystemTime::now()).unwrap_or_default())
+                })
+        })
+        .unwrap_or(DEFAULT_RETRY_AFTER)
+}
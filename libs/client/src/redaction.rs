@@ -0,0 +1,261 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use serde_json::Value;
+use url::Url;
+
+/// The value PHI fields are replaced with.
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// Fields of the Elation models that hold protected health information.
+const DEFAULT_PHI_FIELDS: &[&str] = &[
+    "first_name",
+    "middle_name",
+    "last_name",
+    "actual_name",
+    "dob",
+    "ssn",
+    "address_line1",
+    "address_line2",
+    "city",
+    "zip",
+    "phone",
+    "email",
+    "member_id",
+    "group_id",
+    "notes",
+    "address",
+    "deceased_date",
+    "employer.name",
+];
+
+/// Controls what the client may write to its `tracing` spans and events.
+///
+/// By default, request and response bodies are never emitted. With
+/// [`Redaction::log_bodies`] enabled they are emitted at `DEBUG` level, and even then
+/// every field marked as PHI is replaced with `[REDACTED]`, however deeply it is nested.
+/// The same masking applies to the request body quoted in error messages.
+///
+/// # Example
+///
+/// ```rust
+/// use client::{Client, Redaction};
+///
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token("my-access-token")
+///     .redaction(Redaction::default().log_bodies(true).phi_field("medical_record_number"))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Redaction {
+    log_bodies: bool,
+    phi_fields: BTreeSet<String>,
+}
+
+impl Default for Redaction {
+    /// Bodies are not logged, and the PHI fields of the Elation patient models are masked.
+    fn default() -> Self {
+        Self {
+            log_bodies: false,
+            phi_fields: DEFAULT_PHI_FIELDS.iter().map(|&f| f.to_owned()).collect(),
+        }
+    }
+}
+
+impl Redaction {
+    /// Enables or disables emitting masked request and response bodies at `DEBUG` level.
+    pub fn log_bodies(mut self, log_bodies: bool) -> Self {
+        self.log_bodies = log_bodies;
+        self
+    }
+
+    /// Marks an additional field name as PHI.
+    ///
+    /// A name may be qualified by the field holding it, e.g. `"employer.name"`, to mask the
+    /// field only within that object.
+    pub fn phi_field(mut self, field: impl Into<String>) -> Self {
+        self.phi_fields.insert(field.into());
+        self
+    }
+
+    /// Returns whether bodies may be emitted at all.
+    pub fn logs_bodies(&self) -> bool {
+        self.log_bodies
+    }
+
+    /// Serializes `body` to JSON with every PHI field replaced by `[REDACTED]`.
+    pub fn mask<T: Serialize + ?Sized>(&self, body: &T) -> String {
+        match serde_json::to_value(body) {
+            Ok(mut value) => {
+                self.mask_value(&mut value, None);
+                value.to_string()
+            }
+            Err(_) => REDACTED.to_owned(),
+        }
+    }
+
+    /// Masks an error response body, which is only shown if it is JSON.
+    ///
+    /// The validation messages of PHI fields, lists of strings rather than the values
+    /// themselves, are kept.
+    pub(crate) fn mask_error_text(&self, text: &str) -> String {
+        match serde_json::from_str::<Value>(text) {
+            Ok(mut value) => {
+                self.mask_error_value(&mut value, None);
+                value.to_string()
+            }
+            Err(_) => REDACTED.to_owned(),
+        }
+    }
+
+    /// Replaces every PHI value of the request that `text`, a response body, echoes.
+    ///
+    /// The values are taken from the PHI fields of `body` and the PHI parameters of the
+    /// query string of `url`, so that messages such as `"This field is required."` survive
+    /// while a message quoting a submitted name or date of birth does not.
+    pub(crate) fn mask_echoes<T: Serialize + ?Sized>(
+        &self,
+        text: &str,
+        body: Option<&T>,
+        url: &Url,
+    ) -> String {
+        let mut echoes: Vec<String> = url
+            .query_pairs()
+            .filter(|(name, _)| self.is_phi(name))
+            .map(|(_, value)| value.into_owned())
+            .collect();
+        if let Some(body) = body.and_then(|body| serde_json::to_value(body).ok()) {
+            self.collect_phi(&body, None, false, &mut echoes);
+        }
+        echoes.retain(|echo| !echo.is_empty());
+        if echoes.is_empty() {
+            return text.to_owned();
+        }
+
+        match serde_json::from_str::<Value>(text) {
+            Ok(mut value) => {
+                mask_echoes(&mut value, &echoes);
+                value.to_string()
+            }
+            Err(_) => replace_echoes(text, &echoes),
+        }
+    }
+
+    /// Returns whether `field` is marked as PHI.
+    pub(crate) fn is_phi(&self, field: &str) -> bool {
+        self.phi_fields.contains(field)
+    }
+
+    /// Returns whether `field`, held by the field `parent`, if any, is marked as PHI.
+    pub(crate) fn is_phi_in(&self, parent: Option<&str>, field: &str) -> bool {
+        self.is_phi(field) || parent.is_some_and(|parent| self.is_phi(&format!("{parent}.{field}")))
+    }
+
+    fn collect_phi(
+        &self,
+        value: &Value,
+        parent: Option<&str>,
+        phi: bool,
+        values: &mut Vec<String>,
+    ) {
+        match value {
+            Value::Object(fields) => {
+                for (name, value) in fields {
+                    let phi = phi || self.is_phi_in(parent, name);
+                    self.collect_phi(value, Some(name), phi, values);
+                }
+            }
+            Value::Array(items) => items
+                .iter()
+                .for_each(|item| self.collect_phi(item, parent, phi, values)),
+            Value::String(text) if phi => values.push(text.clone()),
+            Value::Number(number) if phi => values.push(number.to_string()),
+            _ => {}
+        }
+    }
+
+    fn mask_error_value(&self, value: &mut Value, parent: Option<&str>) {
+        match value {
+            Value::Object(fields) => {
+                for (name, value) in fields {
+                    if self.is_phi_in(parent, name) && !is_messages(value) {
+                        *value = Value::String(REDACTED.to_owned());
+                    } else {
+                        self.mask_error_value(value, Some(name));
+                    }
+                }
+            }
+            Value::Array(items) => items
+                .iter_mut()
+                .for_each(|item| self.mask_error_value(item, parent)),
+            _ => {}
+        }
+    }
+
+    fn mask_value(&self, value: &mut Value, parent: Option<&str>) {
+        match value {
+            Value::Object(fields) => {
+                for (name, value) in fields {
+                    if self.is_phi_in(parent, name) {
+                        *value = Value::String(REDACTED.to_owned());
+                    } else {
+                        self.mask_value(value, Some(name));
+                    }
+                }
+            }
+            Value::Array(items) => items
+                .iter_mut()
+                .for_each(|item| self.mask_value(item, parent)),
+            _ => {}
+        }
+    }
+}
+
+/// Returns whether `value` is a list of validation messages.
+fn is_messages(value: &Value) -> bool {
+    matches!(value, Value::Array(items) if items.iter().all(Value::is_string))
+}
+
+/// Replaces `echoes` in every string of `value`, keys excepted.
+fn mask_echoes(value: &mut Value, echoes: &[String]) {
+    match value {
+        Value::Object(fields) => fields
+            .values_mut()
+            .for_each(|value| mask_echoes(value, echoes)),
+        Value::Array(items) => items.iter_mut().for_each(|item| mask_echoes(item, echoes)),
+        Value::String(text) => *text = replace_echoes(text, echoes),
+        _ => {}
+    }
+}
+
+fn replace_echoes(text: &str, echoes: &[String]) -> String {
+    echoes.iter().fold(text.to_owned(), |text, echo| {
+        text.replace(echo.as_str(), REDACTED)
+    })
+}
+
+/// Returns the path of `url` relative to `base`, with every numeric segment replaced by `{id}`.
+///
+/// Query strings are dropped, since search parameters such as names and dates of birth are PHI.
+pub(crate) fn endpoint_template(base: &Url, url: &Url) -> String {
+    relative_path(base, url)
+        .split('/')
+        .map(|segment| {
+            if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Returns the path of `url` relative to the path of `base`.
+pub(crate) fn relative_path<'a>(base: &Url, url: &'a Url) -> &'a str {
+    url.path()
+        .strip_prefix(base.path().trim_end_matches('/'))
+        .unwrap_or(url.path())
+}
@@ -0,0 +1,177 @@
+use std::{
+    fmt::Debug,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use reqwest::Method;
+
+use crate::Error;
+
+/// A predicate deciding whether a failed attempt should be retried.
+pub type RetryPredicate = Arc<dyn Fn(&Error) -> bool + Send + Sync>;
+
+/// Configuration for retrying failed requests.
+///
+/// Idempotent requests (GET, PUT, DELETE, HEAD, OPTIONS) are retried by default.
+/// POST and PATCH requests are only retried when [`RetryPolicy::retry_non_idempotent`]
+/// is enabled, since resending them may create duplicates.
+///
+/// Between attempts the client sleeps for an exponentially growing backoff, optionally
+/// randomized with full jitter, and gives up early once the overall deadline would be exceeded.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use client::{Client, RetryPolicy};
+///
+/// let client = Client::builder()
+///     .base_url("https://sandbox.elationemr.com/api/2.0/")
+///     .token("my-access-token")
+///     .retry_policy(
+///         RetryPolicy::default()
+///             .max_attempts(5)
+///             .deadline(Duration::from_secs(30)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: bool,
+    deadline: Option<Duration>,
+    retry_non_idempotent: bool,
+    retry_if: RetryPredicate,
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, starting at a 100ms backoff and doubling up to 5s, with jitter.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: true,
+            deadline: None,
+            retry_non_idempotent: false,
+            retry_if: Arc::new(Error::is_retryable),
+        }
+    }
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .field("deadline", &self.deadline)
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries. This is what a `Client` uses unless configured otherwise.
+    pub fn none() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// Sets the total number of attempts, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the backoff before the first retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the upper bound for any single backoff.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Sets the factor the backoff grows by after each attempt.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Enables or disables full jitter, i.e. sleeping a random duration up to the backoff.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the overall time budget for a request, including all retries and backoffs.
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Also retries POST and PATCH requests.
+    ///
+    /// Only enable this for endpoints where sending the same request twice is harmless.
+    pub fn retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Replaces the predicate deciding which errors are retried.
+    ///
+    /// The default is [`Error::is_retryable`].
+    pub fn retry_if(mut self, predicate: impl Fn(&Error) -> bool + Send + Sync + 'static) -> Self {
+        self.retry_if = Arc::new(predicate);
+        self
+    }
+
+    /// Returns whether requests with `method` may be retried under this policy.
+    pub fn applies_to(&self, method: &Method) -> bool {
+        self.max_attempts > 1
+            && (self.retry_non_idempotent || !matches!(*method, Method::POST | Method::PATCH))
+    }
+
+    /// Returns how long to wait before the next attempt, or `None` to give up.
+    ///
+    /// * `error` - The error the last attempt failed with.
+    /// * `attempts` - The number of attempts made so far.
+    /// * `started` - When the first attempt was made.
+    pub(crate) fn next_backoff(
+        &self,
+        error: &Error,
+        attempts: u32,
+        started: Instant,
+    ) -> Option<Duration> {
+        if attempts >= self.max_attempts || !(self.retry_if)(error) {
+            return None;
+        }
+
+        let exponent = i32::try_from(attempts - 1).unwrap_or(i32::MAX);
+        let backoff = Duration::from_secs_f64(
+            (self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent))
+                .min(self.max_backoff.as_secs_f64()),
+        );
+        let backoff = if self.jitter {
+            backoff.mul_f64(fastrand::f64())
+        } else {
+            backoff
+        };
+
+        match self.deadline {
+            Some(deadline) if started.elapsed() + backoff >= deadline => None,
+            _ => Some(backoff),
+        }
+    }
+}
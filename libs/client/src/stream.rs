@@ -0,0 +1,259 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use futures::{future::BoxFuture, FutureExt, Stream};
+use serde::de::DeserializeOwned;
+use tokio::task::JoinHandle;
+
+use crate::{Client, Error, PaginatedResponse, RequestOptions, Result};
+
+type KeyFn<'a, T> = Box<dyn Fn(&T) -> Option<String> + Send + Sync + 'a>;
+
+/// A `Stream` over every item of a paginated result set.
+///
+/// Pages are fetched lazily by following the `next` links of the API, so only one or two
+/// pages are held in memory at any time. Nothing is requested until the stream is first polled.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use client::Client;
+/// use futures::TryStreamExt;
+///
+/// # async fn run(client: &Client) -> client::Result<()> {
+/// let mut patients = client
+///     .stream_pages::<serde_json::Value>("/patients/")
+///     .prefetch(true)
+///     .max_items(1_000);
+///
+/// while let Some(patient) = patients.try_next().await? {
+///     println!("{}", patient["id"]);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PageStream<'a, T, E = Error> {
+    client: Client,
+    options: RequestOptions,
+    first: Option<BoxFuture<'a, core::result::Result<PaginatedResponse<T>, E>>>,
+    pending: Option<BoxFuture<'static, Result<PaginatedResponse<T>>>>,
+    buffer: VecDeque<T>,
+    next: Option<String>,
+    prefetch: bool,
+    max_items: Option<usize>,
+    dedup_key: Option<KeyFn<'a, T>>,
+    dedup: bool,
+    previous_keys: HashSet<String>,
+    current_keys: HashSet<String>,
+    yielded: usize,
+    done: bool,
+    _error: PhantomData<E>,
+}
+
+impl<'a, T, E> PageStream<'a, T, E>
+where
+    T: DeserializeOwned + Send + 'static,
+    E: From<Error>,
+{
+    /// Creates a stream that starts with the page returned by `first` and follows its `next` links.
+    ///
+    /// * `client` - The client used to fetch the following pages.
+    /// * `first` - A future resolving to the first page, e.g. a call to `FindService::find`.
+    pub fn new(
+        client: &Client,
+        first: impl Future<Output = core::result::Result<PaginatedResponse<T>, E>> + Send + 'a,
+    ) -> Self {
+        Self {
+            client: client.clone(),
+            options: RequestOptions::default(),
+            first: Some(first.boxed()),
+            pending: None,
+            buffer: VecDeque::new(),
+            next: None,
+            prefetch: false,
+            max_items: None,
+            dedup_key: None,
+            dedup: false,
+            previous_keys: HashSet::new(),
+            current_keys: HashSet::new(),
+            yielded: 0,
+            done: false,
+            _error: PhantomData,
+        }
+    }
+
+    /// Fetches the next page in the background while the current one is being consumed.
+    pub fn prefetch(mut self, prefetch: bool) -> Self {
+        self.prefetch = prefetch;
+        self
+    }
+
+    /// Fetches the following pages with `options`, e.g. to cancel the whole stream at once.
+    ///
+    /// The first page is fetched by the future given to [`PageStream::new`], which needs to
+    /// apply the options itself.
+    pub fn request_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Ends the stream after `max_items` items, without fetching any further pages.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Skips items whose key was already yielded from the current or the previous page.
+    ///
+    /// Offset pagination shifts when records are created or deleted while the pages are being
+    /// read, which can hand out the same record on two consecutive pages. Only the keys of
+    /// those two pages are remembered, so memory stays bounded however long the stream is.
+    /// Items without a key are never skipped.
+    pub fn dedup_by(mut self, key: impl Fn(&T) -> Option<String> + Send + Sync + 'a) -> Self {
+        self.dedup_key = Some(Box::new(key));
+        self.dedup = true;
+        self
+    }
+
+    /// Enables or disables deduplication by the key given to [`PageStream::dedup_by`].
+    pub fn dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    fn accept(&mut self, page: PaginatedResponse<T>) {
+        // Pages are only accepted once the previous one was consumed
+        self.previous_keys = std::mem::take(&mut self.current_keys);
+        self.buffer.extend(page.results);
+        self.next = page.next;
+        if self.prefetch {
+            self.fetch_next();
+        }
+    }
+
+    /// Starts fetching the page behind `next`, if there is one and it is not already underway.
+    fn fetch_next(&mut self) {
+        if self.pending.is_some() {
+            return;
+        }
+        let Some(url) = self.next.take() else {
+            return;
+        };
+
+        let client = self.client.clone();
+        let options = self.options.clone();
+        let fetch = async move {
+            let response = client.get_full_url_with(&url, &options).await?;
+            Ok(response.json::<PaginatedResponse<T>>().await?)
+        };
+
+        self.pending = Some(if self.prefetch {
+            Prefetch(tokio::spawn(fetch)).boxed()
+        } else {
+            fetch.boxed()
+        });
+    }
+
+    fn is_duplicate(&mut self, item: &T) -> bool {
+        let Some(key) = self.dedup_key.as_ref().filter(|_| self.dedup) else {
+            return false;
+        };
+        match key(item) {
+            Some(key) => self.previous_keys.contains(&key) || !self.current_keys.insert(key),
+            None => false,
+        }
+    }
+
+    fn finish(&mut self) {
+        self.done = true;
+        self.pending = None;
+        self.buffer.clear();
+    }
+}
+
+// Items are only ever moved out of the buffer, never pinned in place.
+impl<T, E> Unpin for PageStream<'_, T, E> {}
+
+impl<T, E> Stream for PageStream<'_, T, E>
+where
+    T: DeserializeOwned + Send + 'static,
+    E: From<Error>,
+{
+    type Item = core::result::Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.done {
+                return Poll::Ready(None);
+            }
+            if this.max_items.is_some_and(|max| this.yielded >= max) {
+                this.finish();
+                continue;
+            }
+
+            if let Some(item) = this.buffer.pop_front() {
+                if this.is_duplicate(&item) {
+                    continue;
+                }
+                this.yielded += 1;
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if let Some(first) = this.first.as_mut() {
+                let page = ready!(first.as_mut().poll(cx));
+                this.first = None;
+                match page {
+                    Ok(page) => this.accept(page),
+                    Err(e) => {
+                        this.finish();
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+                continue;
+            }
+
+            this.fetch_next();
+            let Some(pending) = this.pending.as_mut() else {
+                this.finish();
+                continue;
+            };
+
+            let page = ready!(pending.as_mut().poll(cx));
+            this.pending = None;
+            match page {
+                Ok(page) => this.accept(page),
+                Err(e) => {
+                    this.finish();
+                    return Poll::Ready(Some(Err(e.into())));
+                }
+            }
+        }
+    }
+}
+
+/// A page fetch running on its own task, aborted when the stream is dropped.
+struct Prefetch<T>(JoinHandle<Result<PaginatedResponse<T>>>);
+
+impl<T> Future for Prefetch<T> {
+    type Output = Result<PaginatedResponse<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match ready!(Pin::new(&mut self.0).poll(cx)) {
+            Ok(page) => Poll::Ready(page),
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    }
+}
+
+impl<T> Drop for Prefetch<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE},
+    Method, Request, Response, StatusCode,
+};
+use serde::Serialize;
+
+use crate::{RequestParts, Result};
+
+/// Sends requests on behalf of a `Client`: a request goes in, a status, headers and a body come out.
+///
+/// Everything else the client does, from authorization and rate limiting to retries and
+/// middleware, happens above the transport. By default requests go over the network through
+/// [`ReqwestTransport`]; [`MemoryTransport`] answers them in-process instead.
+///
+/// Timeouts, proxies and root certificates set on the `ClientBuilder` only apply to the
+/// default transport.
+#[async_trait]
+pub trait Transport: Send + Sync + Debug {
+    /// Sends `request` and returns the response, whatever its status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if no response was received at all.
+    async fn send(&self, request: Request) -> Result<Response>;
+}
+
+/// The default `Transport`, sending requests over HTTP with a `reqwest::Client`.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport that sends requests with `client`.
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: Request) -> Result<Response> {
+        Ok(self.client.execute(request).await?)
+    }
+}
+
+/// A canned response served by a [`MemoryTransport`].
+#[derive(Debug, Clone)]
+pub struct MemoryResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl MemoryResponse {
+    /// Creates an empty response with the given status.
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: Bytes::new(),
+        }
+    }
+
+    /// Creates a response with the given status and `body` serialized as JSON.
+    pub fn json<T: Serialize + ?Sized>(status: StatusCode, body: &T) -> Self {
+        Self::new(status)
+            .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+            .body(serde_json::to_vec(body).unwrap_or_default())
+    }
+
+    /// Sets a header of the response.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Sets the body of the response.
+    pub fn body(mut self, body: impl Into<Bytes>) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+impl From<MemoryResponse> for Response {
+    fn from(memory: MemoryResponse) -> Self {
+        let mut response = http::Response::new(memory.body);
+        *response.status_mut() = memory.status;
+        *response.headers_mut() = memory.headers;
+        Response::from(response)
+    }
+}
+
+/// A `Transport` that answers requests from responses programmed by a test, without any network.
+///
+/// Responses are registered for a method and a path, optionally followed by a query string,
+/// and answer every matching request until they are replaced. A path without a query string
+/// matches any query. Requests nothing was registered for are answered with
+/// `404 Not Found`, as the API would.
+///
+/// Every request is recorded, so tests can assert on what was sent. Clones share their
+/// responses and recorded requests.
+///
+/// # Example
+///
+/// ```rust
+/// use client::{Client, MemoryResponse, MemoryTransport};
+/// use reqwest::{Method, StatusCode};
+///
+/// # async fn run() -> client::Result<()> {
+/// let transport = MemoryTransport::new();
+/// transport.respond(
+///     Method::GET,
+///     "/patients/1/",
+///     MemoryResponse::json(StatusCode::OK, &serde_json::json!({ "id": 1 })),
+/// );
+///
+/// let client = Client::builder()
+///     .base_url("http://elation.test/")
+///     .token("12345")
+///     .transport(transport.clone())
+///     .build()?;
+///
+/// client.get("/patients/1/", ()).await?;
+/// assert_eq!(transport.requests().len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTransport {
+    state: Arc<Mutex<MemoryState>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    routes: Vec<(Method, String, MemoryResponse)>,
+    requests: Vec<RequestParts>,
+}
+
+impl MemoryTransport {
+    /// Creates a transport that answers every request with `404 Not Found`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers requests to `path`, e.g. `/patients/1/` or `/patients/?last_name=Doe`, with `response`.
+    ///
+    /// Replaces the response registered for the same method and path, if any.
+    pub fn respond(&self, method: Method, path: impl Into<String>, response: MemoryResponse) {
+        let path = path.into();
+        let mut state = self.state();
+        state
+            .routes
+            .retain(|(m, p, _)| !(*m == method && *p == path));
+        state.routes.push((method, path, response));
+    }
+
+    /// Returns every request received so far, in order.
+    pub fn requests(&self) -> Vec<RequestParts> {
+        self.state().requests.clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MemoryState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl Transport for MemoryTransport {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let url = request.url();
+        let with_query = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_owned(),
+        };
+
+        let mut state = self.state();
+        state.requests.push(RequestParts::from(&request));
+
+        // A route with a query string is more specific than one without
+        let route = state
+            .routes
+            .iter()
+            .filter(|(method, _, _)| method == request.method())
+            .find(|(_, path, _)| *path == with_query)
+            .or_else(|| {
+                state
+                    .routes
+                    .iter()
+                    .filter(|(method, _, _)| method == request.method())
+                    .find(|(_, path, _)| path == url.path())
+            });
+
+        Ok(match route {
+            Some((_, _, response)) => response.clone().into(),
+            None => MemoryResponse::json(
+                StatusCode::NOT_FOUND,
+                &serde_json::json!({ "detail": "Not found." }),
+            )
+            .into(),
+        })
+    }
+}
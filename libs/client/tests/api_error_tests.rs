@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use client::{Client, Error};
+    use httpmock::Method::{GET, POST};
+    use httpmock::MockServer;
+    use serde::Serialize;
+
+    #[derive(Debug, Serialize)]
+    struct LabOrderForCreate {
+        patient: Option<i64>,
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_validation_errors_are_parsed_per_field() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(POST).path("/lab_orders");
+            then.status(400)
+                .header("Content-Type", "application/json")
+                .header("X-Request-Id", "req-123")
+                .body(
+                    r#"{
+                        "patient": ["This field is required."],
+                        "content": { "tests": [{}, { "code": ["Unknown test code."] }] },
+                        "non_field_errors": ["The order is already signed."]
+                    }"#,
+                );
+        });
+
+        let client = client_for(&server);
+        let order = LabOrderForCreate { patient: None };
+
+        // Call the method under test
+        let error = client.post("/lab_orders", &order).await.unwrap_err();
+
+        // Assert the result
+        let Error::BadRequest(body) = &error else {
+            panic!("expected BadRequest, got {error:?}");
+        };
+        assert_eq!(body.status, 400);
+        assert_eq!(body.endpoint, "/lab_orders");
+        assert_eq!(body.request_id.as_deref(), Some("req-123"));
+        assert_eq!(body.field("patient"), ["This field is required."]);
+        assert_eq!(body.field("content.tests.1.code"), ["Unknown test code."]);
+        assert!(body.field("practice").is_empty());
+        assert_eq!(body.non_field_errors, ["The order is already signed."]);
+        assert_eq!(error.api_error_body(), Some(&**body));
+
+        // The human readable message is still available through Display
+        let display = error.to_string();
+        assert!(display.starts_with("BadRequest(\"Bad Request at '/lab_orders'"));
+        assert!(display.contains(r#"Body: {\"patient\":null}"#));
+    }
+
+    #[tokio::test]
+    async fn test_detail_and_plain_text_bodies() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/1/");
+            then.status(404)
+                .header("Content-Type", "application/json")
+                .body(r#"{ "detail": "Not found." }"#);
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/2/");
+            then.status(503).body("down for maintenance");
+        });
+
+        let client = client_for(&server);
+
+        // A DRF style `detail` is reported as a non-field error
+        let error = client.get("/patients/1/", ()).await.unwrap_err();
+        let body = error.api_error_body().unwrap();
+        assert!(matches!(error, Error::NotFound(_)));
+        assert_eq!(body.non_field_errors, ["Not found."]);
+        assert!(body.field_errors.is_empty());
+        assert_eq!(body.request_id, None);
+
+        // A body that is not JSON cannot be masked, so it is not kept
+        let error = client.get("/patients/2/", ()).await.unwrap_err();
+        let body = error.api_error_body().unwrap();
+        assert!(matches!(error, Error::ServiceUnavailable(_)));
+        assert!(!body.has_errors());
+        assert_eq!(body.raw, "[REDACTED]");
+    }
+
+    #[tokio::test]
+    async fn test_errors_do_not_quote_phi() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(400)
+                .header("Content-Type", "application/json")
+                .body(r#"{ "last_name": "Doe", "dob": ["Enter a valid date."] }"#);
+        });
+
+        let client = client_for(&server);
+
+        // Call the method under test
+        let error = client
+            .get_full_url(&server.url("/patients/?last_name=Doe&dob=1990-01-01"))
+            .await
+            .unwrap_err();
+
+        // Assert the result
+        let body = error.api_error_body().unwrap();
+        assert_eq!(body.endpoint, "/patients/");
+        // The echoed name is masked, the validation message of the date of birth is not
+        assert_eq!(body.field("last_name"), ["[REDACTED]"]);
+        assert_eq!(body.field("dob"), ["Enter a valid date."]);
+        for quoted in [
+            format!("{error}"),
+            format!("{error:?}"),
+            serde_json::to_string(&error).unwrap(),
+        ] {
+            assert!(!quoted.contains("Doe"), "{quoted}");
+            assert!(!quoted.contains("1990"), "{quoted}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validation_messages_of_phi_fields_survive() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(POST).path("/patients");
+            then.status(400)
+                .header("Content-Type", "application/json")
+                .body(
+                    r#"{
+                        "last_name": ["This field is required."],
+                        "dob": ["Date 1990-13-01 has the wrong format."]
+                    }"#,
+                );
+        });
+
+        let client = client_for(&server);
+
+        // Call the method under test
+        let error = client
+            .post(
+                "/patients",
+                &serde_json::json!({ "first_name": "Jane", "dob": "1990-13-01" }),
+            )
+            .await
+            .unwrap_err();
+
+        // Assert the result
+        let body = error.api_error_body().unwrap();
+        assert_eq!(body.field("last_name"), ["This field is required."]);
+        assert_eq!(body.field("dob"), ["Date [REDACTED] has the wrong format."]);
+        assert!(error.to_string().contains("This field is required."));
+        for quoted in [format!("{error}"), format!("{error:?}")] {
+            assert!(!quoted.contains("Jane"), "{quoted}");
+            assert!(!quoted.contains("1990"), "{quoted}");
+        }
+    }
+}
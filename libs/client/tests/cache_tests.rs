@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use client::{Client, ResponseCache};
+    use httpmock::Method::{GET, PATCH};
+    use httpmock::MockServer;
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    fn client_for(server: &MockServer, cache: ResponseCache) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .cache(cache)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fresh_responses_are_served_locally() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let vendors = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/1/");
+            then.status(200).body(r#"{"id":1,"name":"DLS"}"#);
+        });
+        let patients = server.mock(|when, then| {
+            when.method(GET).path("/patients/1/");
+            then.status(200).body(r#"{"id":1}"#);
+        });
+
+        let client = client_for(&server, ResponseCache::new().ttl("/lab_vendors", DAY));
+
+        for _ in 0..3 {
+            let response = client.get("/lab_vendors/1/", ()).await.unwrap();
+            assert_eq!(response.text().await.unwrap(), r#"{"id":1,"name":"DLS"}"#);
+            client.get("/patients/1/", ()).await.unwrap();
+        }
+
+        // Only the configured resource is cached
+        assert_eq!(vendors.hits_async().await, 1);
+        assert_eq!(patients.hits_async().await, 3);
+        assert_eq!(client.cache().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stale_responses_are_revalidated_with_etag() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let not_modified = server.mock(|when, then| {
+            when.method(GET)
+                .path("/appointment_types/")
+                .query_param("practice", "7")
+                .header("If-None-Match", "\"v1\"");
+            then.status(304);
+        });
+        let fetched = server.mock(|when, then| {
+            when.method(GET)
+                .path("/appointment_types/")
+                .query_param("practice", "7");
+            then.status(200)
+                .header("ETag", "\"v1\"")
+                .body(r#"{"results":[]}"#);
+        });
+
+        // Every response is stale right away
+        let client = client_for(
+            &server,
+            ResponseCache::new().ttl("/appointment_types", Duration::ZERO),
+        );
+
+        for _ in 0..2 {
+            let response = client
+                .get("/appointment_types/", HashMap::from([("practice", 7)]))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), 200);
+            assert_eq!(response.text().await.unwrap(), r#"{"results":[]}"#);
+        }
+
+        assert_eq!(fetched.hits_async().await, 1);
+        assert_eq!(not_modified.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_writes_and_invalidate_discard_cached_responses() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let get = server.mock(|when, then| {
+            when.method(GET).path("/imaging_centers/1/");
+            then.status(200).body(r#"{"id":1}"#);
+        });
+        server.mock(|when, then| {
+            when.method(PATCH).path("/imaging_centers/1/");
+            then.status(200).body(r#"{"id":1}"#);
+        });
+
+        let client = client_for(&server, ResponseCache::new().ttl("/imaging_centers", DAY));
+
+        client.get("/imaging_centers/1/", ()).await.unwrap();
+        client.get("/imaging_centers/1/", ()).await.unwrap();
+        assert_eq!(get.hits_async().await, 1);
+
+        // A successful write through the client invalidates the resource
+        client
+            .patch(
+                "/imaging_centers/1/",
+                &serde_json::json!({ "name": "North" }),
+            )
+            .await
+            .unwrap();
+        client.get("/imaging_centers/1/", ()).await.unwrap();
+        assert_eq!(get.hits_async().await, 2);
+
+        // And so does explicit invalidation
+        client.cache().unwrap().invalidate("/imaging_centers");
+        assert!(client.cache().unwrap().is_empty());
+        client.get("/imaging_centers/1/", ()).await.unwrap();
+        assert_eq!(get.hits_async().await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_least_recently_used_response_is_evicted() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let first = server.mock(|when, then| {
+            when.method(GET).path("/lab_order_tests/1/");
+            then.status(200).body(r#"{"id":1}"#);
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/lab_order_tests/2/");
+            then.status(200).body(r#"{"id":2}"#);
+        });
+        let third = server.mock(|when, then| {
+            when.method(GET).path("/lab_order_tests/3/");
+            then.status(200).body(r#"{"id":3}"#);
+        });
+
+        let client = client_for(
+            &server,
+            ResponseCache::new()
+                .capacity(2)
+                .ttl("/lab_order_tests", DAY),
+        );
+
+        for id in [1, 2, 1, 3, 1, 2] {
+            client
+                .get(&format!("/lab_order_tests/{id}/"), ())
+                .await
+                .unwrap();
+        }
+
+        // Fetching 3 evicted 2, which was used less recently than 1
+        assert_eq!(first.hits_async().await, 1);
+        assert_eq!(second.hits_async().await, 2);
+        assert_eq!(third.hits_async().await, 1);
+        assert_eq!(client.cache().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_disk_backend_survives_a_new_client() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir =
+            std::env::temp_dir().join(format!("elation-cache-{}-{nanos}", std::process::id()));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/cardiac_centers/1/");
+            then.status(200).body(r#"{"id":1}"#);
+        });
+
+        let cache = || {
+            ResponseCache::new()
+                .ttl("/cardiac_centers", DAY)
+                .on_disk(&dir)
+        };
+
+        let client = client_for(&server, cache());
+        client.get("/cardiac_centers/1/", ()).await.unwrap();
+
+        let restarted = client_for(&server, cache());
+        let response = restarted.get("/cardiac_centers/1/", ()).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), r#"{"id":1}"#);
+        assert_eq!(mock.hits_async().await, 1);
+
+        // Invalidation removes the files as well
+        restarted.cache().unwrap().invalidate_all();
+        client_for(&server, cache())
+            .get("/cardiac_centers/1/", ())
+            .await
+            .unwrap();
+        assert_eq!(mock.hits_async().await, 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
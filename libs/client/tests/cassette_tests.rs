@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use client::{Cassette, Client, Error};
+    use httpmock::Method::{GET, POST};
+    use httpmock::MockServer;
+    use serde_json::{json, Value};
+
+    fn cassette_path(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!(
+            "elation-cassettes-{}-{nanos}/{name}.json",
+            std::process::id()
+        ))
+    }
+
+    fn client_with(base_url: &str, cassette: &Cassette) -> Client {
+        Client::builder()
+            .base_url(base_url)
+            .token("secret-token")
+            .middleware(cassette.clone())
+            .build()
+            .unwrap()
+    }
+
+    /// Records a search and a create against a mock server into a new cassette.
+    async fn record(path: &PathBuf) {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/")
+                .query_param("last_name", "Doe");
+            then.status(200)
+                .header("Set-Cookie", "session=abc")
+                .body(
+                    r#"{"results":[{"id":7,"first_name":"Jane","last_name":"Doe","dob":"1970-01-01","sex":"Female"}],"next":null,"previous":null,"count":1}"#,
+                );
+        });
+        server.mock(|when, then| {
+            when.method(POST).path("/allergies");
+            then.status(201).body(r#"{"id":9,"name":"Peanuts"}"#);
+        });
+
+        let cassette = Cassette::record(path);
+        let client = client_with(&server.base_url(), &cassette);
+
+        let response = client
+            .get("/patients/", HashMap::from([("last_name", "Doe")]))
+            .await
+            .unwrap();
+        // The caller still gets the unscrubbed response while recording
+        assert!(response.text().await.unwrap().contains("Jane"));
+
+        client
+            .post("/allergies", &json!({ "patient": 7, "name": "Peanuts" }))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_recorded_cassette_is_scrubbed() {
+        let path = cassette_path("scrubbed");
+        record(&path).await;
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        for secret in ["secret-token", "session=abc", "Jane", "Doe", "1970-01-01"] {
+            assert!(!saved.contains(secret), "{secret} was recorded: {saved}");
+        }
+
+        let saved: Value = serde_json::from_str(&saved).unwrap();
+        let search = &saved["interactions"][0];
+        assert_eq!(search["request"]["method"], "GET");
+        assert_eq!(search["request"]["query"], "last_name=%5BREDACTED%5D");
+        let patient = &search["response"]["body"]["json"]["results"][0];
+        assert_eq!(patient["first_name"], "[REDACTED]");
+        assert_eq!(patient["dob"], "1900-01-01");
+        assert_eq!(patient["sex"], "Female");
+        assert_eq!(
+            saved["interactions"][1]["request"]["body"]["json"],
+            json!({ "patient": 7, "name": "Peanuts" })
+        );
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replay_serves_recorded_responses_without_network() {
+        let path = cassette_path("replay");
+        record(&path).await;
+
+        // Nothing listens on the discard port, so every request must come from the cassette
+        let cassette = Cassette::replay(&path).unwrap();
+        let client = client_with("http://127.0.0.1:9", &cassette);
+
+        let created = client
+            .post("/allergies", &json!({ "patient": 7, "name": "Peanuts" }))
+            .await
+            .unwrap();
+        assert_eq!(created.status(), 201);
+        assert_eq!(
+            created.text().await.unwrap(),
+            r#"{"id":9,"name":"Peanuts"}"#
+        );
+
+        let found: Value = client
+            .get("/patients/", HashMap::from([("last_name", "Doe")]))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(found["results"][0]["id"], 7);
+        assert_eq!(cassette.unplayed(), 0);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replay_fails_on_unmatched_requests() {
+        let path = cassette_path("unmatched");
+        record(&path).await;
+
+        let cassette = Cassette::replay(&path).unwrap();
+        let client = client_with("http://127.0.0.1:9", &cassette);
+
+        // A different body does not match
+        let error = client
+            .post("/allergies", &json!({ "patient": 7, "name": "Shellfish" }))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UnmatchedRequest { ref method, ref request }
+                if method == "POST" && request == "/allergies"
+        ));
+
+        // And a recorded interaction is only played once
+        client
+            .post("/allergies", &json!({ "patient": 7, "name": "Peanuts" }))
+            .await
+            .unwrap();
+        let error = client
+            .post("/allergies", &json!({ "patient": 7, "name": "Peanuts" }))
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), "UnmatchedRequest");
+        assert_eq!(cassette.unplayed(), 1);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
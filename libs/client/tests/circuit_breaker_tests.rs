@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use client::{CircuitBreaker, CircuitBreakerConfig, CircuitState, Client, Error, RetryPolicy};
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+
+    fn config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_rate: 0.5,
+            minimum_requests: 4,
+            open_for: Duration::from_millis(200),
+            ..Default::default()
+        }
+    }
+
+    fn client_for(server: &MockServer, breaker: Arc<CircuitBreaker>) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .shared_circuit_breaker(breaker)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_breaker_opens_at_failure_rate_and_fails_fast() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let healthy = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(200).body("{}");
+        });
+        let failing = server.mock(|when, then| {
+            when.method(GET).path("/appointments/");
+            then.status(503);
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .circuit_breaker(config())
+            .build()
+            .unwrap();
+
+        client.get("/patients/", ()).await.unwrap();
+        client.get("/patients/", ()).await.unwrap();
+        client.get("/appointments/", ()).await.unwrap_err();
+        assert_eq!(client.circuit_stats().unwrap().state, CircuitState::Closed);
+
+        // Two failures out of four requests reach the failure rate
+        client.get("/appointments/", ()).await.unwrap_err();
+        let stats = client.circuit_stats().unwrap();
+        assert_eq!(stats.state, CircuitState::Open);
+        assert_eq!(stats.times_opened, 1);
+
+        // Every resource fails fast while the breaker is open
+        let error = client.get("/patients/", ()).await.unwrap_err();
+        match error {
+            Error::CircuitOpen { retry_after } => {
+                assert!(retry_after <= Duration::from_millis(200))
+            }
+            other => panic!("expected CircuitOpen, got {other:?}"),
+        }
+        assert_eq!(healthy.hits_async().await, 2);
+        assert_eq!(failing.hits_async().await, 2);
+        assert_eq!(client.circuit_stats().unwrap().rejected_requests, 1);
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_do_not_trip_the_breaker() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/1/");
+            then.status(404).body(r#"{"detail":"Not found."}"#);
+        });
+
+        let breaker = Arc::new(CircuitBreaker::new(config()));
+        let client = client_for(&server, breaker.clone());
+
+        for _ in 0..10 {
+            let error = client.get("/patients/1/", ()).await.unwrap_err();
+            assert!(matches!(error, Error::NotFound(_)));
+        }
+
+        let stats = breaker.stats();
+        assert_eq!(stats.state, CircuitState::Closed);
+        assert_eq!((stats.requests, stats.failures), (10, 0));
+    }
+
+    #[tokio::test]
+    async fn test_half_open_probes_close_or_reopen_the_breaker() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let outage = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(500);
+        });
+
+        let breaker = Arc::new(CircuitBreaker::new(config()));
+        let client = client_for(&server, breaker.clone());
+
+        for _ in 0..4 {
+            client.get("/patients/", ()).await.unwrap_err();
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // A failing probe opens the breaker again
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        let error = client.get("/patients/", ()).await.unwrap_err();
+        assert!(matches!(error, Error::InternalServerError(_)));
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert_eq!(breaker.stats().times_opened, 2);
+
+        // A successful probe closes it
+        outage.delete_async().await;
+        let recovered = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(200).body("{}");
+        });
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        client.get("/patients/", ()).await.unwrap();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        client.get("/patients/", ()).await.unwrap();
+        assert_eq!(recovered.hits_async().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_zero_probes_still_lets_a_probe_through() {
+        let server = MockServer::start_async().await;
+
+        let outage = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(500);
+        });
+
+        let breaker = Arc::new(CircuitBreaker::new(CircuitBreakerConfig {
+            probes: 0,
+            ..config()
+        }));
+        assert_eq!(breaker.config().probes, 1);
+        let client = client_for(&server, breaker.clone());
+
+        for _ in 0..4 {
+            client.get("/patients/", ()).await.unwrap_err();
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        outage.delete_async().await;
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(200).body("{}");
+        });
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        client.get("/patients/", ()).await.unwrap();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_retries_stop_once_the_breaker_opens() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(503);
+        });
+
+        let breaker = Arc::new(CircuitBreaker::new(CircuitBreakerConfig {
+            minimum_requests: 2,
+            ..config()
+        }));
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .retry_policy(
+                RetryPolicy::default()
+                    .max_attempts(5)
+                    .initial_backoff(Duration::from_millis(1))
+                    .jitter(false),
+            )
+            .shared_circuit_breaker(breaker.clone())
+            .build()
+            .unwrap();
+
+        let error = client.get("/patients/", ()).await.unwrap_err();
+
+        assert_eq!(error.attempts(), 3);
+        assert!(matches!(error.last_error(), Error::CircuitOpen { .. }));
+        assert_eq!(mock.hits_async().await, 2);
+
+        // Clients sharing the breaker fail fast, too
+        let other = client_for(&server, breaker.clone());
+        let error = other.get("/patients/", ()).await.unwrap_err();
+        assert!(matches!(error, Error::CircuitOpen { .. }));
+
+        breaker.reset();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
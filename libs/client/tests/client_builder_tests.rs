@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use client::{Client, Error};
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use reqwest::header::{HeaderName, HeaderValue};
+
+    #[tokio::test]
+    async fn test_builder_keeps_base_url_path_and_sends_headers() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/2.0/patients/1/")
+                .header("Authorization", "Bearer abc")
+                .header("X-Correlation-Id", "corr-1");
+            then.status(200).body("{}");
+        });
+
+        let client = Client::builder()
+            .base_url(server.url("/api/2.0/"))
+            .token("abc")
+            .default_header(
+                HeaderName::from_static("x-correlation-id"),
+                HeaderValue::from_static("corr-1"),
+            )
+            .build()
+            .unwrap();
+
+        let result = client.get("/patients/1/", ()).await;
+
+        assert!(result.is_ok());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_clients_with_different_configs_coexist() {
+        let first_server = MockServer::start_async().await;
+        let second_server = MockServer::start_async().await;
+
+        let first_mock = first_server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/")
+                .query_param("patient", "1")
+                .query_param("patient", "2")
+                .header("Authorization", "Bearer first");
+            then.status(200).body("{}");
+        });
+        let second_mock = second_server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/")
+                .header("Authorization", "Bearer second");
+            then.status(200).body("{}");
+        });
+
+        let first = Client::builder()
+            .base_url(first_server.base_url())
+            .token("first")
+            .build()
+            .unwrap();
+        let second = Client::builder()
+            .base_url(second_server.base_url())
+            .token("second")
+            .build()
+            .unwrap();
+
+        let (first_result, second_result) = tokio::join!(
+            first.get("/patients/", serde_json::json!({ "patient": [1, 2] })),
+            second.get("/patients/", ())
+        );
+
+        assert!(first_result.is_ok());
+        assert!(second_result.is_ok());
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_build_without_base_url_fails() {
+        let result = Client::builder().token("abc").build();
+
+        assert!(matches!(result, Err(Error::MissingBaseUrl)));
+    }
+}
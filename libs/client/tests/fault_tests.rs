@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use client::{Client, Error, Fault, FaultInjector, FaultRule, RetryPolicy};
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use reqwest::{Method, StatusCode};
+    use serde_json::Value;
+
+    fn client_for(server: &MockServer, faults: &FaultInjector) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .faults(faults.clone())
+            .build()
+            .unwrap()
+    }
+
+    fn mock_patient(server: &MockServer) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/1/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(r#"{"id":1,"first_name":"Jane"}"#);
+        })
+    }
+
+    #[tokio::test]
+    async fn test_injected_status_is_retried_like_a_real_one() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+        let mock = mock_patient(&server);
+
+        let faults = FaultInjector::new().rule(
+            FaultRule::new(Fault::Status(StatusCode::SERVICE_UNAVAILABLE))
+                .endpoint("/patients/*")
+                .times(2),
+        );
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .retry_policy(RetryPolicy::default().initial_backoff(Duration::from_millis(1)))
+            .faults(faults.clone())
+            .build()
+            .unwrap();
+
+        // Two attempts fail before reaching the server, the third goes through
+        let response = client.get("/patients/1/", ()).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(faults.injected(), 2);
+        assert_eq!(mock.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_injected_statuses_map_to_error_variants() {
+        let server = MockServer::start_async().await;
+        mock_patient(&server);
+
+        let faults = FaultInjector::new()
+            .rule(
+                FaultRule::new(Fault::TooManyRequests {
+                    retry_after: Duration::from_secs(7),
+                })
+                .nth(1),
+            )
+            .rule(FaultRule::new(Fault::Status(StatusCode::INTERNAL_SERVER_ERROR)).nth(2));
+        let client = client_for(&server, &faults);
+
+        let error = client.get("/patients/1/", ()).await.unwrap_err();
+        assert!(matches!(error, Error::TooManyRequests(_)));
+        assert!(error.api_error_body().unwrap().raw.contains("7 seconds"));
+
+        let error = client.get("/patients/1/", ()).await.unwrap_err();
+        assert!(matches!(error, Error::InternalServerError(_)));
+
+        // Both rules have fired on their call
+        client.get("/patients/1/", ()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_timeouts_and_resets_are_reqwest_errors() {
+        let server = MockServer::start_async().await;
+        let mock = mock_patient(&server);
+
+        let faults = FaultInjector::new()
+            .rule(
+                FaultRule::new(Fault::Timeout(Duration::from_millis(50)))
+                    .method(Method::GET)
+                    .nth(1),
+            )
+            .rule(FaultRule::new(Fault::ConnectionReset).nth(2));
+        let client = client_for(&server, &faults);
+
+        let error = client.get("/patients/1/", ()).await.unwrap_err();
+        assert!(matches!(error, Error::ReqwestError(ref e) if e.is_timeout()));
+        assert!(error.is_retryable());
+
+        let error = client.get("/patients/1/", ()).await.unwrap_err();
+        assert!(matches!(error, Error::ReqwestError(_)), "{error:?}");
+        assert!(error.is_retryable());
+
+        assert_eq!(mock.hits_async().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_broken_bodies_fail_to_parse() {
+        let server = MockServer::start_async().await;
+        let mock = mock_patient(&server);
+
+        let faults = FaultInjector::new()
+            .rule(FaultRule::new(Fault::TruncatedBody).nth(1))
+            .rule(FaultRule::new(Fault::MalformedJson).nth(2));
+        let client = client_for(&server, &faults);
+
+        for _ in 0..2 {
+            let response = client.get("/patients/1/", ()).await.unwrap();
+            assert_eq!(response.status(), 200);
+            let error = response.json::<Value>().await.unwrap_err();
+            assert!(error.is_decode());
+        }
+
+        // The requests did reach the server
+        assert_eq!(mock.hits_async().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_rules_only_apply_to_matching_requests() {
+        let server = MockServer::start_async().await;
+        let patients = mock_patient(&server);
+        let vendors = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/1/");
+            then.status(200).body(r#"{"id":1}"#);
+        });
+
+        let faults = FaultInjector::new()
+            .rule(FaultRule::new(Fault::Latency(Duration::from_millis(20))).endpoint("/patients/*"))
+            .rule(
+                FaultRule::new(Fault::Status(StatusCode::BAD_GATEWAY))
+                    .endpoint("/lab_vendors/*")
+                    .method(Method::POST),
+            );
+        let client = client_for(&server, &faults);
+
+        let started = std::time::Instant::now();
+        client.get("/patients/1/", ()).await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(20));
+
+        // A GET is not affected by a rule for POSTs
+        client.get("/lab_vendors/1/", ()).await.unwrap();
+
+        assert_eq!(faults.injected(), 1);
+        assert_eq!(patients.hits_async().await, 1);
+        assert_eq!(vendors.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_clones_count_their_own_rules() {
+        let server = MockServer::start_async().await;
+        let vendors = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/1/");
+            then.status(200).body(r#"{"id":1}"#);
+        });
+
+        let base = FaultInjector::new();
+        let first = base.clone().rule(
+            FaultRule::new(Fault::Status(StatusCode::SERVICE_UNAVAILABLE))
+                .endpoint("/lab_vendors/*")
+                .times(1),
+        );
+        let second = base.rule(
+            FaultRule::new(Fault::Status(StatusCode::BAD_GATEWAY))
+                .endpoint("/lab_vendors/*")
+                .times(1),
+        );
+
+        // Firing the first clone's rule leaves the second clone's rule untouched
+        let error = client_for(&server, &first)
+            .get("/lab_vendors/1/", ())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::ServiceUnavailable(_)));
+        let error = client_for(&server, &second)
+            .get("/lab_vendors/1/", ())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::BadGateway(_)));
+
+        client_for(&server, &second)
+            .get("/lab_vendors/1/", ())
+            .await
+            .unwrap();
+        assert_eq!(vendors.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_seeded_probability_is_reproducible() {
+        let server = MockServer::start_async().await;
+        mock_patient(&server);
+
+        let server = &server;
+        let outcomes = |seed| async move {
+            let faults = FaultInjector::new().seed(seed).rule(
+                FaultRule::new(Fault::Status(StatusCode::SERVICE_UNAVAILABLE)).probability(0.5),
+            );
+            let client = client_for(server, &faults);
+            let mut outcomes = Vec::new();
+            for _ in 0..20 {
+                outcomes.push(client.get("/patients/1/", ()).await.is_ok());
+            }
+            outcomes
+        };
+
+        let first = outcomes(7).await;
+        assert_eq!(first, outcomes(7).await);
+        assert!(first.contains(&true) && first.contains(&false));
+    }
+}
@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use client::{Client, Metrics, RateLimit, ResponseCache, RetryPolicy};
+    use httpmock::Method::{GET, POST};
+    use httpmock::MockServer;
+
+    #[tokio::test]
+    async fn test_requests_are_counted_by_endpoint_method_and_status_class() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/1/");
+            then.status(200).body(r#"{"id":1}"#);
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/2/");
+            then.status(404).body(r#"{"detail":"Not found."}"#);
+        });
+        server.mock(|when, then| {
+            when.method(POST).path("/allergies");
+            then.status(201).body(r#"{"id":3}"#);
+        });
+
+        let metrics = Metrics::new();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        client.get("/patients/1/", ()).await.unwrap();
+        client.get("/patients/1/", ()).await.unwrap();
+        client.get("/patients/2/", ()).await.unwrap_err();
+        client.post("/allergies", &()).await.unwrap();
+
+        let requests = |endpoint, method, status_class| {
+            metrics.counter(
+                "elation_requests_total",
+                &[
+                    ("endpoint", endpoint),
+                    ("method", method),
+                    ("status_class", status_class),
+                ],
+            )
+        };
+        // Ids are folded into the endpoint template
+        assert_eq!(requests("/patients/{id}/", "GET", "2xx"), 2.0);
+        assert_eq!(requests("/patients/{id}/", "GET", "4xx"), 1.0);
+        assert_eq!(requests("/allergies", "POST", "2xx"), 1.0);
+
+        let body = metrics.render();
+        assert!(body.contains("# TYPE elation_request_duration_seconds histogram"));
+        assert!(body.contains(
+            r#"elation_request_duration_seconds_bucket{endpoint="/patients/{id}/",method="GET",status_class="2xx",le="+Inf"} 2"#
+        ));
+        assert!(body.contains(
+            r#"elation_request_duration_seconds_count{endpoint="/allergies",method="POST",status_class="2xx"} 1"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_retries_and_connection_failures_are_recorded() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/");
+            then.status(503);
+        });
+
+        let metrics = Metrics::new();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .retry_policy(
+                RetryPolicy::default()
+                    .max_attempts(3)
+                    .initial_backoff(Duration::from_millis(1))
+                    .jitter(false),
+            )
+            .metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        client.get("/lab_vendors/", ()).await.unwrap_err();
+
+        let labels = [("endpoint", "/lab_vendors/"), ("method", "GET")];
+        assert_eq!(metrics.counter("elation_retries_total", &labels), 2.0);
+        assert_eq!(
+            metrics.counter(
+                "elation_requests_total",
+                &[labels[0], labels[1], ("status_class", "5xx")]
+            ),
+            1.0
+        );
+
+        // Nothing listens on the discard port, so no response ever arrives
+        let unreachable = Client::builder()
+            .base_url("http://127.0.0.1:9/")
+            .token("12345")
+            .metrics(metrics.clone())
+            .build()
+            .unwrap();
+        unreachable.get("/lab_vendors/", ()).await.unwrap_err();
+
+        assert_eq!(
+            metrics.counter(
+                "elation_requests_total",
+                &[labels[0], labels[1], ("status_class", "error")]
+            ),
+            1.0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_waits_and_cache_hits_are_recorded() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/1/");
+            then.status(200).body(r#"{"id":1}"#);
+        });
+
+        let metrics = Metrics::new();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .rate_limit(RateLimit {
+                requests_per_second: 20.0,
+                burst: 1,
+                ..Default::default()
+            })
+            .cache(ResponseCache::new().ttl("/lab_vendors", Duration::from_secs(60)))
+            .metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        for _ in 0..3 {
+            client.get("/lab_vendors/1/", ()).await.unwrap();
+        }
+        // A different vendor is not cached yet, and has to wait for the limiter
+        client.get("/lab_vendors/2/", ()).await.unwrap_err();
+
+        let endpoint = ("endpoint", "/lab_vendors/{id}/");
+        let cache = |result| {
+            metrics.counter(
+                "elation_cache_requests_total",
+                &[endpoint, ("result", result)],
+            )
+        };
+        assert_eq!(cache("miss"), 1.0);
+        assert_eq!(cache("hit"), 2.0);
+
+        // Only the miss reached the API
+        assert_eq!(
+            metrics.counter(
+                "elation_requests_total",
+                &[endpoint, ("method", "GET"), ("status_class", "2xx")]
+            ),
+            1.0
+        );
+
+        let waits = [endpoint, ("method", "GET")];
+        assert_eq!(
+            metrics.counter("elation_rate_limit_waits_total", &waits),
+            1.0
+        );
+        assert!(metrics.counter("elation_rate_limit_wait_seconds_total", &waits) > 0.0);
+    }
+
+    #[test]
+    fn test_render_without_requests_lists_every_family() {
+        let body = Metrics::new().buckets(vec![1.0, 0.1]).render();
+
+        for family in [
+            "elation_requests_total counter",
+            "elation_retries_total counter",
+            "elation_rate_limit_waits_total counter",
+            "elation_rate_limit_wait_seconds_total counter",
+            "elation_cache_requests_total counter",
+            "elation_request_duration_seconds histogram",
+        ] {
+            assert!(body.contains(&format!("# TYPE {family}\n")), "{family}");
+        }
+        assert_eq!(
+            Metrics::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8"
+        );
+    }
+}
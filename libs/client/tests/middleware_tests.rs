@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use client::{async_trait, Client, Error, Middleware, Request, RequestParts, Response, Result};
+    use httpmock::Method::{GET, POST};
+    use httpmock::MockServer;
+    use reqwest::header::HeaderValue;
+
+    /// Records every hook call as a line in a shared log.
+    struct Recorder {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Middleware for Recorder {
+        async fn before_request(&self, request: &mut Request) -> Result<Option<Response>> {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} before", self.name));
+            request
+                .headers_mut()
+                .append("X-Middleware", HeaderValue::from_static(self.name));
+            Ok(None)
+        }
+
+        async fn after_response(
+            &self,
+            request: &RequestParts,
+            response: Response,
+        ) -> Result<Response> {
+            self.log.lock().unwrap().push(format!(
+                "{} after {} {} {}",
+                self.name,
+                request.method,
+                request.url.path(),
+                response.status().as_u16()
+            ));
+            Ok(response)
+        }
+
+        async fn on_error(&self, request: &RequestParts, error: &Error) {
+            let body = request
+                .body
+                .as_ref()
+                .map(|body| String::from_utf8_lossy(body).into_owned())
+                .unwrap_or_default();
+            self.log.lock().unwrap().push(format!(
+                "{} error {} {}",
+                self.name,
+                body,
+                error.api_error_body().map_or(0, |body| body.status)
+            ));
+        }
+    }
+
+    /// Answers every GET request locally with an empty JSON object.
+    struct Canned;
+
+    #[async_trait]
+    impl Middleware for Canned {
+        async fn before_request(&self, request: &mut Request) -> Result<Option<Response>> {
+            if request.method() != reqwest::Method::GET {
+                return Ok(None);
+            }
+            Ok(Some(Response::from(
+                http::Response::builder().status(200).body("{}").unwrap(),
+            )))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hooks_run_in_order_around_the_request() {
+        let server = MockServer::start_async().await;
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/1/")
+                .header("Authorization", "Bearer 12345")
+                .header("X-Middleware", "outer")
+                .header("X-Middleware", "inner");
+            then.status(200).body("{}");
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .middleware(Recorder {
+                name: "outer",
+                log: log.clone(),
+            })
+            .middleware(Recorder {
+                name: "inner",
+                log: log.clone(),
+            })
+            .build()
+            .unwrap();
+
+        client.get("/patients/1/", ()).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "outer before",
+                "inner before",
+                "inner after GET /patients/1/ 200",
+                "outer after GET /patients/1/ 200",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_before_request_can_answer_without_sending() {
+        let server = MockServer::start_async().await;
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/1/");
+            then.status(500);
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .middleware(Recorder {
+                name: "outer",
+                log: log.clone(),
+            })
+            .middleware(Canned)
+            .middleware(Recorder {
+                name: "inner",
+                log: log.clone(),
+            })
+            .build()
+            .unwrap();
+
+        let response = client.get("/lab_vendors/1/", ()).await.unwrap();
+
+        assert_eq!(response.text().await.unwrap(), "{}");
+        assert_eq!(mock.hits_async().await, 0);
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["outer before", "outer after GET /lab_vendors/1/ 200"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_on_error_sees_the_request_body() {
+        let server = MockServer::start_async().await;
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        server.mock(|when, then| {
+            when.method(POST).path("/allergies");
+            then.status(400)
+                .body(r#"{ "patient": ["This field is required."] }"#);
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .middleware(Recorder {
+                name: "recorder",
+                log: log.clone(),
+            })
+            .build()
+            .unwrap();
+
+        let error = client
+            .post("/allergies", &serde_json::json!({ "name": "Peanuts" }))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::BadRequest(_)));
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "recorder before",
+                "recorder after POST /allergies 400",
+                r#"recorder error {"name":"Peanuts"} 400"#,
+            ]
+        );
+    }
+}
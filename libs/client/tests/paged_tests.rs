@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use client::{Client, Paged, ParallelPages};
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Default, Serialize)]
+    struct ReportQueryParams {
+        patient: Option<i64>,
+        limit: Option<i32>,
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_paged_flattens_params_and_overrides_limit() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/reports/")
+                .query_param("patient", "42")
+                .query_param("limit", "5")
+                .query_param("offset", "10");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(r#"{ "results": [], "next": null, "previous": null, "count": 0 }"#);
+        });
+
+        let client = client_for(&server);
+        let params = Paged::new(ReportQueryParams {
+            patient: Some(42),
+            limit: Some(100),
+        })
+        .with_limit(5)
+        .with_offset(10);
+
+        // Call the method under test
+        let response = client.get("/reports/", params).await;
+
+        // Assert the result
+        assert!(response.is_ok());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_parallel_pages_follow_server_page_size() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        // The server caps pages at 2 items although 3 were requested
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/reports/")
+                .query_param("limit", "3")
+                .query_param("offset", "0");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(
+                    serde_json::json!({
+                        "results": [1, 2],
+                        "next": server.url("/reports/?limit=2&offset=2"),
+                        "previous": null,
+                        "count": 5,
+                    })
+                    .to_string(),
+                );
+        });
+        let rest: Vec<_> = [(2, vec![3, 4]), (4, vec![5])]
+            .into_iter()
+            .map(|(offset, ids)| {
+                server.mock(|when, then| {
+                    when.method(GET)
+                        .path("/reports/")
+                        .query_param("limit", "2")
+                        .query_param("offset", offset.to_string());
+                    then.status(200)
+                        .header("Content-Type", "application/json")
+                        .body(
+                            serde_json::json!({
+                                "results": ids,
+                                "next": null,
+                                "previous": null,
+                                "count": 5,
+                            })
+                            .to_string(),
+                        );
+                })
+            })
+            .collect();
+
+        let client = client_for(&server);
+        let options = ParallelPages {
+            page_size: 3,
+            concurrency: 2,
+        };
+
+        // Call the method under test
+        let ids: Vec<i64> = client
+            .get_all_pages_parallel("/reports/", (), &options)
+            .await
+            .unwrap();
+
+        // Assert the result
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+        first.assert_async().await;
+        for mock in rest {
+            mock.assert_async().await;
+        }
+    }
+}
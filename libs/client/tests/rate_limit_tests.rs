@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use client::{Client, Error, RateLimit, RateLimiter};
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+
+    #[tokio::test]
+    async fn test_requests_beyond_burst_are_queued() {
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(200).body("{}");
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .rate_limit(RateLimit {
+                requests_per_second: 10.0,
+                burst: 1,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        let started = Instant::now();
+        for _ in 0..3 {
+            client.get("/patients/", ()).await.unwrap();
+        }
+
+        // The first request uses the burst, the other two wait ~100ms each.
+        assert!(started.elapsed() >= Duration::from_millis(190));
+        mock.assert_hits_async(3).await;
+
+        let stats = client.rate_limit_stats().unwrap();
+        assert_eq!(stats.capacity, 1);
+        assert_eq!(stats.throttled_requests, 2);
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests_waits_for_retry_after() {
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(429)
+                .header("Retry-After", "1")
+                .body("slow down");
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .rate_limit(RateLimit {
+                max_throttle_retries: 1,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+
+        let started = Instant::now();
+        let result = client.get("/patients/", ()).await;
+
+        assert!(matches!(result, Err(Error::TooManyRequests(_))));
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        mock.assert_hits_async(2).await;
+        assert_eq!(client.rate_limit_stats().unwrap().rate_limited_responses, 1);
+    }
+
+    #[tokio::test]
+    async fn test_practices_have_separate_buckets() {
+        let limiter = Arc::new(RateLimiter::new(RateLimit {
+            requests_per_second: 1.0,
+            burst: 1,
+            per_practice: true,
+            ..Default::default()
+        }));
+
+        limiter.acquire(Some(1)).await;
+
+        assert!(limiter.stats(Some(1)).available < 1.0);
+        assert_eq!(limiter.stats(Some(2)).available, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_degenerate_config_is_clamped() {
+        let limiter = RateLimiter::new(RateLimit {
+            requests_per_second: 0.0,
+            burst: 0,
+            ..Default::default()
+        });
+
+        assert_eq!(limiter.config().burst, 1);
+        assert!(limiter.config().requests_per_second > 0.0);
+
+        // The single token is available right away instead of waiting forever
+        let waited = tokio::time::timeout(Duration::from_secs(1), limiter.acquire(None))
+            .await
+            .unwrap();
+        assert_eq!(waited, Duration::ZERO);
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use client::{Client, Error, Method, PaginatedResponse};
+    use httpmock::Method::{DELETE, GET, PATCH};
+    use httpmock::MockServer;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct CareGap {
+        id: i64,
+        status: String,
+    }
+
+    #[derive(Debug, Serialize)]
+    struct CareGapUpdate {
+        status: &'static str,
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_request_deserializes_typed_responses() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let search = server.mock(|when, then| {
+            when.method(GET)
+                .path("/care_gaps/")
+                .query_param("patient", "7")
+                .header("Authorization", "Bearer 12345");
+            then.status(200).json_body(json!({
+                "count": 1,
+                "next": null,
+                "previous": null,
+                "results": [{"id": 1, "status": "open"}]
+            }));
+        });
+        let update = server.mock(|when, then| {
+            when.method(PATCH)
+                .path("/care_gaps/1/")
+                .query_param("notify", "false")
+                .json_body(json!({"status": "closed"}));
+            then.status(200)
+                .json_body(json!({"id": 1, "status": "closed"}));
+        });
+
+        let client = client_for(&server);
+
+        let page: PaginatedResponse<CareGap> = client
+            .request(
+                Method::GET,
+                "/care_gaps/",
+                HashMap::from([("patient", 7)]),
+                None::<&()>,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            page.results,
+            vec![CareGap {
+                id: 1,
+                status: "open".to_string()
+            }]
+        );
+
+        // The query is sent for methods other than GET, too
+        let gap: CareGap = client
+            .request(
+                Method::PATCH,
+                "/care_gaps/1/",
+                HashMap::from([("notify", false)]),
+                Some(&CareGapUpdate { status: "closed" }),
+            )
+            .await
+            .unwrap();
+        assert_eq!(gap.status, "closed");
+
+        search.assert_async().await;
+        update.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_request_maps_errors_and_empty_bodies() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/care_gaps/2/");
+            then.status(404).json_body(json!({"detail": "Not found."}));
+        });
+        server.mock(|when, then| {
+            when.method(DELETE).path("/care_gaps/1/");
+            then.status(204);
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/care_gaps/3/");
+            then.status(200).body("not json");
+        });
+
+        let client = client_for(&server);
+
+        let error = client
+            .request::<_, (), CareGap>(Method::GET, "/care_gaps/2/", (), None)
+            .await
+            .unwrap_err();
+        match error {
+            Error::NotFound(body) => assert_eq!(body.non_field_errors, vec!["Not found."]),
+            other => panic!("expected NotFound, got {other:?}"),
+        }
+
+        let () = client
+            .request(Method::DELETE, "/care_gaps/1/", (), None::<&()>)
+            .await
+            .unwrap();
+
+        let error = client
+            .request::<_, (), CareGap>(Method::GET, "/care_gaps/3/", (), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Json(_)));
+    }
+
+    #[tokio::test]
+    async fn test_request_value_accepts_full_urls() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/care_gaps/")
+                .query_param("offset", "25");
+            then.status(200)
+                .json_body(json!({"count": 26, "next": null, "results": []}));
+        });
+
+        let client = client_for(&server);
+
+        let page = client
+            .request_value(
+                Method::GET,
+                &server.url("/care_gaps/"),
+                HashMap::from([("offset", 25)]),
+                None::<&()>,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(page["count"], 26);
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use client::{CancellationToken, Client, Error, RequestOptions, RetryPolicy};
+    use httpmock::Method::{GET, POST};
+    use httpmock::MockServer;
+    use reqwest::header::{HeaderName, HeaderValue, ACCEPT};
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_headers_are_sent_with_one_call_only() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let correlated = server.mock(|when, then| {
+            when.method(POST)
+                .path("/allergies")
+                .header("X-Correlation-Id", "checkout-42")
+                .header("Accept", "application/vnd.elation+json");
+            then.status(201).body("{}");
+        });
+        let plain = server.mock(|when, then| {
+            when.method(POST)
+                .path("/allergies")
+                .header("Accept", "application/json");
+            then.status(201).body("{}");
+        });
+
+        let client = client_for(&server);
+        let options = RequestOptions::new()
+            .header(
+                HeaderName::from_static("x-correlation-id"),
+                HeaderValue::from_static("checkout-42"),
+            )
+            .header(
+                ACCEPT,
+                HeaderValue::from_static("application/vnd.elation+json"),
+            );
+
+        client
+            .post_with("/allergies", &serde_json::json!({}), &options)
+            .await
+            .unwrap();
+        client
+            .post("/allergies", &serde_json::json!({}))
+            .await
+            .unwrap();
+
+        correlated.assert_async().await;
+        plain.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_timeout_applies_to_each_attempt() {
+        let server = MockServer::start_async().await;
+
+        let slow = server.mock(|when, then| {
+            when.method(GET).path("/reports/1/");
+            then.status(200).delay(Duration::from_secs(2)).body("{}");
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .retry_policy(
+                RetryPolicy::default()
+                    .max_attempts(2)
+                    .initial_backoff(Duration::from_millis(1)),
+            )
+            .build()
+            .unwrap();
+
+        let options = RequestOptions::new().timeout(Duration::from_millis(50));
+        let error = client
+            .get_with("/reports/1/", (), &options)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.attempts(), 2);
+        assert!(matches!(error.last_error(), Error::ReqwestError(e) if e.is_timeout()));
+        assert_eq!(slow.hits_async().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_deadline_covers_retries() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/1/");
+            then.status(503).body("{}");
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .retry_policy(
+                RetryPolicy::default()
+                    .max_attempts(100)
+                    .initial_backoff(Duration::from_millis(20))
+                    .jitter(false),
+            )
+            .build()
+            .unwrap();
+
+        let started = Instant::now();
+        let options = RequestOptions::new().deadline(started + Duration::from_millis(150));
+        let error = client
+            .get_with("/patients/1/", (), &options)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::DeadlineExceeded));
+        assert!(error.is_ambiguous());
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_abandons_the_call() {
+        let server = MockServer::start_async().await;
+
+        let slow = server.mock(|when, then| {
+            when.method(GET).path("/patients/1/");
+            then.status(200).delay(Duration::from_secs(5)).body("{}");
+        });
+
+        let client = client_for(&server);
+        let token = CancellationToken::new();
+        let options = RequestOptions::new().cancellation(token.clone());
+
+        let cancel = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            cancel.cancel();
+        });
+
+        let started = Instant::now();
+        let error = client
+            .get_with("/patients/1/", (), &options)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Cancelled));
+        assert!(error.is_ambiguous());
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert_eq!(error.kind(), "Cancelled");
+
+        // A call made with a cancelled token is never sent
+        client
+            .get_with("/patients/1/", (), &options)
+            .await
+            .unwrap_err();
+        assert_eq!(slow.hits_async().await, 1);
+    }
+}
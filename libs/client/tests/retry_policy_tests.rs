@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use client::{Client, Error, RetryPolicy};
+    use httpmock::Method::{GET, POST};
+    use httpmock::MockServer;
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(10))
+            .jitter(false)
+    }
+
+    fn client_with(server: &MockServer, policy: RetryPolicy) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .retry_policy(policy)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_idempotent_request_is_retried_until_attempts_run_out() {
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/patients/1/");
+            then.status(503).body("down for maintenance");
+        });
+
+        let client = client_with(&server, fast_policy().max_attempts(3));
+
+        let error = client.get("/patients/1/", ()).await.unwrap_err();
+
+        assert_eq!(error.attempts(), 3);
+        assert!(matches!(error.last_error(), Error::ServiceUnavailable(_)));
+        mock.assert_hits_async(3).await;
+    }
+
+    #[tokio::test]
+    async fn test_post_is_only_retried_when_opted_in() {
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/patients");
+            then.status(502).body("bad gateway");
+        });
+
+        let client = client_with(&server, fast_policy());
+        let error = client.post("/patients", &()).await.unwrap_err();
+
+        assert!(matches!(error, Error::BadGateway(_)));
+        mock.assert_hits_async(1).await;
+
+        let client = client_with(&server, fast_policy().retry_non_idempotent(true));
+        let error = client.post("/patients", &()).await.unwrap_err();
+
+        assert_eq!(error.attempts(), 3);
+        mock.assert_hits_async(4).await;
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_are_not_retried() {
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/patients/1/");
+            then.status(404).body("not found");
+        });
+
+        let client = client_with(&server, fast_policy());
+        let error = client.get("/patients/1/", ()).await.unwrap_err();
+
+        assert!(matches!(error, Error::NotFound(_)));
+        mock.assert_hits_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_retries_stop_at_deadline() {
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/patients/1/");
+            then.status(504).body("timeout");
+        });
+
+        let client = client_with(
+            &server,
+            fast_policy()
+                .initial_backoff(Duration::from_millis(500))
+                .deadline(Duration::from_millis(100)),
+        );
+        let error = client.get("/patients/1/", ()).await.unwrap_err();
+
+        assert!(matches!(error, Error::GatewayTimeout(_)));
+        mock.assert_hits_async(1).await;
+    }
+}
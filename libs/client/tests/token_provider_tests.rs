@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use client::{
+        async_trait, Client, ClientCredentialsProvider, Result, TokenProvider, TokenServiceProvider,
+    };
+    use httpmock::Method::{GET, POST};
+    use httpmock::MockServer;
+
+    /// Hands out `token-0` until invalidated, then `token-1`, and so on.
+    #[derive(Debug, Default, Clone)]
+    struct RotatingToken {
+        generation: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl TokenProvider for RotatingToken {
+        async fn token(&self) -> Result<String> {
+            Ok(format!("token-{}", self.generation.load(Ordering::SeqCst)))
+        }
+
+        async fn invalidate(&self, token: &str) {
+            let generation = self.generation.load(Ordering::SeqCst);
+            if token == format!("token-{generation}") {
+                self.generation.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unauthorized_request_is_retried_with_fresh_token() {
+        let server = MockServer::start_async().await;
+
+        let rejected = server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/1/")
+                .header("Authorization", "Bearer token-0");
+            then.status(401).body("expired");
+        });
+        let accepted = server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/1/")
+                .header("Authorization", "Bearer token-1");
+            then.status(200).body("{}");
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token_provider(RotatingToken::default())
+            .build()
+            .unwrap();
+
+        let result = client.get("/patients/1/", ()).await;
+
+        assert!(result.is_ok());
+        rejected.assert_async().await;
+        accepted.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_client_credentials_token_is_cached_until_expiry() {
+        let server = MockServer::start_async().await;
+
+        let token_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/oauth2/token/")
+                .body_contains("grant_type=client_credentials")
+                .body_contains("client_id=id");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(r#"{ "access_token": "abc", "expires_in": 3600 }"#);
+        });
+
+        let provider =
+            ClientCredentialsProvider::new(&server.url("/oauth2/token/"), "id", "secret").unwrap();
+
+        assert_eq!(provider.token().await.unwrap(), "abc");
+        assert_eq!(provider.token().await.unwrap(), "abc");
+        token_mock.assert_hits_async(1).await;
+
+        provider.invalidate("abc").await;
+        assert_eq!(provider.token().await.unwrap(), "abc");
+        token_mock.assert_hits_async(2).await;
+    }
+
+    #[tokio::test]
+    async fn test_failing_token_service_is_not_used_as_a_token() {
+        let server = MockServer::start_async().await;
+
+        let token_mock = server.mock(|when, then| {
+            when.method(GET).path("/token");
+            then.status(500).body("Internal Server Error");
+        });
+
+        let provider = TokenServiceProvider::new(&server.url("/")).unwrap();
+
+        assert!(provider.token().await.is_err());
+        assert!(provider.token().await.is_err());
+        token_mock.assert_hits_async(2).await;
+    }
+
+    #[tokio::test]
+    async fn test_invalidating_a_replaced_token_keeps_the_cached_one() {
+        let server = MockServer::start_async().await;
+
+        let token_mock = server.mock(|when, then| {
+            when.method(POST).path("/oauth2/token/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(r#"{ "access_token": "new", "expires_in": 3600 }"#);
+        });
+
+        let provider =
+            ClientCredentialsProvider::new(&server.url("/oauth2/token/"), "id", "secret").unwrap();
+        assert_eq!(provider.token().await.unwrap(), "new");
+
+        // A request that was rejected with an older token must not discard the new one
+        provider.invalidate("old").await;
+        assert_eq!(provider.token().await.unwrap(), "new");
+        token_mock.assert_hits_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_unauthorized_requests_refresh_the_token_once() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/1/")
+                .header("Authorization", "Bearer token-0");
+            then.status(401).body("expired");
+        });
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/1/")
+                .header("Authorization", "Bearer token-1");
+            then.status(200).body("{}");
+        });
+
+        let provider = RotatingToken::default();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token_provider(provider.clone())
+            .build()
+            .unwrap();
+
+        let (first, second, third) = futures::join!(
+            client.get("/patients/1/", ()),
+            client.get("/patients/1/", ()),
+            client.get("/patients/1/", ()),
+        );
+
+        assert!(first.is_ok() && second.is_ok() && third.is_ok());
+        assert_eq!(provider.generation.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_client_credentials_token_is_refreshed_before_expiry() {
+        let server = MockServer::start_async().await;
+
+        // Shorter than the refresh window, so it is refreshed halfway through its lifetime.
+        let token_mock = server.mock(|when, then| {
+            when.method(POST).path("/oauth2/token/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(r#"{ "access_token": "abc", "expires_in": 1 }"#);
+        });
+
+        let provider =
+            ClientCredentialsProvider::new(&server.url("/oauth2/token/"), "id", "secret").unwrap();
+
+        provider.token().await.unwrap();
+        provider.token().await.unwrap();
+        token_mock.assert_hits_async(1).await;
+
+        tokio::time::sleep(std::time::Duration::from_millis(600)).await;
+        provider.token().await.unwrap();
+        token_mock.assert_hits_async(2).await;
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use client::{Client, Redaction};
+    use httpmock::Method::{GET, PATCH};
+    use httpmock::MockServer;
+    use tracing_subscriber::fmt::{format::FmtSpan, MakeWriter};
+
+    /// Collects everything the subscriber writes.
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Captured {
+        fn output(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Captured {
+        type Writer = Captured;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn capture() -> (Captured, tracing::subscriber::DefaultGuard) {
+        let captured = Captured::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(captured.clone())
+            .with_max_level(tracing::Level::DEBUG)
+            .with_span_events(FmtSpan::CLOSE)
+            .with_ansi(false)
+            .finish();
+        (captured, tracing::subscriber::set_default(subscriber))
+    }
+
+    fn patient_update() -> serde_json::Value {
+        serde_json::json!({
+            "first_name": "Jane",
+            "dob": "1970-01-01",
+            "ssn": "123-45-6789",
+            "address": { "address_line1": "1 Main St", "state": "CA" },
+            "guarantor": { "state": "CA" },
+            "vip": true,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_request_span_records_outcome_without_bodies() {
+        let server = MockServer::start_async().await;
+        let (captured, _guard) = capture();
+
+        server.mock(|when, then| {
+            when.method(PATCH).path("/patients/140754680471554/");
+            then.status(400)
+                .header("X-Request-Id", "req-9")
+                .body(r#"{ "ssn": ["123-45-6789 is not a valid SSN."] }"#);
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
+
+        let error = client
+            .patch("/patients/140754680471554/", &patient_update())
+            .await
+            .unwrap_err();
+
+        let output = captured.output();
+        assert!(output.contains("elation.request"));
+        assert!(output.contains("http.method=PATCH"));
+        assert!(output.contains("endpoint=/patients/{id}/"));
+        assert!(output.contains("http.status_code=400"));
+        assert!(output.contains("request_id=\"req-9\""));
+        assert!(output.contains("attempts=1"));
+        assert!(output.contains("latency_ms="));
+        assert!(output.contains("error=\"BadRequest\""));
+
+        // Neither the logs nor the error message contain the PHI that was sent
+        for phi in ["Jane", "1970-01-01", "123-45-6789", "1 Main St"] {
+            assert!(!output.contains(phi), "{phi} was logged: {output}");
+        }
+        let message = error.to_string();
+        for phi in ["Jane", "1970-01-01", "1 Main St"] {
+            assert!(!message.contains(phi), "{phi} is in the error: {message}");
+        }
+        assert!(message.contains(r#"\"vip\":true"#));
+    }
+
+    #[tokio::test]
+    async fn test_debug_mode_logs_masked_bodies() {
+        let server = MockServer::start_async().await;
+        let (captured, _guard) = capture();
+
+        server.mock(|when, then| {
+            when.method(PATCH).path("/patients/1/");
+            then.status(409)
+                .body(r#"{ "last_name": "Doe", "non_field_errors": ["Duplicate patient."] }"#);
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .redaction(Redaction::default().log_bodies(true).phi_field("state"))
+            .build()
+            .unwrap();
+
+        client
+            .patch("/patients/1/", &patient_update())
+            .await
+            .unwrap_err();
+
+        let output = captured.output();
+        assert!(output.contains(r#""first_name":"[REDACTED]""#));
+        assert!(output.contains(r#""address":"[REDACTED]""#));
+        assert!(output.contains(r#""state":"[REDACTED]""#));
+        assert!(output.contains(r#""vip":true"#));
+        assert!(output.contains(r#""last_name":"[REDACTED]""#));
+        assert!(output.contains("Duplicate patient."));
+        for phi in ["Jane", "123-45-6789", "1 Main St", "CA", "Doe"] {
+            assert!(!output.contains(phi), "{phi} was logged: {output}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_strings_are_not_logged() {
+        let server = MockServer::start_async().await;
+        let (captured, _guard) = capture();
+
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/2.0/patients/")
+                .query_param("last_name", "Doe");
+            then.status(200).body("{}");
+        });
+
+        let client = Client::builder()
+            .base_url(server.url("/api/2.0/"))
+            .token("12345")
+            .build()
+            .unwrap();
+
+        client
+            .get("/patients/", HashMap::from([("last_name", "Doe")]))
+            .await
+            .unwrap();
+
+        let output = captured.output();
+        assert!(output.contains("endpoint=/patients/"));
+        assert!(output.contains("http.status_code=200"));
+        assert!(!output.contains("Doe"));
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use client::{
+        async_trait, Client, Error, MemoryResponse, MemoryTransport, Request, Response, Result,
+        RetryPolicy, Transport,
+    };
+    use reqwest::header::{HeaderName, HeaderValue};
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+
+    fn client_over(transport: impl Transport + 'static) -> Client {
+        Client::builder()
+            .base_url("http://elation.test/api/2.0/")
+            .token("12345")
+            .default_header(
+                HeaderName::from_static("x-practice"),
+                HeaderValue::from_static("65540"),
+            )
+            .transport(transport)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_serves_programmed_responses() {
+        let transport = MemoryTransport::new();
+        transport.respond(
+            Method::GET,
+            "/api/2.0/patients/",
+            MemoryResponse::json(StatusCode::OK, &json!({ "results": [] })),
+        );
+        transport.respond(
+            Method::GET,
+            "/api/2.0/patients/?last_name=Doe",
+            MemoryResponse::json(StatusCode::OK, &json!({ "results": [{ "id": 1 }] })),
+        );
+        transport.respond(
+            Method::POST,
+            "/api/2.0/allergies",
+            MemoryResponse::json(StatusCode::CREATED, &json!({ "id": 9 })),
+        );
+
+        let client = client_over(transport.clone());
+
+        // The most specific route answers
+        let found = client
+            .get("/patients/", HashMap::from([("last_name", "Doe")]))
+            .await
+            .unwrap();
+        assert_eq!(found.text().await.unwrap(), r#"{"results":[{"id":1}]}"#);
+        let all = client
+            .get("/patients/", HashMap::from([("last_name", "Roe")]))
+            .await
+            .unwrap();
+        assert_eq!(all.text().await.unwrap(), r#"{"results":[]}"#);
+
+        let created = client
+            .post("/allergies", &json!({ "name": "Peanuts" }))
+            .await
+            .unwrap();
+        assert_eq!(created.status(), StatusCode::CREATED);
+
+        // Requests are recorded as the transport received them
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2].method, Method::POST);
+        assert_eq!(requests[2].headers["authorization"], "Bearer 12345");
+        assert_eq!(requests[2].headers["x-practice"], "65540");
+        assert_eq!(requests[2].headers["accept"], "application/json");
+        assert_eq!(
+            requests[2].body.as_deref(),
+            Some(br#"{"name":"Peanuts"}"#.as_slice())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_answers_unknown_requests_with_not_found() {
+        let client = client_over(MemoryTransport::new());
+
+        let error = client.get("/patients/1/", ()).await.unwrap_err();
+
+        let Error::NotFound(body) = error else {
+            panic!("expected NotFound, got {error:?}");
+        };
+        assert_eq!(body.non_field_errors, ["Not found."]);
+    }
+
+    /// Fails with a 503 until it has been called `failures` times.
+    #[derive(Debug)]
+    struct Flaky {
+        failures: usize,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Transport for Flaky {
+        async fn send(&self, _request: Request) -> Result<Response> {
+            let status = if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                StatusCode::SERVICE_UNAVAILABLE
+            } else {
+                StatusCode::OK
+            };
+            Ok(MemoryResponse::new(status).body("{}").into())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_behavior_runs_above_custom_transports() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let client = Client::builder()
+            .base_url("http://elation.test/")
+            .token("12345")
+            .retry_policy(
+                RetryPolicy::default().initial_backoff(std::time::Duration::from_millis(1)),
+            )
+            .transport(Flaky {
+                failures: 2,
+                calls: calls.clone(),
+            })
+            .build()
+            .unwrap();
+
+        client.get("/lab_vendors/1/", ()).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
@@ -82,7 +82,7 @@ fn find_problematic_field(json: &str, target_column: usize) -> Option<(String, S
     let mut current_field = String::new();
     let mut in_quotes = false;
     let mut escaped = false;
-    for (i, ch) in json.chars().enumerate() {
+    for (i, ch) in json.char_indices() {
         if i + 1 == target_column {
             return Some((
                 current_field
@@ -0,0 +1,61 @@
+use std::collections::BTreeMap;
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::{json, Value};
+
+/// An error response shaped like the ones Elation's API returns.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    /// `400 Bad Request` with the messages for each invalid field.
+    Validation(BTreeMap<String, Vec<String>>),
+
+    /// `400 Bad Request` with messages that concern the request as a whole.
+    Invalid(String),
+
+    /// `404 Not Found`.
+    NotFound,
+
+    /// `405 Method Not Allowed` for resources that cannot be written to.
+    MethodNotAllowed(String),
+
+    /// `409 Conflict` with messages that concern the request as a whole.
+    Conflict(String),
+}
+
+impl ApiError {
+    /// A `400 Bad Request` for a single field.
+    pub fn field(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::Validation(BTreeMap::from([(name.into(), vec![message.into()])]))
+    }
+
+    /// Returns the status code and the JSON body of the error.
+    pub fn parts(&self) -> (StatusCode, Value) {
+        match self {
+            ApiError::Validation(fields) => (StatusCode::BAD_REQUEST, json!(fields)),
+            ApiError::Invalid(message) => (
+                StatusCode::BAD_REQUEST,
+                json!({ "non_field_errors": [message] }),
+            ),
+            ApiError::NotFound => (StatusCode::NOT_FOUND, json!({ "detail": "Not found." })),
+            ApiError::MethodNotAllowed(method) => (
+                StatusCode::METHOD_NOT_ALLOWED,
+                json!({ "detail": format!("Method \"{method}\" not allowed.") }),
+            ),
+            ApiError::Conflict(message) => (
+                StatusCode::CONFLICT,
+                json!({ "non_field_errors": [message] }),
+            ),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, body) = self.parts();
+        (status, Json(body)).into_response()
+    }
+}
@@ -0,0 +1,143 @@
+//! A stateful stand-in for the Elation EMR API, for integration tests and local development.
+//!
+//! Every resource the SDK has a model for is served under its `Resource::endpoint()`:
+//! records are kept in memory, created records get fresh ids, lists are filtered by their
+//! query parameters and paginated with `limit`, `offset` and `next` links, and invalid
+//! requests are answered with Elation-shaped `400`, `404`, `405` and `409` errors.
+//! Created and replaced records are validated against the SDK's `*ForCreate` models.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use fake_elation::FakeElation;
+//!
+//! # async fn run() -> std::io::Result<()> {
+//! let fake = FakeElation::new();
+//! fake.insert("/lab_vendors", serde_json::json!({ "name": "Quest" }));
+//!
+//! let server = fake.spawn().await?;
+//! let client = client::Client::builder()
+//!     .base_url(server.base_url())
+//!     .token("any-token")
+//!     .build()
+//!     .unwrap();
+//! # Ok(())
+//! # }
+//! ```
+
+mod error;
+mod resources;
+mod routes;
+mod store;
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use axum::Router;
+use models::resource::Resource;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::{net::TcpListener, task::JoinHandle};
+
+pub use error::ApiError;
+pub use routes::DEFAULT_LIMIT;
+
+use store::Store;
+
+/// The state of a fake Elation API, shared by every server started from it and its clones.
+#[derive(Debug, Clone, Default)]
+pub struct FakeElation {
+    store: Arc<Mutex<Store>>,
+}
+
+impl FakeElation {
+    /// Creates a fake API without any records.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the routes of the fake API, e.g. to nest them under `/api/2.0`.
+    pub fn router(&self) -> Router {
+        routes::router(self.store.clone())
+    }
+
+    /// Serves the fake API on `listener` until the task is dropped.
+    pub async fn serve(self, listener: TcpListener) -> std::io::Result<()> {
+        axum::serve(listener, self.router()).await
+    }
+
+    /// Serves the fake API on a free local port in the background.
+    ///
+    /// The server stops when the returned `RunningFake` is dropped.
+    pub async fn spawn(&self) -> std::io::Result<RunningFake> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+        let task = tokio::spawn(self.clone().serve(listener));
+        Ok(RunningFake { base_url, task })
+    }
+
+    /// Stores a record of the resource at `endpoint`, e.g. `"/patients"`, without validating it.
+    ///
+    /// The record keeps its id if it has one and gets a fresh one otherwise. Returns the
+    /// stored record.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such resource or the record is not a JSON object.
+    pub fn insert(&self, endpoint: &str, record: Value) -> Value {
+        self.store()
+            .insert(resource_name(endpoint), record)
+            .unwrap_or_else(|error| panic!("cannot insert into {endpoint}: {error:?}"))
+    }
+
+    /// Stores a model as a record of its resource. See [`FakeElation::insert`].
+    pub fn insert_resource<T: Resource + Serialize>(&self, resource: &T) -> Value {
+        let record = serde_json::to_value(resource).expect("models serialize to JSON");
+        self.insert(T::endpoint(), record)
+    }
+
+    /// Returns the records of the resource at `endpoint`, ordered by id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such resource.
+    pub fn records(&self, endpoint: &str) -> Vec<Value> {
+        self.store()
+            .list(resource_name(endpoint), &[])
+            .unwrap_or_else(|_| panic!("there is no resource at {endpoint}"))
+    }
+
+    /// Returns whether the fake API serves a resource at `endpoint`.
+    pub fn serves(&self, endpoint: &str) -> bool {
+        self.store().has_resource(resource_name(endpoint))
+    }
+
+    fn store(&self) -> MutexGuard<'_, Store> {
+        self.store
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A fake API served in the background by [`FakeElation::spawn`].
+#[derive(Debug)]
+pub struct RunningFake {
+    base_url: String,
+    task: JoinHandle<std::io::Result<()>>,
+}
+
+impl RunningFake {
+    /// Returns the URL to use as the base URL of a `Client`, e.g. `http://127.0.0.1:50123`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+}
+
+impl Drop for RunningFake {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn resource_name(endpoint: &str) -> &str {
+    endpoint.trim_matches('/')
+}
@@ -0,0 +1,16 @@
+use fake_elation::FakeElation;
+use std::env;
+use tokio::net::TcpListener;
+
+/// Serves an empty fake Elation API, by default where `Client::new` looks for it under `TEST_ENV`.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = env::var("FAKE_ELATION_ADDR").unwrap_or_else(|_| "127.0.0.1:1234".to_string());
+
+    let listener = TcpListener::bind(&addr).await?;
+    println!("Fake Elation API listening on {addr}");
+
+    FakeElation::new().serve(listener).await?;
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use models::orders::*;
+use models::patient_profile::*;
+use models::resource::Resource;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::error::ApiError;
+
+/// Checks a request body by deserializing it into one of the `*ForCreate` models.
+type Validator = fn(&Value) -> Result<(), ApiError>;
+
+/// How the fake server treats one resource.
+#[derive(Debug, Clone)]
+pub struct ResourceSpec {
+    /// The endpoint of the resource without its leading slash, e.g. `patients`.
+    pub name: &'static str,
+
+    /// The field holding the identifier of a record.
+    pub id_field: &'static str,
+
+    /// Validates created and replaced records, or `None` if the resource is read-only.
+    pub validate: Option<Validator>,
+
+    /// Fields whose combined values must be unique among the records of the resource.
+    pub unique_together: &'static [&'static str],
+}
+
+impl ResourceSpec {
+    /// A read-only resource, identified by `id`.
+    fn read_only<T: Resource>() -> Self {
+        Self {
+            name: T::endpoint().trim_start_matches('/'),
+            id_field: "id",
+            validate: None,
+            unique_together: &[],
+        }
+    }
+
+    /// A resource whose records are created from `C`.
+    fn writable<T: Resource, C: DeserializeOwned>() -> Self {
+        Self {
+            validate: Some(validate::<C>),
+            ..Self::read_only::<T>()
+        }
+    }
+
+    fn id_field(self, id_field: &'static str) -> Self {
+        Self { id_field, ..self }
+    }
+
+    fn unique_together(self, fields: &'static [&'static str]) -> Self {
+        Self {
+            unique_together: fields,
+            ..self
+        }
+    }
+}
+
+/// Returns every resource the SDK has a model for.
+pub fn all() -> Vec<ResourceSpec> {
+    vec![
+        // Orders
+        ResourceSpec::read_only::<AncillaryCompany>(),
+        ResourceSpec::read_only::<CardiacCenter>(),
+        ResourceSpec::writable::<CardiacOrder, CardiacOrderForCreate>(),
+        ResourceSpec::writable::<CardiacOrderTest, CardiacOrderTestForCreate>(),
+        ResourceSpec::read_only::<ImagingCenter>(),
+        ResourceSpec::writable::<ImagingOrder, ImagingOrderForCreate>(),
+        ResourceSpec::writable::<ImagingOrderTest, ImagingOrderTestForCreate>(),
+        ResourceSpec::writable::<LabOrder, LabOrderForCreate>(),
+        ResourceSpec::writable::<LabOrderCompendium, LabOrderCompendiumForCreate>(),
+        ResourceSpec::writable::<LabOrderSet, LabOrderSetForCreate>(),
+        ResourceSpec::writable::<LabOrderTest, LabOrderTestForCreate>(),
+        ResourceSpec::writable::<LabVendor, LabVendorForCreate>(),
+        ResourceSpec::read_only::<PulmonaryCenter>(),
+        ResourceSpec::writable::<PulmonaryOrder, PulmonaryOrderForCreate>(),
+        ResourceSpec::writable::<PulmonaryOrderTest, PulmonaryOrderTestForCreate>(),
+        ResourceSpec::read_only::<SleepCenter>(),
+        ResourceSpec::writable::<SleepOrder, SleepOrderForCreate>(),
+        ResourceSpec::writable::<SleepOrderTest, SleepOrderTestForCreate>(),
+        // Patient profile
+        ResourceSpec::writable::<Allergy, AllergyForCreate>(),
+        ResourceSpec::writable::<AllergyDocumentation, AllergyDocumentationForCreate>(),
+        ResourceSpec::writable::<AppointmentType, AppointmentTypeForCreate>(),
+        ResourceSpec::read_only::<DrugIntolerance>(),
+        ResourceSpec::writable::<FamilyHistory, FamilyHistoryForCreate>(),
+        ResourceSpec::writable::<History, HistoryForCreate>(),
+        ResourceSpec::writable::<Immunization, ImmunizationForCreate>(),
+        ResourceSpec::writable::<InsuranceCard, InsuranceCardForCreate>().id_field("rank"),
+        ResourceSpec::writable::<Patient, PatientForCreate>().unique_together(&[
+            "first_name",
+            "last_name",
+            "dob",
+        ]),
+        ResourceSpec::writable::<PatientPhoto, PatientPhotoForCreate>(),
+        ResourceSpec::writable::<PatientProviderTeam, PatientProviderTeamForCreate>()
+            .id_field("patient_provider_team_id"),
+        ResourceSpec::writable::<Problem, ProblemForCreate>(),
+        ResourceSpec::writable::<Vaccine, VaccineForCreate>(),
+    ]
+}
+
+/// Deserializes `body` into `C`, turning the first failure into a DRF-style field error.
+fn validate<C: DeserializeOwned>(body: &Value) -> Result<(), ApiError> {
+    if !body.is_object() {
+        return Err(ApiError::Invalid(format!(
+            "Invalid data. Expected a dictionary, but got {}.",
+            json_type(body)
+        )));
+    }
+
+    let error = match serde_path_to_error::deserialize::<_, C>(body) {
+        Ok(_) => return Ok(()),
+        Err(error) => error,
+    };
+
+    let path = error.path().to_string();
+    let message = error.inner().to_string();
+    let (field, message) = match message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split('`').next())
+    {
+        Some(missing) if path == "." => (missing.to_owned(), "This field is required.".to_owned()),
+        Some(missing) => (
+            format!("{path}.{missing}"),
+            "This field is required.".to_owned(),
+        ),
+        None if path == "." => return Err(ApiError::Invalid(message)),
+        None => (path, capitalize(&message)),
+    };
+    Err(ApiError::field(field, message))
+}
+
+/// Returns the name DRF uses for the type of a JSON value.
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "int",
+        Value::String(_) => "str",
+        Value::Array(_) => "list",
+        Value::Object(_) => "dict",
+    }
+}
+
+fn capitalize(message: &str) -> String {
+    let mut chars = message.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}.", first.to_uppercase(), chars.as_str()),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use axum::{
+    body::Bytes,
+    extract::{OriginalUri, Path, RawQuery, State},
+    http::{header::HOST, HeaderMap, StatusCode},
+    routing::get,
+    Json, Router,
+};
+use serde_json::{json, Value};
+
+use crate::error::ApiError;
+use crate::store::Store;
+
+/// The page size used when a request does not set `limit`.
+pub const DEFAULT_LIMIT: usize = 25;
+
+type Shared = Arc<Mutex<Store>>;
+
+/// Builds the routes of every resource: `/{resource}/` and `/{resource}/{id}/`, with or
+/// without the trailing slash.
+pub(crate) fn router(store: Shared) -> Router {
+    Router::new()
+        .route("/:resource", get(list).post(create).put(upsert))
+        .route("/:resource/", get(list).post(create).put(upsert))
+        .route(
+            "/:resource/:id",
+            get(retrieve).put(replace).patch(update).delete(destroy),
+        )
+        .route(
+            "/:resource/:id/",
+            get(retrieve).put(replace).patch(update).delete(destroy),
+        )
+        .fallback(|| async { ApiError::NotFound })
+        .with_state(store)
+}
+
+fn lock(store: &Shared) -> MutexGuard<'_, Store> {
+    store
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Parses a request body, answering malformed JSON the way DRF does.
+fn parse_body(body: &Bytes) -> Result<Value, ApiError> {
+    serde_json::from_slice(body)
+        .map_err(|error| ApiError::Invalid(format!("JSON parse error - {error}")))
+}
+
+/// Parses `limit` or `offset`, if present.
+fn parse_number(params: &[(String, String)], name: &str) -> Result<Option<usize>, ApiError> {
+    params
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| {
+            value
+                .parse()
+                .map_err(|_| ApiError::field(name, "A valid integer is required."))
+        })
+        .transpose()
+}
+
+/// Lists the records of a resource, filtered by the query and paginated with `limit` and `offset`.
+async fn list(
+    State(store): State<Shared>,
+    Path(resource): Path<String>,
+    OriginalUri(uri): OriginalUri,
+    RawQuery(query): RawQuery,
+    headers: HeaderMap,
+) -> Result<Json<Value>, ApiError> {
+    let params: Vec<(String, String)> =
+        serde_urlencoded::from_str(query.as_deref().unwrap_or_default())
+            .map_err(|error| ApiError::Invalid(error.to_string()))?;
+    let limit = parse_number(&params, "limit")?
+        .unwrap_or(DEFAULT_LIMIT)
+        .max(1);
+    let offset = parse_number(&params, "offset")?.unwrap_or(0);
+    let filters: Vec<(String, String)> = params
+        .into_iter()
+        .filter(|(key, _)| key != "limit" && key != "offset")
+        .collect();
+
+    let records = lock(&store).list(&resource, &filters)?;
+    let count = records.len();
+    let results: Vec<Value> = records.into_iter().skip(offset).take(limit).collect();
+
+    let host = headers
+        .get(HOST)
+        .and_then(|host| host.to_str().ok())
+        .unwrap_or("localhost");
+    let link = |offset: usize| {
+        let mut query = filters.clone();
+        query.push(("limit".to_owned(), limit.to_string()));
+        query.push(("offset".to_owned(), offset.to_string()));
+        format!(
+            "http://{host}{}?{}",
+            uri.path(),
+            serde_urlencoded::to_string(query).unwrap_or_default()
+        )
+    };
+
+    Ok(Json(json!({
+        "count": count,
+        "next": (offset + limit < count).then(|| link(offset + limit)),
+        "previous": (offset > 0).then(|| link(offset.saturating_sub(limit))),
+        "results": results,
+    })))
+}
+
+async fn create(
+    State(store): State<Shared>,
+    Path(resource): Path<String>,
+    body: Bytes,
+) -> Result<(StatusCode, Json<Value>), ApiError> {
+    let created = lock(&store).create(&resource, parse_body(&body)?)?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+async fn upsert(
+    State(store): State<Shared>,
+    Path(resource): Path<String>,
+    body: Bytes,
+) -> Result<Json<Value>, ApiError> {
+    Ok(Json(lock(&store).upsert(&resource, parse_body(&body)?)?))
+}
+
+async fn retrieve(
+    State(store): State<Shared>,
+    Path((resource, id)): Path<(String, String)>,
+) -> Result<Json<Value>, ApiError> {
+    Ok(Json(lock(&store).get(&resource, &id)?))
+}
+
+async fn replace(
+    State(store): State<Shared>,
+    Path((resource, id)): Path<(String, String)>,
+    body: Bytes,
+) -> Result<Json<Value>, ApiError> {
+    Ok(Json(lock(&store).replace(
+        &resource,
+        &id,
+        parse_body(&body)?,
+    )?))
+}
+
+async fn update(
+    State(store): State<Shared>,
+    Path((resource, id)): Path<(String, String)>,
+    body: Bytes,
+) -> Result<Json<Value>, ApiError> {
+    Ok(Json(lock(&store).update(
+        &resource,
+        &id,
+        parse_body(&body)?,
+    )?))
+}
+
+async fn destroy(
+    State(store): State<Shared>,
+    Path((resource, id)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    lock(&store).delete(&resource, &id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,229 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::{Map, Value};
+
+use crate::error::ApiError;
+use crate::resources::{self, ResourceSpec};
+
+/// The in-memory records of every resource.
+#[derive(Debug)]
+pub(crate) struct Store {
+    specs: HashMap<&'static str, ResourceSpec>,
+    records: HashMap<&'static str, BTreeMap<i64, Value>>,
+    next_id: i64,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Self {
+            specs: resources::all()
+                .into_iter()
+                .map(|spec| (spec.name, spec))
+                .collect(),
+            records: HashMap::new(),
+            next_id: 1,
+        }
+    }
+}
+
+impl Store {
+    /// Returns the records of `resource` matching every filter, ordered by id.
+    ///
+    /// Repeated filters for the same field match any of their values. A filter only
+    /// applies to records that have its field, so query parameters that are not fields,
+    /// e.g. date ranges, are ignored. A plural filter such as `patients` applies to the
+    /// singular field when there is no plural one.
+    pub fn list(
+        &self,
+        resource: &str,
+        filters: &[(String, String)],
+    ) -> Result<Vec<Value>, ApiError> {
+        let spec = self.spec(resource)?;
+        let mut by_field: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (field, value) in filters {
+            by_field.entry(field).or_default().push(value);
+        }
+
+        Ok(self
+            .records
+            .get(spec.name)
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .filter(|record| {
+                by_field
+                    .iter()
+                    .all(|(field, values)| match field_of(record, field) {
+                        Some(actual) => values.iter().any(|value| matches(actual, value)),
+                        None => true,
+                    })
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Returns the record of `resource` with the given id.
+    pub fn get(&self, resource: &str, id: &str) -> Result<Value, ApiError> {
+        let spec = self.spec(resource)?;
+        let id = parse_id(id)?;
+        self.records
+            .get(spec.name)
+            .and_then(|records| records.get(&id))
+            .cloned()
+            .ok_or(ApiError::NotFound)
+    }
+
+    /// Validates `body` and stores it as a new record with a fresh id.
+    pub fn create(&mut self, resource: &str, body: Value) -> Result<Value, ApiError> {
+        let spec = self.writable(resource, "POST")?;
+        (spec.validate.expect("writable resources have a validator"))(&body)?;
+        let id = self.next_id;
+        self.store(spec, id, body)
+    }
+
+    /// Validates `body` and stores it in place of the record with the given id.
+    pub fn replace(&mut self, resource: &str, id: &str, body: Value) -> Result<Value, ApiError> {
+        let spec = self.writable(resource, "PUT")?;
+        let id = parse_id(id)?;
+        self.get(spec.name, &id.to_string())?;
+        (spec.validate.expect("writable resources have a validator"))(&body)?;
+        self.store(spec, id, body)
+    }
+
+    /// Replaces the record whose id is in `body`, or creates one if there is none.
+    pub fn upsert(&mut self, resource: &str, body: Value) -> Result<Value, ApiError> {
+        let spec = self.writable(resource, "PUT")?;
+        match body.get(spec.id_field).and_then(Value::as_i64) {
+            Some(id) if self.get(spec.name, &id.to_string()).is_ok() => {
+                self.replace(spec.name, &id.to_string(), body)
+            }
+            _ => self.create(spec.name, body),
+        }
+    }
+
+    /// Merges the fields of `patch` into the record with the given id.
+    pub fn update(&mut self, resource: &str, id: &str, patch: Value) -> Result<Value, ApiError> {
+        let spec = self.writable(resource, "PATCH")?;
+        let Value::Object(patch) = patch else {
+            return Err(ApiError::Invalid(
+                "Invalid data. Expected a dictionary.".to_owned(),
+            ));
+        };
+        let id = parse_id(id)?;
+        let Value::Object(mut record) = self.get(spec.name, &id.to_string())? else {
+            return Err(ApiError::NotFound);
+        };
+        record.extend(patch);
+        self.store(spec, id, Value::Object(record))
+    }
+
+    /// Removes the record with the given id.
+    pub fn delete(&mut self, resource: &str, id: &str) -> Result<(), ApiError> {
+        let spec = self.writable(resource, "DELETE")?;
+        let id = parse_id(id)?;
+        self.records
+            .get_mut(spec.name)
+            .and_then(|records| records.remove(&id))
+            .map(|_| ())
+            .ok_or(ApiError::NotFound)
+    }
+
+    /// Stores `record` without validating it, keeping its id if it has one.
+    pub fn insert(&mut self, resource: &str, record: Value) -> Result<Value, ApiError> {
+        let spec = self.spec(resource)?.clone();
+        let id = record
+            .get(spec.id_field)
+            .and_then(Value::as_i64)
+            .unwrap_or(self.next_id);
+        self.store(spec, id, record)
+    }
+
+    /// Returns whether the store knows `resource`.
+    pub fn has_resource(&self, resource: &str) -> bool {
+        self.specs.contains_key(resource)
+    }
+
+    fn spec(&self, resource: &str) -> Result<&ResourceSpec, ApiError> {
+        self.specs.get(resource).ok_or(ApiError::NotFound)
+    }
+
+    fn writable(&self, resource: &str, method: &str) -> Result<ResourceSpec, ApiError> {
+        let spec = self.spec(resource)?;
+        match spec.validate {
+            Some(_) => Ok(spec.clone()),
+            None => Err(ApiError::MethodNotAllowed(method.to_owned())),
+        }
+    }
+
+    fn store(&mut self, spec: ResourceSpec, id: i64, record: Value) -> Result<Value, ApiError> {
+        let Value::Object(mut record) = record else {
+            return Err(ApiError::Invalid(
+                "Invalid data. Expected a dictionary.".to_owned(),
+            ));
+        };
+        record.insert(spec.id_field.to_owned(), Value::from(id));
+        self.check_unique(&spec, id, &record)?;
+
+        self.next_id = self.next_id.max(id + 1);
+        let record = Value::Object(record);
+        self.records
+            .entry(spec.name)
+            .or_default()
+            .insert(id, record.clone());
+        Ok(record)
+    }
+
+    fn check_unique(
+        &self,
+        spec: &ResourceSpec,
+        id: i64,
+        record: &Map<String, Value>,
+    ) -> Result<(), ApiError> {
+        if spec.unique_together.is_empty() {
+            return Ok(());
+        }
+
+        let duplicate = self
+            .records
+            .get(spec.name)
+            .into_iter()
+            .flat_map(|records| records.iter())
+            .any(|(other_id, other)| {
+                *other_id != id
+                    && spec
+                        .unique_together
+                        .iter()
+                        .all(|field| record.get(*field) == other.get(*field))
+            });
+        if duplicate {
+            return Err(ApiError::Conflict(format!(
+                "The fields {} must make a unique set.",
+                spec.unique_together.join(", ")
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Parses an id from a path; anything that is not a number cannot be found.
+fn parse_id(id: &str) -> Result<i64, ApiError> {
+    id.parse().map_err(|_| ApiError::NotFound)
+}
+
+/// Returns whether a stored value equals a query parameter value.
+fn matches(actual: &Value, expected: &str) -> bool {
+    match actual {
+        Value::String(actual) => actual == expected,
+        Value::Array(items) => items.iter().any(|item| matches(item, expected)),
+        Value::Null => expected.is_empty() || expected == "null",
+        other => serde_json::from_str::<Value>(expected).is_ok_and(|expected| expected == *other),
+    }
+}
+
+/// Returns the field of `record` a filter applies to.
+fn field_of<'r>(record: &'r Value, filter: &str) -> Option<&'r Value> {
+    record.get(filter).or_else(|| {
+        filter
+            .strip_suffix('s')
+            .and_then(|singular| record.get(singular))
+    })
+}
@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use client::{Client, Error};
+    use fake_elation::{FakeElation, RunningFake};
+    use futures::TryStreamExt;
+    use models::patient_profile::*;
+    use serde_json::{json, Value};
+    use services::patient_profile::AllergyService;
+    use services::prelude::*;
+    use time::{Date, Month};
+
+    fn client_for(server: &RunningFake) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    fn allergy(patient: i64, name: &str) -> AllergyForCreate {
+        AllergyForCreate {
+            status: AllergyStatus::Active,
+            start_date: Date::from_calendar_date(2020, Month::March, 1).unwrap(),
+            reaction: Some("Hives".to_string()),
+            name: name.to_string(),
+            severity: None,
+            medispanid: None,
+            medispandnid: None,
+            patient,
+        }
+    }
+
+    fn patient() -> Value {
+        json!({
+            "first_name": "Jane",
+            "last_name": "Smith",
+            "dob": "1985-05-05",
+            "sex": "Female",
+            "primary_physician": 1,
+            "caregiver_practice": 2,
+            "insurances": [],
+        })
+    }
+
+    #[tokio::test]
+    async fn test_allergy_crud_through_the_sdk() {
+        // Start the fake API on a free port
+        let server = FakeElation::new().spawn().await.unwrap();
+        let client = client_for(&server);
+        let service = AllergyService::new(&client);
+
+        let created = service.post(&allergy(7, "Peanuts")).await.unwrap();
+        assert_eq!(created.name, "Peanuts");
+        assert_eq!(created.patient, 7);
+
+        let fetched = service.get(created.id).await.unwrap();
+        assert_eq!(fetched.id, created.id);
+        assert_eq!(fetched.reaction.as_deref(), Some("Hives"));
+
+        service.delete(created.id).await.unwrap();
+        let error = service.get(created.id).await.unwrap_err();
+        assert!(matches!(
+            error,
+            services::Error::ClientError(Error::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_find_filters_and_follows_next_links() {
+        let fake = FakeElation::new();
+        for n in 0..30 {
+            fake.insert(
+                "/allergies",
+                serde_json::to_value(allergy(1, &format!("Allergy {n}"))).unwrap(),
+            );
+        }
+        fake.insert(
+            "/allergies",
+            serde_json::to_value(allergy(2, "Shellfish")).unwrap(),
+        );
+        let server = fake.spawn().await.unwrap();
+        let client = client_for(&server);
+        let service = AllergyService::new(&client);
+
+        let params = || PatientProfileQueryParams { patients: vec![1] };
+
+        // The first page holds the default 25 records and links to the rest
+        let page = service.find(params()).await.unwrap();
+        assert_eq!(page.count, 30);
+        assert_eq!(page.results.len(), 25);
+        let next = page.next.unwrap();
+        assert!(next.starts_with(server.base_url()));
+        assert!(next.contains("patients=1") && next.contains("offset=25"));
+
+        let all: Vec<Allergy> = service.find_stream(params()).try_collect().await.unwrap();
+        assert_eq!(all.len(), 30);
+        assert!(all.iter().all(|allergy| allergy.patient == 1));
+
+        // limit and offset are honored as sent
+        let window: Value = client
+            .get(
+                "/allergies/",
+                HashMap::from([("patients", "2"), ("limit", "5"), ("offset", "0")]),
+            )
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(window["count"], 1);
+        assert_eq!(window["results"][0]["name"], "Shellfish");
+        assert_eq!(window["next"], Value::Null);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_and_duplicate_records_are_rejected() {
+        let fake = FakeElation::new();
+        let server = fake.spawn().await.unwrap();
+        let client = client_for(&server);
+
+        // Missing fields are reported per field, as DRF does
+        let error = client
+            .post("/patients", &json!({ "first_name": "Jane" }))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::BadRequest(_)));
+        assert!(error.to_string().contains("This field is required."));
+
+        client.post("/patients", &patient()).await.unwrap();
+        let error = client.post("/patients", &patient()).await.unwrap_err();
+        assert!(matches!(error, Error::Conflict(_)));
+        assert_eq!(fake.records("/patients").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_records_and_read_only_resources() {
+        let fake = FakeElation::new();
+        fake.insert("/imaging_centers", json!({ "id": 3, "name": "North" }));
+        let server = fake.spawn().await.unwrap();
+        let client = client_for(&server);
+
+        let error = client.get("/patients/999/", ()).await.unwrap_err();
+        assert!(matches!(error, Error::NotFound(_)));
+
+        let center: Value = client
+            .get("/imaging_centers/3/", ())
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(center["name"], "North");
+
+        // Reference data cannot be written
+        let error = client
+            .post("/imaging_centers", &json!({ "name": "South" }))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::ReqwestError(ref error) if error.status().map(|status| status.as_u16()) == Some(405)
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::resource::Resource;
+use crate::impl_resource;
 use derive_more::derive::Display;
 use serde::{Deserialize, Serialize};
 
@@ -21,13 +21,12 @@ pub struct AncillaryCompany {
     pub name: String,
 }
 
-impl Resource for AncillaryCompany {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/ancillary_companies"
-    }
-}
+impl_resource!(
+    Resource: AncillaryCompany,
+    IdType: i64,
+    Endpoint: "/ancillary_companies",
+    IdField: id,
+);
 
 /// Represents query parameters for searching ancillary companies.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -3,7 +3,7 @@ use serde_with::serde_as;
 use time::OffsetDateTime;
 use utils::time::Rfc3339;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -40,13 +40,12 @@ pub struct CardiacCenter {
     pub zip: String,
 }
 
-impl Resource for CardiacCenter {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/cardiac_centers"
-    }
-}
+impl_resource!(
+    Resource: CardiacCenter,
+    IdType: i64,
+    Endpoint: "/cardiac_centers",
+    IdField: id,
+);
 
 /// Represents the data required to create a new cardiac center.
 #[serde_as]
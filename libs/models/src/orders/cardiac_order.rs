@@ -4,7 +4,7 @@ use serde_with::serde_as;
 use time::{Date, OffsetDateTime};
 use utils::time::Rfc3339;
 
-use crate::{resource::Resource, Icd10Code};
+use crate::{impl_resource, Icd10Code};
 
 time::serde::format_description!(one_true_date, Date, "[year]-[month]-[day]");
 
@@ -62,13 +62,12 @@ pub struct CardiacOrder {
     pub tests: Vec<CardiacOrderTest>,
 }
 
-impl Resource for CardiacOrder {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/cardiac_orders"
-    }
-}
+impl_resource!(
+    Resource: CardiacOrder,
+    IdType: i64,
+    Endpoint: "/cardiac_orders",
+    IdField: id,
+);
 
 /// Represents the data required to create a new cardiac order.
 #[serde_as]
@@ -3,7 +3,7 @@ use serde_with::serde_as;
 use time::OffsetDateTime;
 use utils::time::Rfc3339;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -40,13 +40,12 @@ pub struct ImagingCenter {
     pub zip: String,
 }
 
-impl Resource for ImagingCenter {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/imaging_centers"
-    }
-}
+impl_resource!(
+    Resource: ImagingCenter,
+    IdType: i64,
+    Endpoint: "/imaging_centers",
+    IdField: id,
+);
 
 /// Represents query parameters for searching imaging centers.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
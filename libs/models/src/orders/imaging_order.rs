@@ -4,7 +4,7 @@ use serde_with::serde_as;
 use time::{Date, OffsetDateTime};
 use utils::time::Rfc3339;
 
-use crate::{resource::Resource, Icd10Code};
+use crate::{impl_resource, Icd10Code};
 
 time::serde::format_description!(one_true_date, Date, "[year]-[month]-[day]");
 
@@ -96,13 +96,12 @@ pub struct ImagingOrder {
     pub tests: Vec<ImagingOrderTest>,
 }
 
-impl Resource for ImagingOrder {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/imaging_orders"
-    }
-}
+impl_resource!(
+    Resource: ImagingOrder,
+    IdType: i64,
+    Endpoint: "/imaging_orders",
+    IdField: id,
+);
 
 /// Represents the possible stat methods for an imaging order.
 #[derive(Clone, Debug, Serialize, Deserialize)]
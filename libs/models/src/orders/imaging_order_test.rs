@@ -3,7 +3,7 @@ use serde_with::serde_as;
 use time::OffsetDateTime;
 use utils::time::Rfc3339;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -24,13 +24,12 @@ pub struct ImagingOrderTest {
     pub practice: Option<i64>,
 }
 
-impl Resource for ImagingOrderTest {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/imaging_order_tests"
-    }
-}
+impl_resource!(
+    Resource: ImagingOrderTest,
+    IdType: i64,
+    Endpoint: "/imaging_order_tests",
+    IdField: id,
+);
 
 /// Represents the data required to create a new imaging order test.
 #[derive(Clone, Debug, Serialize, Deserialize)]
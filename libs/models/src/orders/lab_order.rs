@@ -4,7 +4,7 @@ use serde_with::serde_as;
 use time::{Date, OffsetDateTime};
 use utils::time::Rfc3339;
 
-use crate::{resource::Resource, Icd10Code};
+use crate::{impl_resource, Icd10Code};
 
 use super::{Resolution, ResolutionState, StatMethod};
 
@@ -72,13 +72,12 @@ pub struct LabOrder {
     pub facility: Option<Facility>,
 }
 
-impl Resource for LabOrder {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/lab_orders"
-    }
-}
+impl_resource!(
+    Resource: LabOrder,
+    IdType: i64,
+    Endpoint: "/lab_orders",
+    IdField: id,
+);
 
 /// Represents the content of the lab order report.
 #[serde_as]
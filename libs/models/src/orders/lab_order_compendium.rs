@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -30,13 +30,12 @@ pub struct LabOrderCompendium {
     pub deleted_date: Option<OffsetDateTime>,
 }
 
-impl Resource for LabOrderCompendium {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/lab_order_compendiums"
-    }
-}
+impl_resource!(
+    Resource: LabOrderCompendium,
+    IdType: i64,
+    Endpoint: "/lab_order_compendiums",
+    IdField: id,
+);
 
 /// Represents the data required to create a new lab order compendium.
 #[derive(Clone, Debug, Serialize, Deserialize)]
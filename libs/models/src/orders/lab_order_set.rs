@@ -1,5 +1,5 @@
 use super::{FastingMethod, LabOrderTest, LabOrderTestForCreate, StatMethod};
-use crate::{resource::Resource, Icd10Code};
+use crate::{impl_resource, Icd10Code};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::{Date, OffsetDateTime};
@@ -30,13 +30,12 @@ pub struct LabOrderSet {
     pub practice: i64,
 }
 
-impl Resource for LabOrderSet {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/lab_order_sets"
-    }
-}
+impl_resource!(
+    Resource: LabOrderSet,
+    IdType: i64,
+    Endpoint: "/lab_order_sets",
+    IdField: id,
+);
 
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -61,7 +60,7 @@ pub struct LabOrderSetContent {
     pub standing_order_frequency: Option<String>,
 
     /// Date at which the standing order should be canceled.
-    #[serde(with = "one_true_date::option")]
+    #[serde(with = "one_true_date::option", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub standing_order_end_date: Option<Date>,
 
@@ -132,7 +131,7 @@ pub struct LabOrderSetContentForCreate {
     pub standing_order_frequency: Option<String>,
 
     /// Date at which the standing order should be canceled.
-    #[serde(with = "one_true_date::option")]
+    #[serde(with = "one_true_date::option", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub standing_order_end_date: Option<Date>,
 
@@ -1,4 +1,4 @@
-use crate::resource::Resource;
+use crate::impl_resource;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
@@ -50,13 +50,12 @@ pub struct LabOrderTest {
     pub synonyms: Vec<String>,
 }
 
-impl Resource for LabOrderTest {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/lab_order_tests"
-    }
-}
+impl_resource!(
+    Resource: LabOrderTest,
+    IdType: i64,
+    Endpoint: "/lab_order_tests",
+    IdField: id,
+);
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Question {
@@ -184,7 +183,7 @@ pub struct LabOrderTestQueryParams {
     ///
     /// Note: This field corresponds to `practice_created` in the API and is used for filtering lab tests based on the practice.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "practice_created")]
+    #[serde(rename(serialize = "practice_created"))]
     pub practice: Option<Vec<i64>>,
 
     /// The IDs of compendiums.
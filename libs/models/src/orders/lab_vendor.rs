@@ -1,5 +1,5 @@
 use super::lab_order_compendium::LabOrderCompendium;
-use crate::resource::Resource;
+use crate::impl_resource;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
@@ -42,13 +42,12 @@ pub struct LabVendor {
     pub default_compendium: Option<LabOrderCompendium>,
 }
 
-impl Resource for LabVendor {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/lab_vendors"
-    }
-}
+impl_resource!(
+    Resource: LabVendor,
+    IdType: i64,
+    Endpoint: "/lab_vendors",
+    IdField: id,
+);
 
 /// Represents the data required to create a new lab vendor.
 #[derive(Clone, Debug, Serialize, Deserialize)]
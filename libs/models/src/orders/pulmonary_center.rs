@@ -1,4 +1,4 @@
-use crate::resource::Resource;
+use crate::impl_resource;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
@@ -39,13 +39,12 @@ pub struct PulmonaryCenter {
     pub zip: String,
 }
 
-impl Resource for PulmonaryCenter {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/pulmonary_centers"
-    }
-}
+impl_resource!(
+    Resource: PulmonaryCenter,
+    IdType: i64,
+    Endpoint: "/pulmonary_centers",
+    IdField: id,
+);
 
 /// Represents query parameters for searching pulmonary centers.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
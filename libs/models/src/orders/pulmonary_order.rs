@@ -7,7 +7,7 @@ time::serde::format_description!(one_true_date, Date, "[year]-[month]-[day]");
 
 use super::{pulmonary_order_test::PulmonaryOrderTest, Resolution};
 
-use crate::{resource::Resource, Icd10Code};
+use crate::{impl_resource, Icd10Code};
 
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -62,13 +62,12 @@ pub struct PulmonaryOrder {
     pub tests: Vec<PulmonaryOrderTest>,
 }
 
-impl Resource for PulmonaryOrder {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/pulmonary_orders"
-    }
-}
+impl_resource!(
+    Resource: PulmonaryOrder,
+    IdType: i64,
+    Endpoint: "/pulmonary_orders",
+    IdField: id,
+);
 
 /// Represents the data required to create a new pulmonary order.
 #[serde_as]
@@ -3,7 +3,7 @@ use serde_with::serde_as;
 use time::OffsetDateTime;
 use utils::time::Rfc3339;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -24,13 +24,12 @@ pub struct PulmonaryOrderTest {
     pub deleted_date: Option<OffsetDateTime>,
 }
 
-impl Resource for PulmonaryOrderTest {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/pulmonary_order_tests"
-    }
-}
+impl_resource!(
+    Resource: PulmonaryOrderTest,
+    IdType: i64,
+    Endpoint: "/pulmonary_order_tests",
+    IdField: id,
+);
 
 /// Represents the data required to create a new pulmonary order test.
 #[serde_as]
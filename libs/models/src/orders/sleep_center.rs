@@ -1,4 +1,4 @@
-use crate::resource::Resource;
+use crate::impl_resource;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
@@ -39,13 +39,12 @@ pub struct SleepCenter {
     pub zip: String,
 }
 
-impl Resource for SleepCenter {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/sleep_centers"
-    }
-}
+impl_resource!(
+    Resource: SleepCenter,
+    IdType: i64,
+    Endpoint: "/sleep_centers",
+    IdField: id,
+);
 
 /// Represents query parameters for searching sleep centers.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -4,7 +4,7 @@ use time::{Date, OffsetDateTime};
 use utils::time::Rfc3339;
 
 use super::{sleep_order_test::SleepOrderTest, Resolution};
-use crate::{resource::Resource, Icd10Code};
+use crate::{impl_resource, Icd10Code};
 
 time::serde::format_description!(one_true_date, Date, "[year]-[month]-[day]");
 
@@ -66,13 +66,12 @@ pub struct SleepOrder {
     pub tests: Vec<SleepOrderTest>,
 }
 
-impl Resource for SleepOrder {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/sleep_orders"
-    }
-}
+impl_resource!(
+    Resource: SleepOrder,
+    IdType: i64,
+    Endpoint: "/sleep_orders",
+    IdField: id,
+);
 
 /// Represents the data required to create a new sleep order.
 #[serde_as]
@@ -3,7 +3,7 @@ use serde_with::serde_as;
 use time::Date;
 use time::OffsetDateTime;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents an allergy object in the patient profile.
 ///
@@ -112,10 +112,9 @@ pub struct AllergyForUpdate {
     pub medispandnid: Option<String>,
 }
 
-impl Resource for Allergy {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/allergies"
-    }
-}
+impl_resource!(
+    Resource: Allergy,
+    IdType: i64,
+    Endpoint: "/allergies",
+    IdField: id,
+);
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents the allergy documentation (NKDA) object.
 ///
@@ -42,10 +42,9 @@ pub struct AllergyDocumentationForUpdate {
     pub patient: Option<i64>,
 }
 
-impl Resource for AllergyDocumentation {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/allergy_documentation"
-    }
-}
+impl_resource!(
+    Resource: AllergyDocumentation,
+    IdType: i64,
+    Endpoint: "/allergy_documentation",
+    IdField: id,
+);
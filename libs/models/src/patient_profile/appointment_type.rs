@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents an appointment type in the scheduling system.
 ///
@@ -159,10 +159,9 @@ pub struct AppointmentTypeForUpdate {
     pub visit_note_type: Option<String>,
 }
 
-impl Resource for AppointmentType {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/appointment_types"
-    }
-}
+impl_resource!(
+    Resource: AppointmentType,
+    IdType: i64,
+    Endpoint: "/appointment_types",
+    IdField: id,
+);
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::{Date, OffsetDateTime};
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents a drug intolerance in a patient's profile.
 ///
@@ -85,10 +85,9 @@ pub struct DrugIntoleranceForUpdate {
     pub status: Option<bool>,
 }
 
-impl Resource for DrugIntolerance {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/drug_intolerances"
-    }
-}
+impl_resource!(
+    Resource: DrugIntolerance,
+    IdType: i64,
+    Endpoint: "/drug_intolerances",
+    IdField: id,
+);
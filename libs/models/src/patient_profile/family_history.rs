@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents a family history object in the patient profile.
 ///
@@ -92,10 +92,9 @@ pub struct FamilyHistoryForUpdate {
     pub snomed_code: Option<String>,
 }
 
-impl Resource for FamilyHistory {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/family_history"
-    }
-}
+impl_resource!(
+    Resource: FamilyHistory,
+    IdType: i64,
+    Endpoint: "/family_history",
+    IdField: id,
+);
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents a patient's history entry.
 ///
@@ -86,10 +86,9 @@ pub struct HistoryForUpdate {
     pub text: Option<String>,
 }
 
-impl Resource for History {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/histories"
-    }
-}
+impl_resource!(
+    Resource: History,
+    IdType: i64,
+    Endpoint: "/histories",
+    IdField: id,
+);
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::{Date, OffsetDateTime};
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents an immunization object in the patient profile.
 ///
@@ -176,10 +176,9 @@ pub struct ImmunizationForUpdate {
     pub site: Option<String>,
 }
 
-impl Resource for Immunization {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/immunizations"
-    }
-}
+impl_resource!(
+    Resource: Immunization,
+    IdType: i64,
+    Endpoint: "/immunizations",
+    IdField: id,
+);
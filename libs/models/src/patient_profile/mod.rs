@@ -29,7 +29,7 @@ pub use vaccine::*;
 pub mod utils;
 
 /// Represents the default query parameters for finding the various Patient Profile resources
-#[derive(Debug, serde::Serialize, serde::Deserialize, Default)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Default)]
 pub struct PatientProfileQueryParams {
     /// A vector of patient IDs
     pub patients: Vec<i64>,
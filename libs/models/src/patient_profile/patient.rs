@@ -3,7 +3,7 @@ use serde_with::serde_as;
 use time::{Date, OffsetDateTime};
 use utils::time::Rfc3339;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 time::serde::format_description!(one_true_date, Date, "[year]-[month]-[day]");
 
@@ -607,11 +607,9 @@ pub enum SexualOrientation {
     Asexual,
 }
 
-impl Resource for Patient {
-    type Id = i64;
-
-    //type Endpoint = &'static str;
-    fn endpoint() -> &'static str {
-        "/patients"
-    }
-}
+impl_resource!(
+    Resource: Patient,
+    IdType: i64,
+    Endpoint: "/patients",
+    IdField: id,
+);
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents a patient's photo, which includes metadata such as file type, size, dimensions, and more.
 #[serde_as]
@@ -88,10 +88,9 @@ pub struct PatientPhotoForUpdate {
     pub height: Option<i32>,
 }
 
-impl Resource for PatientPhoto {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/patient_photos"
-    }
-}
+impl_resource!(
+    Resource: PatientPhoto,
+    IdType: i64,
+    Endpoint: "/patient_photos",
+    IdField: id,
+);
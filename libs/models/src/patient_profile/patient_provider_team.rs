@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents the Patient Provider Team, which includes a collection of providers assisting in the care of a patient.
 #[serde_as]
@@ -106,10 +106,9 @@ pub struct PatientProviderTeamMemberForUpdate {
     pub treatment_reason: Option<String>,
 }
 
-impl Resource for PatientProviderTeam {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/patient_provider_teams"
-    }
-}
+impl_resource!(
+    Resource: PatientProviderTeam,
+    IdType: i64,
+    Endpoint: "/patient_provider_teams",
+    IdField: patient_provider_team_id,
+);
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::Date;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents a diagnosis in a patient's problem list.
 ///
@@ -117,31 +117,30 @@ pub struct ProblemForUpdate {
     pub start_date: Option<Date>,
 }
 
-/// Represents query parameters for searching patients.
+/// Represents query parameters for searching problems.
 #[serde_as]
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct ProblemQueryParams {
     /// Vector of IDs of patients to find problems for
     pub patients: Vec<i64>,
-    ///// Filter for last modified date greater than (optional).
-    //pub last_modified__gt: Option<String>,
-    ///// Filter for last modified date greater than or equal to (optional).
-    //pub last_modified__gte: Option<String>,
-    ///// Filter for last modified date less than (optional).
-    //pub last_modified__lt: Option<String>,
-    ///// Filter for last modified date less than or equal to (optional).
-    //pub last_modified__lte: Option<String>,
+    /// Filter for last modified date greater than (optional).
+    pub last_modified__gt: Option<String>,
+    /// Filter for last modified date greater than or equal to (optional).
+    pub last_modified__gte: Option<String>,
+    /// Filter for last modified date less than (optional).
+    pub last_modified__lt: Option<String>,
+    /// Filter for last modified date less than or equal to (optional).
+    pub last_modified__lte: Option<String>,
     ///// The maximum number of results to return (optional).
     //pub limit: Option<i32>,
     ///// The offset for pagination (optional).
     //pub offset: Option<i32>,
 }
 
-impl Resource for Problem {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/problems"
-    }
-}
+impl_resource!(
+    Resource: Problem,
+    IdType: i64,
+    Endpoint: "/problems",
+    IdField: id,
+);
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use time::OffsetDateTime;
 
-use crate::resource::Resource;
+use crate::impl_resource;
 
 /// Represents a vaccine object, which can be a publicly accessible vaccine
 /// from Medispan or Elation, or a vaccine entered by the practice.
@@ -103,10 +103,9 @@ pub struct VaccineForUpdate {
     pub practice: Option<String>,
 }
 
-impl Resource for Vaccine {
-    type Id = i64;
-
-    fn endpoint() -> &'static str {
-        "/vaccines"
-    }
-}
+impl_resource!(
+    Resource: Vaccine,
+    IdType: i64,
+    Endpoint: "/vaccines",
+    IdField: id,
+);
@@ -4,4 +4,53 @@ pub trait Resource {
 
     /// Returns the API endpoint for the resource.
     fn endpoint() -> &'static str;
+
+    /// Returns a key that tells this record apart from every other record of its kind, if
+    /// it has one.
+    ///
+    /// Paginated streams skip records whose key they handed out on the previous page. The
+    /// default returns `None`, which turns that deduplication off.
+    fn unique_key(&self) -> Option<String> {
+        None
+    }
+}
+
+/// A resource with an identifier of its own, unique among all records of its kind.
+///
+/// Insurance cards are only told apart by their rank within a patient, so they are not
+/// identifiable. Models that are should implement it with [`impl_resource!`](crate::impl_resource).
+pub trait Identifiable: Resource {
+    /// Returns the identifier of this resource.
+    fn id(&self) -> Self::Id;
+}
+
+/// Implements [`Resource`] and [`Identifiable`] for a model identified by one of its fields.
+///
+/// The model's `unique_key` is its identifier, so streams of it are always deduplicated.
+#[macro_export]
+macro_rules! impl_resource {
+    (
+        Resource: $resource:ty,
+        IdType: $id_type:ty,
+        Endpoint: $endpoint:literal,
+        IdField: $id_field:ident $(,)?
+    ) => {
+        impl $crate::resource::Resource for $resource {
+            type Id = $id_type;
+
+            fn endpoint() -> &'static str {
+                $endpoint
+            }
+
+            fn unique_key(&self) -> Option<String> {
+                Some($crate::resource::Identifiable::id(self).to_string())
+            }
+        }
+
+        impl $crate::resource::Identifiable for $resource {
+            fn id(&self) -> Self::Id {
+                self.$id_field
+            }
+        }
+    };
 }
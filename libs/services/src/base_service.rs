@@ -3,19 +3,27 @@ use crate::resource_service::{
     DeleteService, FindService, GetService, PatchService, PostService, PutService,
 };
 use async_trait::async_trait;
-use client::{Client, PaginatedResponse, Params};
+use client::{Client, PageStream, PaginatedResponse, ParallelPages, Params, RequestOptions};
 use models::resource::Resource;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::fmt::Debug;
+use std::ops::Deref;
+use std::sync::Arc;
+use tracing::{Instrument, Span};
 
+/// The generic implementation behind every service generated by `impl_service!`.
+///
+/// A service either borrows its client, see [`BaseService::new`], or shares ownership of it,
+/// see [`BaseService::shared`]. A shared service is `'static`, so it can be stored in
+/// application state or moved into spawned tasks. Cloning a service is cheap either way.
 pub struct BaseService<'a, T, C, U>
 where
     T: Resource + Serialize + DeserializeOwned + Send + Sync,
     C: Serialize + Send + Sync,
     U: Serialize + Send + Sync,
 {
-    client: &'a Client,
+    client: ClientHandle<'a>,
     _marker: std::marker::PhantomData<(T, C, U)>,
 }
 
@@ -27,12 +35,84 @@ where
 {
     pub fn new(client: &'a Client) -> Self {
         Self {
-            client,
+            client: ClientHandle::Borrowed(client),
             _marker: std::marker::PhantomData,
         }
     }
 }
 
+impl<T, C, U> BaseService<'static, T, C, U>
+where
+    T: Resource + Serialize + DeserializeOwned + Send + Sync,
+    C: Serialize + Send + Sync,
+    U: Serialize + Send + Sync,
+{
+    /// Creates a service that keeps `client` alive for as long as the service or a clone of it exists.
+    pub fn shared(client: Arc<Client>) -> Self {
+        Self {
+            client: ClientHandle::Shared(client),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, C, U> Clone for BaseService<'_, T, C, U>
+where
+    T: Resource + Serialize + DeserializeOwned + Send + Sync,
+    C: Serialize + Send + Sync,
+    U: Serialize + Send + Sync,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, C, U> Debug for BaseService<'_, T, C, U>
+where
+    T: Resource + Serialize + DeserializeOwned + Send + Sync,
+    C: Serialize + Send + Sync,
+    U: Serialize + Send + Sync,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BaseService")
+            .field("resource", &T::endpoint())
+            .field("client", &*self.client)
+            .finish()
+    }
+}
+
+/// A client that is either borrowed or shared.
+#[derive(Clone)]
+pub(crate) enum ClientHandle<'a> {
+    Borrowed(&'a Client),
+    Shared(Arc<Client>),
+}
+
+impl Deref for ClientHandle<'_> {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        match self {
+            ClientHandle::Borrowed(client) => client,
+            ClientHandle::Shared(client) => client,
+        }
+    }
+}
+
+/// Creates the `elation.service` span that the client's request spans are nested in.
+///
+/// * `operation` - The service method being called, e.g. `"get"`.
+fn service_span<T: Resource>(operation: &'static str) -> Span {
+    let resource = std::any::type_name::<T>()
+        .rsplit("::")
+        .next()
+        .unwrap_or_default();
+    tracing::info_span!("elation.service", resource, operation)
+}
+
 #[async_trait]
 impl<'a, T, C, U> GetService<'a, T> for BaseService<'a, T, C, U>
 where
@@ -44,9 +124,17 @@ where
     type Id = T::Id;
 
     async fn get(&self, id: Self::Id) -> Result<T, Error> {
+        self.get_with(id, &RequestOptions::default()).await
+    }
+
+    async fn get_with(&self, id: Self::Id, options: &RequestOptions) -> Result<T, Error> {
         let endpoint = format!("{}/{}/", T::endpoint(), id.to_string());
-        let response = self.client.get(&endpoint, ()).await?;
-        let resource = response.json::<T>().await?;
+        let resource = async {
+            let response = self.client.get_with(&endpoint, (), options).await?;
+            Ok::<_, Error>(response.json::<T>().await?)
+        }
+        .instrument(service_span::<T>("get"))
+        .await?;
         Ok(resource)
     }
 }
@@ -60,9 +148,17 @@ where
     U: Serialize + Send + Sync,
 {
     async fn post(&self, resource: &C) -> Result<T, Error> {
+        self.post_with(resource, &RequestOptions::default()).await
+    }
+
+    async fn post_with(&self, resource: &C, options: &RequestOptions) -> Result<T, Error> {
         let endpoint = T::endpoint();
-        let response = self.client.post(endpoint, resource).await?;
-        let created_resource = response.json::<T>().await?;
+        let created_resource = async {
+            let response = self.client.post_with(endpoint, resource, options).await?;
+            Ok::<_, Error>(response.json::<T>().await?)
+        }
+        .instrument(service_span::<T>("post"))
+        .await?;
         Ok(created_resource)
     }
 }
@@ -78,9 +174,23 @@ where
     type Id = T::Id;
 
     async fn patch(&self, id: Self::Id, resource: &U) -> Result<T, Error> {
+        self.patch_with(id, resource, &RequestOptions::default())
+            .await
+    }
+
+    async fn patch_with(
+        &self,
+        id: Self::Id,
+        resource: &U,
+        options: &RequestOptions,
+    ) -> Result<T, Error> {
         let endpoint = format!("{}/{}/", T::endpoint(), id.to_string());
-        let response = self.client.patch(&endpoint, resource).await?;
-        let updated_resource = response.json::<T>().await?;
+        let updated_resource = async {
+            let response = self.client.patch_with(&endpoint, resource, options).await?;
+            Ok::<_, Error>(response.json::<T>().await?)
+        }
+        .instrument(service_span::<T>("patch"))
+        .await?;
         Ok(updated_resource)
     }
 }
@@ -96,8 +206,15 @@ where
     type Id = T::Id;
 
     async fn delete(&self, id: Self::Id) -> Result<(), Error> {
+        self.delete_with(id, &RequestOptions::default()).await
+    }
+
+    async fn delete_with(&self, id: Self::Id, options: &RequestOptions) -> Result<(), Error> {
         let endpoint = format!("{}/{}/", T::endpoint(), id.to_string());
-        self.client.delete(&endpoint).await?;
+        self.client
+            .delete_with(&endpoint, options)
+            .instrument(service_span::<T>("delete"))
+            .await?;
         Ok(())
     }
 }
@@ -113,9 +230,25 @@ where
     type Id = T::Id;
 
     async fn put(&self, resource_for_create: &C) -> Result<T, Error> {
+        self.put_with(resource_for_create, &RequestOptions::default())
+            .await
+    }
+
+    async fn put_with(
+        &self,
+        resource_for_create: &C,
+        options: &RequestOptions,
+    ) -> Result<T, Error> {
         let endpoint = format!("{}/", T::endpoint());
-        let response = self.client.put(&endpoint, resource_for_create).await?;
-        let updated_resource = response.json::<T>().await?;
+        let updated_resource = async {
+            let response = self
+                .client
+                .put_with(&endpoint, resource_for_create, options)
+                .await?;
+            Ok::<_, Error>(response.json::<T>().await?)
+        }
+        .instrument(service_span::<T>("put"))
+        .await?;
         Ok(updated_resource)
     }
 }
@@ -123,16 +256,64 @@ where
 #[async_trait]
 impl<'a, T, C, U, P> FindService<'a, T, P> for BaseService<'a, T, C, U>
 where
-    T: Resource + Serialize + DeserializeOwned + Send + Sync + Debug,
+    T: Resource + Serialize + DeserializeOwned + Send + Sync + Debug + 'static,
     T::Id: ToString + Send + Sync,
     C: Serialize + Send + Sync + Debug,
     U: Serialize + Send + Sync + Debug,
-    P: Params + Send + Sync + 'a,
+    P: Params + Clone + Send + Sync + 'a,
 {
     async fn find(&self, params: P) -> Result<PaginatedResponse<T>, Error> {
+        self.find_with(params, &RequestOptions::default()).await
+    }
+    async fn find_with(
+        &self,
+        params: P,
+        options: &RequestOptions,
+    ) -> Result<PaginatedResponse<T>, Error> {
         let endpoint = format!("{}/", T::endpoint());
-        let response = self.client.get(&endpoint, params).await?;
-        let paginated_response = response.json::<PaginatedResponse<T>>().await?;
+        let paginated_response = async {
+            let response = self.client.get_with(&endpoint, params, options).await?;
+            Ok::<_, Error>(response.json::<PaginatedResponse<T>>().await?)
+        }
+        .instrument(service_span::<T>("find"))
+        .await?;
         Ok(paginated_response)
     }
+    fn find_stream(&self, params: P) -> PageStream<'a, T, Error> {
+        self.find_stream_with(params, &RequestOptions::default())
+    }
+    fn find_stream_with(&self, params: P, options: &RequestOptions) -> PageStream<'a, T, Error> {
+        let client = self.client.clone();
+        let first_options = options.clone();
+        let first = async move {
+            let endpoint = format!("{}/", T::endpoint());
+            let response = client.get_with(&endpoint, params, &first_options).await?;
+            Ok(response.json::<PaginatedResponse<T>>().await?)
+        };
+
+        PageStream::new(
+            &self.client,
+            first.instrument(service_span::<T>("find_stream")),
+        )
+        .request_options(options.clone())
+        .dedup_by(|resource: &T| resource.unique_key())
+    }
+    async fn find_all_parallel(&self, params: P, options: ParallelPages) -> Result<Vec<T>, Error> {
+        self.find_all_parallel_with(params, options, &RequestOptions::default())
+            .await
+    }
+    async fn find_all_parallel_with(
+        &self,
+        params: P,
+        options: ParallelPages,
+        request_options: &RequestOptions,
+    ) -> Result<Vec<T>, Error> {
+        let endpoint = format!("{}/", T::endpoint());
+        let resources = self
+            .client
+            .get_all_pages_parallel_with(&endpoint, params, &options, request_options)
+            .instrument(service_span::<T>("find_all_parallel"))
+            .await?;
+        Ok(resources)
+    }
 }
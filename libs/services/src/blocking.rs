@@ -0,0 +1,105 @@
+//! Synchronous equivalents of the services generated by `impl_service!`.
+//!
+//! Enabled by the `blocking` feature. Each service here wraps a [`client::blocking::Client`]
+//! and offers the methods of its async counterpart without `.await`, returning the same
+//! models and [`Error`](crate::Error) type. Calls must not be made from within an async
+//! context.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use services::blocking::PatientService;
+//!
+//! # fn run() -> services::Result<()> {
+//! let client = client::blocking::Client::new()?;
+//! let service = PatientService::new(&client);
+//!
+//! let patient = service.get(7)?;
+//! println!("{} {}", patient.first_name, patient.last_name);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{future::Future, marker::PhantomData};
+
+use client::{blocking, Client};
+
+use crate::Result;
+
+/// A synchronous service for the resources of the async service `S`.
+///
+/// Use the aliases of this module, e.g. [`PatientService`], rather than naming `S` directly.
+/// The methods available depend on the traits `S` implements.
+#[derive(Debug, Clone)]
+pub struct Service<S> {
+    client: blocking::Client,
+    _service: PhantomData<fn() -> S>,
+}
+
+impl<S> Service<S> {
+    /// Creates a service that sends its requests through `client`.
+    pub fn new(client: &blocking::Client) -> Self {
+        Self {
+            client: client.clone(),
+            _service: PhantomData,
+        }
+    }
+
+    /// Returns the blocking client the service sends its requests through.
+    pub fn client(&self) -> &blocking::Client {
+        &self.client
+    }
+
+    /// Runs the future `call` creates with the async client on the blocking client's runtime.
+    #[doc(hidden)]
+    pub fn block_on<'s, F, Fut, T>(&'s self, call: F) -> Result<T>
+    where
+        F: FnOnce(&'s Client) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.client.block_on(call(self.client.as_async()))
+    }
+}
+
+macro_rules! blocking_services {
+    ($($module:ident::$service:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("A synchronous [`", stringify!($service), "`](crate::", stringify!($module), "::", stringify!($service), ").")]
+            pub type $service = Service<crate::$module::$service<'static>>;
+        )*
+    };
+}
+
+blocking_services!(
+    orders::AncillaryCompanyService,
+    orders::CardiacCenterService,
+    orders::CardiacOrderService,
+    orders::CardiacOrderTestService,
+    orders::ImagingCenterService,
+    orders::ImagingOrderService,
+    orders::ImagingOrderTestService,
+    orders::LabOrderCompendiumService,
+    orders::LabOrderService,
+    orders::LabOrderSetService,
+    orders::LabOrderTestService,
+    orders::LabVendorService,
+    orders::PulmonaryCenterService,
+    orders::PulmonaryOrderService,
+    orders::PulmonaryOrderTestService,
+    orders::SleepCenterService,
+    orders::SleepOrderService,
+    orders::SleepOrderTestService,
+    patient_profile::AllergyDocumentationService,
+    patient_profile::AllergyService,
+    patient_profile::AppointmentTypeService,
+    patient_profile::DrugIntoleranceService,
+    patient_profile::FamilyHistoryService,
+    patient_profile::HistoryService,
+    patient_profile::ImmunizationService,
+    patient_profile::InsuranceCardService,
+    patient_profile::PatientPhotoService,
+    patient_profile::PatientProviderTeamService,
+    patient_profile::PatientService,
+    patient_profile::ProblemService,
+    patient_profile::VaccineService,
+);
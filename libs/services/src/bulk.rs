@@ -0,0 +1,261 @@
+use async_trait::async_trait;
+use client::RequestOptions;
+use futures::{stream, StreamExt};
+use models::resource::Resource;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+use crate::resource_service::{DeleteService, PatchService, PostService};
+
+/// Configuration for sending many requests of a bulk operation at once.
+#[derive(Debug, Clone)]
+pub struct BulkOptions {
+    /// The maximum number of requests in flight at the same time.
+    ///
+    /// Every request still waits for the client's rate limiter, so a higher concurrency only
+    /// helps while the limiter has capacity to spare.
+    pub concurrency: usize,
+}
+
+impl Default for BulkOptions {
+    fn default() -> Self {
+        Self { concurrency: 4 }
+    }
+}
+
+/// The outcome of a bulk operation: one result per input item, in input order.
+#[derive(Debug)]
+pub struct BulkReport<T> {
+    results: Vec<Result<T>>,
+}
+
+impl<T> BulkReport<T> {
+    /// Returns the result for each input item, in input order.
+    pub fn results(&self) -> &[Result<T>] {
+        &self.results
+    }
+
+    /// Returns the result for each input item, in input order.
+    pub fn into_results(self) -> Vec<Result<T>> {
+        self.results
+    }
+
+    /// Returns the number of input items.
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Returns whether the operation had no input items.
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Returns whether every item succeeded.
+    pub fn is_success(&self) -> bool {
+        self.results.iter().all(Result::is_ok)
+    }
+
+    /// Iterates over the items that succeeded, with their index in the input.
+    pub fn successes(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| result.as_ref().ok().map(|value| (index, value)))
+    }
+
+    /// Iterates over the items that failed, with their index in the input.
+    pub fn failures(&self) -> impl Iterator<Item = (usize, &Error)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| result.as_ref().err().map(|error| (index, error)))
+    }
+
+    /// Returns the number of items that failed.
+    pub fn failure_count(&self) -> usize {
+        self.results.iter().filter(|result| result.is_err()).count()
+    }
+}
+
+impl<T> From<Vec<Result<T>>> for BulkReport<T> {
+    fn from(results: Vec<Result<T>>) -> Self {
+        Self { results }
+    }
+}
+
+impl<T> IntoIterator for BulkReport<T> {
+    type Item = Result<T>;
+    type IntoIter = std::vec::IntoIter<Result<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+/// Creates many resources concurrently.
+///
+/// Implemented for every service that can post. A failed item does not stop the others; its
+/// error is reported at its index in the [`BulkReport`].
+///
+/// ### Example:
+/// ```rust,ignore
+/// let report = ProblemService::new(&client)
+///     .post_many(&problems, BulkOptions::default())
+///     .await;
+/// for (index, error) in report.failures() {
+///     eprintln!("problem {index} was not created: {error}");
+/// }
+/// ```
+#[async_trait]
+pub trait BulkPostService<'a, T, C> {
+    /// Posts each of `resources`, sending up to `options.concurrency` requests at a time.
+    async fn post_many(&self, resources: &[C], options: BulkOptions) -> BulkReport<T>;
+
+    /// Like `post_many`, applying `request_options` to every request.
+    async fn post_many_with(
+        &self,
+        resources: &[C],
+        options: BulkOptions,
+        request_options: &RequestOptions,
+    ) -> BulkReport<T>;
+}
+
+#[async_trait]
+impl<'a, S, T, C> BulkPostService<'a, T, C> for S
+where
+    S: PostService<'a, T, C> + Sync,
+    T: Resource + DeserializeOwned + Send + Sync,
+    C: Serialize + Send + Sync,
+{
+    async fn post_many(&self, resources: &[C], options: BulkOptions) -> BulkReport<T> {
+        self.post_many_with(resources, options, &RequestOptions::default())
+            .await
+    }
+
+    async fn post_many_with(
+        &self,
+        resources: &[C],
+        options: BulkOptions,
+        request_options: &RequestOptions,
+    ) -> BulkReport<T> {
+        // The calls are lazy, so none is sent before the stream polls it
+        let calls = resources
+            .iter()
+            .map(|resource| self.post_with(resource, request_options))
+            .collect::<Vec<_>>();
+        let results = stream::iter(calls)
+            .buffered(options.concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await;
+        BulkReport::from(results)
+    }
+}
+
+/// Partially updates many resources concurrently.
+///
+/// Implemented for every service that can patch. A failed item does not stop the others; its
+/// error is reported at its index in the [`BulkReport`].
+#[async_trait]
+pub trait BulkPatchService<'a, T, U> {
+    type Id: ToString + Send + Sync;
+
+    /// Patches the resource with each id with its params, sending up to
+    /// `options.concurrency` requests at a time.
+    async fn patch_many(&self, patches: &[(Self::Id, U)], options: BulkOptions) -> BulkReport<T>;
+
+    /// Like `patch_many`, applying `request_options` to every request.
+    async fn patch_many_with(
+        &self,
+        patches: &[(Self::Id, U)],
+        options: BulkOptions,
+        request_options: &RequestOptions,
+    ) -> BulkReport<T>;
+}
+
+#[async_trait]
+impl<'a, S, T, U> BulkPatchService<'a, T, U> for S
+where
+    S: PatchService<'a, T, U> + Sync,
+    <S as PatchService<'a, T, U>>::Id: Clone,
+    T: Resource + DeserializeOwned + Send + Sync,
+    U: Serialize + Send + Sync,
+{
+    type Id = <S as PatchService<'a, T, U>>::Id;
+
+    async fn patch_many(&self, patches: &[(Self::Id, U)], options: BulkOptions) -> BulkReport<T> {
+        self.patch_many_with(patches, options, &RequestOptions::default())
+            .await
+    }
+
+    async fn patch_many_with(
+        &self,
+        patches: &[(Self::Id, U)],
+        options: BulkOptions,
+        request_options: &RequestOptions,
+    ) -> BulkReport<T> {
+        // The calls are lazy, so none is sent before the stream polls it
+        let calls = patches
+            .iter()
+            .map(|(id, params)| self.patch_with(id.clone(), params, request_options))
+            .collect::<Vec<_>>();
+        let results = stream::iter(calls)
+            .buffered(options.concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await;
+        BulkReport::from(results)
+    }
+}
+
+/// Deletes many resources concurrently.
+///
+/// Implemented for every service that can delete. A failed item does not stop the others; its
+/// error is reported at its index in the [`BulkReport`].
+#[async_trait]
+pub trait BulkDeleteService<'a> {
+    type Id: ToString + Send + Sync;
+
+    /// Deletes the resource with each of `ids`, sending up to `options.concurrency` requests
+    /// at a time.
+    async fn delete_many(&self, ids: &[Self::Id], options: BulkOptions) -> BulkReport<()>;
+
+    /// Like `delete_many`, applying `request_options` to every request.
+    async fn delete_many_with(
+        &self,
+        ids: &[Self::Id],
+        options: BulkOptions,
+        request_options: &RequestOptions,
+    ) -> BulkReport<()>;
+}
+
+#[async_trait]
+impl<'a, S> BulkDeleteService<'a> for S
+where
+    S: DeleteService<'a> + Sync,
+    <S as DeleteService<'a>>::Id: Clone,
+{
+    type Id = <S as DeleteService<'a>>::Id;
+
+    async fn delete_many(&self, ids: &[Self::Id], options: BulkOptions) -> BulkReport<()> {
+        self.delete_many_with(ids, options, &RequestOptions::default())
+            .await
+    }
+
+    async fn delete_many_with(
+        &self,
+        ids: &[Self::Id],
+        options: BulkOptions,
+        request_options: &RequestOptions,
+    ) -> BulkReport<()> {
+        // The calls are lazy, so none is sent before the stream polls it
+        let calls = ids
+            .iter()
+            .map(|id| self.delete_with(id.clone(), request_options))
+            .collect::<Vec<_>>();
+        let results = stream::iter(calls)
+            .buffered(options.concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await;
+        BulkReport::from(results)
+    }
+}
@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use client::ResponseCache;
+use models::resource::Resource;
+use models::{
+    AppointmentType, CardiacCenter, ImagingCenter, LabOrderCompendium, LabOrderTest, LabVendor,
+};
+
+/// Registers resources with a `ResponseCache` by their model type instead of their endpoint.
+pub trait CacheResources: Sized {
+    /// Caches `GetService::get` and `FindService::find` responses for `T` for `ttl`.
+    fn resource<T: Resource>(self, ttl: Duration) -> Self;
+
+    /// Caches the reference data fetched for every order screen for `ttl`.
+    ///
+    /// These are lab vendors, lab order compendiums and tests, imaging and cardiac
+    /// centers, and appointment types, which change rarely.
+    fn reference_data(self, ttl: Duration) -> Self {
+        self.resource::<LabVendor>(ttl)
+            .resource::<LabOrderCompendium>(ttl)
+            .resource::<LabOrderTest>(ttl)
+            .resource::<ImagingCenter>(ttl)
+            .resource::<CardiacCenter>(ttl)
+            .resource::<AppointmentType>(ttl)
+    }
+}
+
+impl CacheResources for ResponseCache {
+    fn resource<T: Resource>(self, ttl: Duration) -> Self {
+        self.ttl(T::endpoint(), ttl)
+    }
+}
@@ -0,0 +1,382 @@
+use std::{fmt, sync::Arc};
+
+use client::{Client, PageStream, RequestOptions};
+use futures::TryStreamExt;
+use models::orders::{
+    CardiacOrder, CardiacOrderQueryParams, ImagingOrder, ImagingOrderQueryParams, LabOrder,
+    LabOrderQueryParams, PulmonaryOrder, PulmonaryOrderQueryParams, SleepOrder,
+    SleepOrderQueryParams,
+};
+use models::patient_profile::{
+    Allergy, DrugIntolerance, FamilyHistory, History, Immunization, InsuranceCard, Patient,
+    PatientProfileQueryParams, PatientProviderTeam, Problem,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::Instrument;
+
+use crate::base_service::ClientHandle;
+use crate::error::Error;
+use crate::loader::PatientScoped;
+use crate::orders::{
+    CardiacOrderService, ImagingOrderService, LabOrderService, PulmonaryOrderService,
+    SleepOrderService,
+};
+use crate::patient_profile::{
+    AllergyService, DrugIntoleranceService, FamilyHistoryService, HistoryService,
+    ImmunizationService, InsuranceCardService, PatientProviderTeamService, PatientService,
+    ProblemService,
+};
+use crate::resource_service::{FindService, GetService};
+
+/// A part of a patient's chart that [`ChartService`] can load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartSection {
+    Allergies,
+    Problems,
+    DrugIntolerances,
+    Immunizations,
+    Histories,
+    FamilyHistories,
+    ProviderTeam,
+    InsuranceCards,
+    LabOrders,
+    ImagingOrders,
+    CardiacOrders,
+    PulmonaryOrders,
+    SleepOrders,
+}
+
+impl ChartSection {
+    /// Every section of the chart.
+    pub const ALL: [ChartSection; 13] = [
+        ChartSection::Allergies,
+        ChartSection::Problems,
+        ChartSection::DrugIntolerances,
+        ChartSection::Immunizations,
+        ChartSection::Histories,
+        ChartSection::FamilyHistories,
+        ChartSection::ProviderTeam,
+        ChartSection::InsuranceCards,
+        ChartSection::LabOrders,
+        ChartSection::ImagingOrders,
+        ChartSection::CardiacOrders,
+        ChartSection::PulmonaryOrders,
+        ChartSection::SleepOrders,
+    ];
+}
+
+/// The outcome of loading one part of a chart.
+pub type Section<T> = core::result::Result<T, SectionError>;
+
+/// Why a part of a chart could not be loaded.
+///
+/// Unlike [`Error`], it can be deserialized, so charts can be cached with their failures.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionError {
+    /// The kind of error, e.g. `"NotFound"`, see `client::Error::kind`.
+    pub kind: String,
+
+    /// The error message.
+    pub message: String,
+}
+
+impl From<Error> for SectionError {
+    fn from(error: Error) -> Self {
+        let kind = match &error {
+            Error::ClientError(error) => error.kind(),
+            Error::InvalidInput(_) => "InvalidInput",
+            Error::Checkpoint(_) => "Checkpoint",
+            Error::Callback(_) => "Callback",
+        };
+        Self {
+            kind: kind.to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.kind, self.message)
+    }
+}
+
+impl std::error::Error for SectionError {}
+
+/// A snapshot of a patient's clinical record, as loaded by [`ChartService::load`].
+///
+/// The patient is always loaded. Every other section is `None` unless it was requested, and
+/// carries its own result otherwise, so one failing section leaves the others usable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatientChart {
+    /// The id of the patient the chart belongs to.
+    pub patient_id: i64,
+
+    /// The patient's demographics.
+    pub patient: Section<Patient>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allergies: Option<Section<Vec<Allergy>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub problems: Option<Section<Vec<Problem>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drug_intolerances: Option<Section<Vec<DrugIntolerance>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub immunizations: Option<Section<Vec<Immunization>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub histories: Option<Section<Vec<History>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family_histories: Option<Section<Vec<FamilyHistory>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_team: Option<Section<Vec<PatientProviderTeam>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insurance_cards: Option<Section<Vec<InsuranceCard>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lab_orders: Option<Section<Vec<LabOrder>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imaging_orders: Option<Section<Vec<ImagingOrder>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cardiac_orders: Option<Section<Vec<CardiacOrder>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pulmonary_orders: Option<Section<Vec<PulmonaryOrder>>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sleep_orders: Option<Section<Vec<SleepOrder>>>,
+}
+
+impl PatientChart {
+    /// Returns the error of `section`, if it was requested and failed.
+    pub fn section_error(&self, section: ChartSection) -> Option<&SectionError> {
+        fn error<T>(section: &Option<Section<T>>) -> Option<&SectionError> {
+            section.as_ref().and_then(|section| section.as_ref().err())
+        }
+
+        match section {
+            ChartSection::Allergies => error(&self.allergies),
+            ChartSection::Problems => error(&self.problems),
+            ChartSection::DrugIntolerances => error(&self.drug_intolerances),
+            ChartSection::Immunizations => error(&self.immunizations),
+            ChartSection::Histories => error(&self.histories),
+            ChartSection::FamilyHistories => error(&self.family_histories),
+            ChartSection::ProviderTeam => error(&self.provider_team),
+            ChartSection::InsuranceCards => error(&self.insurance_cards),
+            ChartSection::LabOrders => error(&self.lab_orders),
+            ChartSection::ImagingOrders => error(&self.imaging_orders),
+            ChartSection::CardiacOrders => error(&self.cardiac_orders),
+            ChartSection::PulmonaryOrders => error(&self.pulmonary_orders),
+            ChartSection::SleepOrders => error(&self.sleep_orders),
+        }
+    }
+
+    /// Returns the requested sections that failed to load.
+    pub fn failed_sections(&self) -> Vec<ChartSection> {
+        ChartSection::ALL
+            .into_iter()
+            .filter(|section| self.section_error(*section).is_some())
+            .collect()
+    }
+
+    /// Returns whether the patient and every requested section loaded.
+    pub fn is_complete(&self) -> bool {
+        self.patient.is_ok() && self.failed_sections().is_empty()
+    }
+}
+
+/// Loads [`PatientChart`]s, fetching their sections concurrently.
+///
+/// ### Example:
+/// ```rust,ignore
+/// let chart = ChartService::new(&client)
+///     .load(patient_id, &[ChartSection::Allergies, ChartSection::Problems])
+///     .await;
+/// if let Some(Ok(allergies)) = &chart.allergies {
+///     println!("{} allergies", allergies.len());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ChartService<'a> {
+    client: ClientHandle<'a>,
+}
+
+impl<'a> ChartService<'a> {
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client: ClientHandle::Borrowed(client),
+        }
+    }
+}
+
+impl ChartService<'static> {
+    /// Creates a service that keeps `client` alive for as long as the service or a clone of it exists.
+    pub fn shared(client: Arc<Client>) -> Self {
+        Self {
+            client: ClientHandle::Shared(client),
+        }
+    }
+}
+
+impl ChartService<'_> {
+    /// Loads the patient with `patient_id` and the given `sections` of their chart.
+    ///
+    /// All requests are sent concurrently, subject to the client's rate limits, and every
+    /// page of each section is fetched. A failed request fails only its own section.
+    pub async fn load(&self, patient_id: i64, sections: &[ChartSection]) -> PatientChart {
+        self.load_with(patient_id, sections, &RequestOptions::default())
+            .await
+    }
+
+    /// Like `load`, applying `options` to every request.
+    pub async fn load_with(
+        &self,
+        patient_id: i64,
+        sections: &[ChartSection],
+        options: &RequestOptions,
+    ) -> PatientChart {
+        let client = &*self.client;
+        let wanted = |section| sections.contains(&section);
+        let profile = || PatientProfileQueryParams {
+            patients: vec![patient_id],
+        };
+
+        let (
+            patient,
+            allergies,
+            problems,
+            drug_intolerances,
+            immunizations,
+            histories,
+            family_histories,
+            provider_team,
+            insurance_cards,
+            lab_orders,
+            imaging_orders,
+            cardiac_orders,
+            pulmonary_orders,
+            sleep_orders,
+        ) = async {
+            futures::join!(
+                async {
+                    PatientService::new(client)
+                        .get_with(patient_id, options)
+                        .await
+                        .map_err(SectionError::from)
+                },
+                section(wanted(ChartSection::Allergies), || {
+                    AllergyService::new(client).find_stream_with(profile(), options)
+                }),
+                section(wanted(ChartSection::Problems), || {
+                    ProblemService::new(client)
+                        .find_stream_with(Problem::for_patients(vec![patient_id]), options)
+                }),
+                section(wanted(ChartSection::DrugIntolerances), || {
+                    DrugIntoleranceService::new(client).find_stream_with(profile(), options)
+                }),
+                section(wanted(ChartSection::Immunizations), || {
+                    ImmunizationService::new(client).find_stream_with(profile(), options)
+                }),
+                section(wanted(ChartSection::Histories), || {
+                    HistoryService::new(client).find_stream_with(profile(), options)
+                }),
+                section(wanted(ChartSection::FamilyHistories), || {
+                    FamilyHistoryService::new(client).find_stream_with(profile(), options)
+                }),
+                section(wanted(ChartSection::ProviderTeam), || {
+                    PatientProviderTeamService::new(client).find_stream_with(profile(), options)
+                }),
+                section(wanted(ChartSection::InsuranceCards), || {
+                    InsuranceCardService::new(client).find_stream_with(profile(), options)
+                }),
+                section(wanted(ChartSection::LabOrders), || {
+                    let params = LabOrderQueryParams {
+                        patient: Some(patient_id),
+                        ..Default::default()
+                    };
+                    LabOrderService::new(client).find_stream_with(params, options)
+                }),
+                section(wanted(ChartSection::ImagingOrders), || {
+                    let params = ImagingOrderQueryParams {
+                        patient: Some(patient_id.to_string()),
+                        ..Default::default()
+                    };
+                    ImagingOrderService::new(client).find_stream_with(params, options)
+                }),
+                section(wanted(ChartSection::CardiacOrders), || {
+                    let params = CardiacOrderQueryParams {
+                        patient: Some(patient_id),
+                        ..Default::default()
+                    };
+                    CardiacOrderService::new(client).find_stream_with(params, options)
+                }),
+                section(wanted(ChartSection::PulmonaryOrders), || {
+                    let params = PulmonaryOrderQueryParams {
+                        patient: Some(patient_id),
+                        ..Default::default()
+                    };
+                    PulmonaryOrderService::new(client).find_stream_with(params, options)
+                }),
+                section(wanted(ChartSection::SleepOrders), || {
+                    let params = SleepOrderQueryParams {
+                        patient: Some(patient_id),
+                        ..Default::default()
+                    };
+                    SleepOrderService::new(client).find_stream_with(params, options)
+                }),
+            )
+        }
+        .instrument(tracing::info_span!("elation.chart", patient_id))
+        .await;
+
+        PatientChart {
+            patient_id,
+            patient,
+            allergies,
+            problems,
+            drug_intolerances,
+            immunizations,
+            histories,
+            family_histories,
+            provider_team,
+            insurance_cards,
+            lab_orders,
+            imaging_orders,
+            cardiac_orders,
+            pulmonary_orders,
+            sleep_orders,
+        }
+    }
+}
+
+impl fmt::Debug for ChartService<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChartService")
+            .field("client", &*self.client)
+            .finish()
+    }
+}
+
+/// Collects every resource of `stream` if the section was requested.
+async fn section<'s, T>(
+    wanted: bool,
+    stream: impl FnOnce() -> PageStream<'s, T, Error>,
+) -> Option<Section<Vec<T>>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    if !wanted {
+        return None;
+    }
+    Some(stream().try_collect().await.map_err(SectionError::from))
+}
@@ -41,6 +41,16 @@ pub enum Error {
     ///
     /// Contains a message describing the invalid input.
     InvalidInput(String),
+
+    /// Represents an error when a sync checkpoint could not be loaded or saved.
+    ///
+    /// Contains a message describing what went wrong with the checkpoint store.
+    Checkpoint(String),
+
+    /// Represents an error returned by a sync callback that rejected a change set.
+    ///
+    /// Wraps the callback's own error; the rejected changes are not checkpointed.
+    Callback(#[serde_as(as = "DisplayFromStr")] Box<dyn std::error::Error + Send + Sync>),
 }
 
 impl fmt::Display for Error {
@@ -62,6 +72,8 @@ impl fmt::Display for Error {
         match self {
             Error::InvalidInput(msg) => write!(fmt, "Invalid input: {}", msg),
             Error::ClientError(e) => write!(fmt, "{}", e),
+            Error::Checkpoint(msg) => write!(fmt, "Checkpoint error: {}", msg),
+            Error::Callback(e) => write!(fmt, "Sync callback failed: {}", e),
         }
     }
 }
@@ -0,0 +1,329 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    str::FromStr,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use client::{Params, RequestOptions};
+use futures::{future, StreamExt, TryStreamExt};
+use models::orders::{LabOrder, LabOrderForCreate, LabOrderQueryParams};
+use models::patient_profile::{Patient, PatientForCreate, PatientQueryParams};
+use models::resource::{Identifiable, Resource};
+use reqwest::header::{HeaderName, HeaderValue};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+use crate::resource_service::{FindService, GetService, PostService};
+
+/// The header the idempotency key is sent in, for gateways and APIs that honor it.
+const IDEMPOTENCY_KEY_HEADER: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// How long a key is remembered unless `IdempotencyStore::ttl` says otherwise.
+const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Identifies one logical create, however many times it is attempted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    /// Uses a key chosen by the caller, e.g. the id of the form the user submitted.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+
+    /// Derives a key from the endpoint of `T` and the content of `resource`.
+    ///
+    /// Posting the same content twice yields the same key, so a second, deliberate create of
+    /// identical content within the store's time to live needs a key of its own.
+    pub fn derive<T: Resource, C: Serialize>(resource: &C) -> Result<Self> {
+        // serde_json sorts object keys, which makes the serialization canonical
+        let content =
+            serde_json::to_string(&serde_json::to_value(resource).map_err(client::Error::from)?)
+                .map_err(client::Error::from)?;
+        let endpoint = T::endpoint().trim_matches('/');
+        Ok(Self(format!("{endpoint}-{:016x}", fnv1a(&content))))
+    }
+
+    /// Returns the key as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for IdempotencyKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Remembers which record each recent idempotency key created.
+///
+/// Entries are kept in memory for a time to live, 24 hours by default. Clones share their
+/// entries, so one store can protect every service of an application.
+#[derive(Debug, Clone)]
+pub struct IdempotencyStore {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<IdempotencyKey, (String, Instant)>>>,
+}
+
+impl Default for IdempotencyStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdempotencyStore {
+    /// Creates an empty store that remembers keys for 24 hours.
+    pub fn new() -> Self {
+        Self {
+            ttl: DEFAULT_TTL,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Sets how long a key is remembered after its record was created.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Returns the id of the record created for `key`, if it is still remembered.
+    pub fn get(&self, key: &IdempotencyKey) -> Option<String> {
+        let mut entries = self.entries();
+        self.purge(&mut entries);
+        entries.get(key).map(|(id, _)| id.clone())
+    }
+
+    /// Remembers that `key` created the record with `id`.
+    pub fn insert(&self, key: IdempotencyKey, id: impl ToString) {
+        let mut entries = self.entries();
+        self.purge(&mut entries);
+        entries.insert(key, (id.to_string(), Instant::now()));
+    }
+
+    /// Forgets `key`, e.g. after its record was deleted.
+    pub fn remove(&self, key: &IdempotencyKey) {
+        self.entries().remove(key);
+    }
+
+    /// Returns the number of keys remembered.
+    pub fn len(&self) -> usize {
+        let mut entries = self.entries();
+        self.purge(&mut entries);
+        entries.len()
+    }
+
+    /// Returns whether no key is remembered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn purge(&self, entries: &mut HashMap<IdempotencyKey, (String, Instant)>) {
+        entries.retain(|_, (_, stored_at)| stored_at.elapsed() < self.ttl);
+    }
+
+    fn entries(&self) -> MutexGuard<'_, HashMap<IdempotencyKey, (String, Instant)>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A create body that can be recognized among existing records.
+///
+/// After an ambiguous failure, the records found with `reconcile_query` are checked with
+/// `matches` to tell whether the create went through after all.
+pub trait Reconcile<T, P> {
+    /// Returns the query that finds every record this body may have created.
+    fn reconcile_query(&self) -> P;
+
+    /// Returns whether `candidate` is the record this body created.
+    fn matches(&self, candidate: &T) -> bool;
+}
+
+/// Creates resources at most once, even when a `POST` fails ambiguously.
+///
+/// Implemented for every service that can post, find and get a resource whose create body
+/// implements [`Reconcile`].
+///
+/// ### Example:
+/// ```rust,ignore
+/// let store = IdempotencyStore::new();
+/// let service = PatientService::new(&client);
+/// let patient = service.post_idempotent(&new_patient, &store).await?;
+/// ```
+#[async_trait]
+pub trait IdempotentPostService<'a, T, C, P> {
+    /// Posts `resource` with a key derived from its content. See [`IdempotentPostService::post_idempotent_with`].
+    async fn post_idempotent(&self, resource: &C, store: &IdempotencyStore) -> Result<T>;
+
+    /// Posts `resource` unless `key` already created a record.
+    ///
+    /// * If `store` remembers a record for `key`, that record is fetched and returned.
+    /// * Otherwise the resource is posted with the key in an `Idempotency-Key` header.
+    /// * If the post fails ambiguously, see `client::Error::is_ambiguous`, the records found
+    ///   by the body's reconcile query, on every page, are searched for the ones it matches.
+    ///   A single match is returned and no match posts the resource once more, but several
+    ///   matches return the ambiguous error, since any of them may be the record.
+    ///
+    /// Whichever record is returned is remembered for `key`.
+    async fn post_idempotent_with(
+        &self,
+        resource: &C,
+        key: IdempotencyKey,
+        store: &IdempotencyStore,
+        options: &RequestOptions,
+    ) -> Result<T>;
+}
+
+#[async_trait]
+impl<'a, S, T, C, P> IdempotentPostService<'a, T, C, P> for S
+where
+    S: PostService<'a, T, C> + FindService<'a, T, P> + GetService<'a, T> + Sync,
+    <S as GetService<'a, T>>::Id: FromStr,
+    T: Identifiable + DeserializeOwned + Send + Sync + 'static,
+    C: Reconcile<T, P> + Serialize + Send + Sync,
+    P: Params + Serialize + Send + Sync,
+{
+    async fn post_idempotent(&self, resource: &C, store: &IdempotencyStore) -> Result<T> {
+        let key = IdempotencyKey::derive::<T, C>(resource)?;
+        self.post_idempotent_with(resource, key, store, &RequestOptions::default())
+            .await
+    }
+
+    async fn post_idempotent_with(
+        &self,
+        resource: &C,
+        key: IdempotencyKey,
+        store: &IdempotencyStore,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        if let Some(id) = store.get(&key) {
+            let id = id.parse().map_err(|_| {
+                Error::InvalidInput(format!("idempotency key {key} maps to invalid id {id}"))
+            })?;
+            return self.get_with(id, options).await;
+        }
+
+        let value = HeaderValue::from_str(key.as_str()).map_err(client::Error::from)?;
+        let options = options.clone().header(IDEMPOTENCY_KEY_HEADER, value);
+
+        let created = match self.post_with(resource, &options).await {
+            Err(Error::ClientError(error)) if error.is_ambiguous() => {
+                tracing::warn!(
+                    error = error.kind(),
+                    "create failed ambiguously, reconciling"
+                );
+                // Two matches are enough to know the record cannot be told apart
+                let existing: Vec<T> = match self
+                    .find_stream_with(resource.reconcile_query(), &options)
+                    .try_filter(|candidate| future::ready(resource.matches(candidate)))
+                    .take(2)
+                    .try_collect()
+                    .await
+                {
+                    Ok(existing) => existing,
+                    Err(_) => return Err(Error::ClientError(error)),
+                };
+                let mut existing = existing.into_iter();
+                match (existing.next(), existing.next()) {
+                    (Some(existing), None) => existing,
+                    (None, _) => self.post_with(resource, &options).await?,
+                    (Some(_), Some(_)) => return Err(Error::ClientError(error)),
+                }
+            }
+            result => result?,
+        };
+
+        store.insert(key, created.id());
+        Ok(created)
+    }
+}
+
+impl Reconcile<Patient, PatientQueryParams> for PatientForCreate {
+    fn reconcile_query(&self) -> PatientQueryParams {
+        PatientQueryParams {
+            first_name: Some(self.first_name.clone()),
+            last_name: Some(self.last_name.clone()),
+            dob: Some(self.dob.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn matches(&self, candidate: &Patient) -> bool {
+        candidate.first_name == self.first_name
+            && candidate.last_name == self.last_name
+            && candidate.dob == self.dob
+            && candidate.sex == self.sex
+            && candidate.primary_physician == self.primary_physician
+            && candidate.caregiver_practice == self.caregiver_practice
+    }
+}
+
+impl Reconcile<LabOrder, LabOrderQueryParams> for LabOrderForCreate {
+    fn reconcile_query(&self) -> LabOrderQueryParams {
+        LabOrderQueryParams {
+            patient: Some(self.patient),
+            practice: Some(self.practice),
+            ..Default::default()
+        }
+    }
+
+    fn matches(&self, candidate: &LabOrder) -> bool {
+        candidate.patient == self.patient
+            && candidate.practice == self.practice
+            && candidate.ordering_physician == self.ordering_physician
+            && self.vendor.is_none_or(|vendor| candidate.vendor == vendor)
+            && self
+                .test_date
+                .is_none_or(|date| candidate.test_date == Some(date))
+            && self
+                .document_date
+                .is_none_or(|date| candidate.document_date == Some(date))
+            && self
+                .chart_date
+                .is_none_or(|date| candidate.chart_date == Some(date))
+            && self
+                .ccs
+                .as_ref()
+                .is_none_or(|ccs| same_ids(ccs, &candidate.ccs))
+            && same_ids(
+                self.content.as_ref().map_or(&[], |content| &content.tests),
+                &candidate
+                    .content
+                    .tests
+                    .iter()
+                    .map(|test| test.id)
+                    .collect::<Vec<_>>(),
+            )
+            && self.content.as_ref().is_none_or(|content| {
+                content.patient_instructions == candidate.content.patient_instructions
+                    && content.test_center_notes == candidate.content.test_center_notes
+                    && content.standing_order_frequency
+                        == candidate.content.standing_order_frequency
+                    && content.standing_order_end_date == candidate.content.standing_order_end_date
+                    && content
+                        .collection_datetime
+                        .is_none_or(|time| candidate.content.collection_datetime == Some(time))
+            })
+    }
+}
+
+/// Returns whether `left` and `right` hold the same ids, in any order.
+fn same_ids(left: &[i64], right: &[i64]) -> bool {
+    let (mut left, mut right) = (left.to_vec(), right.to_vec());
+    left.sort_unstable();
+    right.sort_unstable();
+    left == right
+}
+
+/// A stable 64-bit FNV-1a hash, so derived keys stay the same across builds.
+fn fnv1a(content: &str) -> u64 {
+    content.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
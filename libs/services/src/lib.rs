@@ -1,7 +1,15 @@
 pub mod base_service;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod bulk;
+pub mod cache;
+pub mod chart;
+pub mod idempotency;
+pub mod loader;
 pub mod macros;
 pub mod prelude;
 pub mod resource_service;
+pub mod sync;
 
 pub mod orders;
 pub mod patient_profile;
@@ -0,0 +1,266 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use client::Params;
+use futures::{future, TryStreamExt};
+use models::patient_profile::{
+    Allergy, AllergyDocumentation, DrugIntolerance, FamilyHistory, History, Immunization,
+    PatientPhoto, PatientProfileQueryParams, Problem, ProblemQueryParams,
+};
+use models::resource::{Identifiable, Resource};
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::sync::{watch, OnceCell};
+
+use crate::error::Result;
+use crate::resource_service::{FindService, GetService};
+
+/// A resource that belongs to a single patient and can be found by patient.
+pub trait PatientScoped {
+    /// The query parameters used to find the resource.
+    type QueryParams: Params + Serialize + Send + Sync;
+
+    /// Returns the id of the patient the resource belongs to.
+    fn patient(&self) -> i64;
+
+    /// Returns the query parameters for every record of `patients`.
+    fn for_patients(patients: Vec<i64>) -> Self::QueryParams;
+}
+
+macro_rules! impl_patient_scoped {
+    ($($resource:ty => $params:ident),* $(,)?) => {
+        $(
+            impl PatientScoped for $resource {
+                type QueryParams = $params;
+
+                fn patient(&self) -> i64 {
+                    self.patient
+                }
+
+                fn for_patients(patients: Vec<i64>) -> $params {
+                    let mut params = $params::default();
+                    params.patients = patients;
+                    params
+                }
+            }
+        )*
+    };
+}
+
+impl_patient_scoped!(
+    Allergy => PatientProfileQueryParams,
+    AllergyDocumentation => PatientProfileQueryParams,
+    DrugIntolerance => PatientProfileQueryParams,
+    FamilyHistory => PatientProfileQueryParams,
+    History => PatientProfileQueryParams,
+    Immunization => PatientProfileQueryParams,
+    PatientPhoto => PatientProfileQueryParams,
+    Problem => ProblemQueryParams,
+);
+
+/// Loads resources by id or by patient, sending each distinct request at most once.
+///
+/// A loader is meant to live as long as one unit of work, such as rendering a page, and
+/// remembers everything it loads for that long:
+///
+/// * Concurrent [`Loader::load`] calls for the same id share one `get`, and later calls are
+///   answered from memory. Failed gets are not remembered.
+/// * [`Loader::load_for_patient`] calls made together, e.g. joined with
+///   `futures::future::join_all`, are combined into one `find` for all of their patients.
+///   The records found are remembered by id as well.
+///
+/// ### Example:
+/// ```rust,ignore
+/// let loader = Loader::new(PatientService::new(&client));
+/// let patients = loader
+///     .load_many(orders.iter().map(|order| order.patient))
+///     .await;
+/// ```
+pub struct Loader<T, S> {
+    service: S,
+    entries: Mutex<HashMap<String, Arc<OnceCell<T>>>>,
+    patients: Mutex<HashMap<i64, Arc<OnceCell<Vec<T>>>>>,
+    batch: Mutex<Option<PendingBatch>>,
+    _resource: PhantomData<fn() -> T>,
+}
+
+/// The patients waiting for the next batched `find`.
+struct PendingBatch {
+    patients: Vec<i64>,
+    done: watch::Receiver<()>,
+}
+
+impl<T, S> Loader<T, S> {
+    /// Creates a loader that sends its requests through `service`.
+    pub fn new(service: S) -> Self {
+        Self {
+            service,
+            entries: Mutex::new(HashMap::new()),
+            patients: Mutex::new(HashMap::new()),
+            batch: Mutex::new(None),
+            _resource: PhantomData,
+        }
+    }
+
+    /// Returns the service the loader sends its requests through.
+    pub fn service(&self) -> &S {
+        &self.service
+    }
+
+    /// Forgets everything loaded so far.
+    pub fn clear(&self) {
+        lock(&self.entries).clear();
+        lock(&self.patients).clear();
+    }
+
+    fn entry(&self, id: String) -> Arc<OnceCell<T>> {
+        lock(&self.entries).entry(id).or_default().clone()
+    }
+
+    fn patient_entry(&self, patient: i64) -> Arc<OnceCell<Vec<T>>> {
+        lock(&self.patients).entry(patient).or_default().clone()
+    }
+}
+
+impl<T: Clone, S> Loader<T, S> {
+    /// Remembers `resource` for `id`, so loading `id` needs no request.
+    pub fn prime(&self, id: impl ToString, resource: T) {
+        // A resource that is already loaded or being loaded is kept
+        let _ = self.entry(id.to_string()).set(resource);
+    }
+}
+
+impl<'a, T, S> Loader<T, S>
+where
+    S: GetService<'a, T>,
+    <S as GetService<'a, T>>::Id: Clone,
+    T: Resource + DeserializeOwned + Clone + Send + Sync,
+{
+    /// Loads the resource with `id`, sharing the `get` with any concurrent load of the same id.
+    pub async fn load(&self, id: <S as GetService<'a, T>>::Id) -> Result<T> {
+        let entry = self.entry(id.to_string());
+        entry
+            .get_or_try_init(|| self.service.get(id))
+            .await
+            .cloned()
+    }
+
+    /// Loads the resource with each of `ids` concurrently, in the order of `ids`.
+    pub async fn load_many(
+        &self,
+        ids: impl IntoIterator<Item = <S as GetService<'a, T>>::Id>,
+    ) -> Vec<Result<T>> {
+        future::join_all(ids.into_iter().map(|id| self.load(id))).await
+    }
+}
+
+impl<'a, T, S> Loader<T, S>
+where
+    S: FindService<'a, T, T::QueryParams>,
+    T: Identifiable + PatientScoped + DeserializeOwned + Clone + Send + Sync + 'static,
+{
+    /// Loads every resource of `patient`, following pagination.
+    ///
+    /// The patients of all calls made before the first of them resumes are found with a
+    /// single `find`. If that `find` fails, the call that sent it returns its error and the
+    /// others retry with a `find` of their own.
+    pub async fn load_for_patient(&self, patient: i64) -> Result<Vec<T>> {
+        let entry = self.patient_entry(patient);
+        if let Some(resources) = entry.get() {
+            return Ok(resources.clone());
+        }
+
+        let waiting = {
+            let mut batch = lock(&self.batch);
+            match batch.as_mut() {
+                Some(pending) if pending.done.has_changed().is_ok() => {
+                    if !pending.patients.contains(&patient) {
+                        pending.patients.push(patient);
+                    }
+                    Some(pending.done.clone())
+                }
+                // No batch is pending, or its leader was dropped before sending it
+                _ => None,
+            }
+        };
+
+        match waiting {
+            Some(mut done) => {
+                // An error means the leader was dropped, which leaves the entry empty
+                let _ = done.changed().await;
+            }
+            None => self.send_batch(patient).await?,
+        }
+
+        entry
+            .get_or_try_init(|| async {
+                let mut found = self.find_patients(vec![patient]).await?;
+                Ok(found.remove(&patient).unwrap_or_default())
+            })
+            .await
+            .cloned()
+    }
+
+    /// Leads a new batch: lets the other calls made together join it, then finds all of its
+    /// patients and fills their entries.
+    async fn send_batch(&self, patient: i64) -> Result<()> {
+        let (done, receiver) = watch::channel(());
+        *lock(&self.batch) = Some(PendingBatch {
+            patients: vec![patient],
+            done: receiver,
+        });
+
+        tokio::task::yield_now().await;
+
+        let patients = lock(&self.batch)
+            .take()
+            .map(|batch| batch.patients)
+            .unwrap_or_else(|| vec![patient]);
+        let result = self.find_patients(patients.clone()).await;
+        if let Ok(found) = &result {
+            for patient in patients {
+                let resources = found.get(&patient).cloned().unwrap_or_default();
+                let _ = self.patient_entry(patient).set(resources);
+            }
+        }
+
+        // Wakes the other calls of the batch, whether it succeeded or not
+        let _ = done.send(());
+        result.map(drop)
+    }
+
+    /// Finds the resources of `patients`, grouped by patient, and remembers each by id.
+    async fn find_patients(&self, patients: Vec<i64>) -> Result<HashMap<i64, Vec<T>>> {
+        let resources = self
+            .service
+            .find_stream(T::for_patients(patients))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut found = HashMap::<i64, Vec<T>>::new();
+        for resource in resources {
+            let _ = self.entry(resource.id().to_string()).set(resource.clone());
+            found.entry(resource.patient()).or_default().push(resource);
+        }
+        Ok(found)
+    }
+}
+
+impl<T, S: fmt::Debug> fmt::Debug for Loader<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Loader")
+            .field("service", &self.service)
+            .field("entries", &lock(&self.entries).len())
+            .field("patients", &lock(&self.patients).len())
+            .finish()
+    }
+}
+
+fn lock<V>(mutex: &Mutex<V>) -> MutexGuard<'_, V> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -51,6 +51,17 @@ concat!(
                 async fn get(&self, id: Self::Id) -> Result<$resource> {
                     self.base.get(id).await
                 }
+
+#[doc = "Fetches a single instance of the resource by ID, applying `options` to this call only."]
+#[doc = ""]
+#[doc = "### Example:"]
+#[doc = "```rust"]
+#[doc = "let options = RequestOptions::new().timeout(Duration::from_secs(5));"]
+#[doc = "let resource = service.get_with(id, &options).await?;"]
+#[doc = "```"]
+                async fn get_with(&self, id: Self::Id, options: &RequestOptions) -> Result<$resource> {
+                    self.base.get_with(id, options).await
+                }
             }
         }
     };
@@ -109,6 +120,17 @@ concat!(
                 async fn post(&self, resource: &$resource_for_create) -> Result<$resource> {
                     self.base.post(resource).await
                 }
+
+#[doc = "Creates a new instance of the resource, applying `options` to this call only."]
+#[doc = ""]
+#[doc = "### Example:"]
+#[doc = "```rust"]
+#[doc = "let options = RequestOptions::new().timeout(Duration::from_secs(5));"]
+#[doc = "let created_resource = service.post_with(&new_resource, &options).await?;"]
+#[doc = "```"]
+                async fn post_with(&self, resource: &$resource_for_create, options: &RequestOptions) -> Result<$resource> {
+                    self.base.post_with(resource, options).await
+                }
             }
         }
     };
@@ -167,6 +189,17 @@ concat!(
                 async fn delete(&self, id: Self::Id) -> Result<()> {
                     self.base.delete(id).await
                 }
+
+#[doc = "Deletes an existing resource by ID, applying `options` to this call only."]
+#[doc = ""]
+#[doc = "### Example:"]
+#[doc = "```rust"]
+#[doc = "let options = RequestOptions::new().timeout(Duration::from_secs(5));"]
+#[doc = "service.delete_with(id, &options).await?;"]
+#[doc = "```"]
+                async fn delete_with(&self, id: Self::Id, options: &RequestOptions) -> Result<()> {
+                    self.base.delete_with(id, options).await
+                }
             }
         }
     };
@@ -237,6 +270,22 @@ concat!(
                     ) -> Result<$resource> {
                         self.base.patch(id, resource).await
                     }
+
+#[doc = "Updates an existing resource by ID, applying `options` to this call only."]
+#[doc = ""]
+#[doc = "### Example:"]
+#[doc = "```rust"]
+#[doc = "let options = RequestOptions::new().timeout(Duration::from_secs(5));"]
+#[doc = "let updated_resource = service.patch_with(id, &update_data, &options).await?;"]
+#[doc = "```"]
+                    async fn patch_with(
+                        &self,
+                        id: Self::Id,
+                        resource: &$resource_for_update,
+                        options: &RequestOptions,
+                    ) -> Result<$resource> {
+                        self.base.patch_with(id, resource, options).await
+                    }
                 }
         }
     };
@@ -293,6 +342,17 @@ concat!(
                 async fn put(&self, resource: &$resource_for_create) -> Result<$resource> {
                     self.base.put(resource).await
                 }
+
+#[doc = "Replaces or creates a resource, applying `options` to this call only."]
+#[doc = ""]
+#[doc = "### Example:"]
+#[doc = "```rust"]
+#[doc = "let options = RequestOptions::new().timeout(Duration::from_secs(5));"]
+#[doc = "let resource = service.put_with(&new_resource, &options).await?;"]
+#[doc = "```"]
+                async fn put_with(&self, resource: &$resource_for_create, options: &RequestOptions) -> Result<$resource> {
+                    self.base.put_with(resource, options).await
+                }
             }
         }
     };
@@ -336,6 +396,23 @@ concat!(
     "    }\n",
     "}\n",
     "println!(\"Total items fetched: {}\", all_results.len());\n",
+    "```\n\n",
+    "### Example: Streaming All Resources\n",
+    "```rust\n",
+    "let service = ", stringify!($service_name), "::new(&client);\n",
+    "let params = ", stringify!($resource_query_params), " { /* fields */ };\n",
+    "let mut resources = service.find_stream(params).prefetch(true).max_items(10_000);\n\n",
+    "while let Some(resource) = resources.try_next().await? {\n",
+    "    println!(\"Resource: {:?}\", resource);\n",
+    "}\n",
+    "```\n\n",
+    "### Example: Fetching Pages in Parallel\n",
+    "```rust\n",
+    "let service = ", stringify!($service_name), "::new(&client);\n",
+    "let params = ", stringify!($resource_query_params), " { /* fields */ };\n",
+    "let options = ParallelPages { page_size: 100, concurrency: 4 };\n",
+    "let resources = service.find_all_parallel(params, options).await?;\n",
+    "println!(\"Total items fetched: {}\", resources.len());\n",
     "```\n"
 ),
             #[async_trait::async_trait]
@@ -386,6 +463,99 @@ concat!(
                 async fn find(&self, params: $resource_query_params) -> Result<PaginatedResponse<$resource>> {
                     self.base.find(params).await
                 }
+
+#[doc = "Finds resources matching the provided query parameters, applying `options` to this call only."]
+#[doc = ""]
+#[doc = "### Example:"]
+#[doc = "```rust"]
+#[doc = "let options = RequestOptions::new().timeout(Duration::from_secs(5));"]
+#[doc = "let page = service.find_with(params, &options).await?;"]
+#[doc = "```"]
+                async fn find_with(&self, params: $resource_query_params, options: &RequestOptions) -> Result<PaginatedResponse<$resource>> {
+                    self.base.find_with(params, options).await
+                }
+
+#[doc = "Streams every resource matching the provided query parameters."]
+#[doc = ""]
+#[doc = "Pages are fetched lazily as the stream is consumed, and resources already yielded"]
+#[doc = "on an earlier page are skipped when offset pagination shifts under concurrent writes."]
+#[doc = ""]
+#[doc = "### Parameters:"]
+#[doc = "- `params`: The query parameters used for filtering, of type `"]
+#[doc = stringify!($resource_query_params)]
+#[doc = "`."]
+#[doc = ""]
+#[doc = "### Returns:"]
+#[doc = "- A [`PageStream`] yielding each matching resource, or an error if fetching a page fails."]
+#[doc = ""]
+#[doc = "### Example:"]
+#[doc = "```rust"]
+#[doc = "let service = "]
+#[doc = stringify!($service_name)]
+#[doc = "::new(&client);"]
+#[doc = "let params = "]
+#[doc = stringify!($resource_query_params)]
+#[doc = " { /* fields */ };"]
+#[doc = "let mut resources = service.find_stream(params).prefetch(true).max_items(10_000);"]
+#[doc = "while let Some(resource) = resources.try_next().await? {"]
+#[doc = "    println!(\"Resource: {:?}\", resource);"]
+#[doc = "}"]
+#[doc = "```"]
+                fn find_stream(&self, params: $resource_query_params) -> PageStream<'a, $resource, Error> {
+                    self.base.find_stream(params)
+                }
+
+#[doc = "Streams every resource matching the provided query parameters, applying `options` to every page request."]
+#[doc = ""]
+#[doc = "### Example:"]
+#[doc = "```rust"]
+#[doc = "let options = RequestOptions::new().timeout(Duration::from_secs(5));"]
+#[doc = "let mut resources = service.find_stream_with(params, &options);"]
+#[doc = "```"]
+                fn find_stream_with(&self, params: $resource_query_params, options: &RequestOptions) -> PageStream<'a, $resource, Error> {
+                    self.base.find_stream_with(params, options)
+                }
+
+#[doc = "Fetches every resource matching the provided query parameters, several pages at a time."]
+#[doc = ""]
+#[doc = "The first page reveals the total count, after which the remaining `limit`/`offset` windows"]
+#[doc = "are fetched concurrently and reassembled in order."]
+#[doc = ""]
+#[doc = "### Parameters:"]
+#[doc = "- `params`: The query parameters used for filtering, of type `"]
+#[doc = stringify!($resource_query_params)]
+#[doc = "`."]
+#[doc = "- `options`: The page size and the number of pages fetched at the same time."]
+#[doc = ""]
+#[doc = "### Returns:"]
+#[doc = "- [`Result`] containing every matching resource, or an error if fetching any page fails."]
+#[doc = ""]
+#[doc = "### Example:"]
+#[doc = "```rust"]
+#[doc = "let service = "]
+#[doc = stringify!($service_name)]
+#[doc = "::new(&client);"]
+#[doc = "let params = "]
+#[doc = stringify!($resource_query_params)]
+#[doc = " { /* fields */ };"]
+#[doc = "let options = ParallelPages { page_size: 100, concurrency: 4 };"]
+#[doc = "let resources = service.find_all_parallel(params, options).await?;"]
+#[doc = "println!(\"Total items fetched: {}\", resources.len());"]
+#[doc = "```"]
+                async fn find_all_parallel(&self, params: $resource_query_params, options: ParallelPages) -> Result<Vec<$resource>> {
+                    self.base.find_all_parallel(params, options).await
+                }
+
+#[doc = "Fetches every resource matching the provided query parameters several pages at a time, applying `request_options` to every page request."]
+#[doc = ""]
+#[doc = "### Example:"]
+#[doc = "```rust"]
+#[doc = "let options = RequestOptions::new().timeout(Duration::from_secs(5));"]
+#[doc = "let resources = service.find_all_parallel_with(params, ParallelPages::default(), &options).await?;"]
+#[doc = "```"]
+                async fn find_all_parallel_with(&self, params: $resource_query_params, options: ParallelPages, request_options: &RequestOptions) -> Result<Vec<$resource>> {
+                    self.base.find_all_parallel_with(params, options, request_options).await
+                }
             }
         }
     };
@@ -415,8 +585,15 @@ macro_rules! impl_service {
                 "```rust\n",
                 "let client = Client::new();\n",
                 "let service = ", stringify!($service_name), "::new(&client);\n",
+                "```\n\n",
+                "#### Example: Sharing `", stringify!($service_name), "` between tasks\n",
+                "```rust\n",
+                "let client = Arc::new(Client::new().await?);\n",
+                "let service = ", stringify!($service_name), "::shared(client);\n",
+                "tokio::spawn(async move { /* use service */ });\n",
                 "```\n"
             ),
+            #[derive(Clone, Debug)]
             pub struct $service_name<'a> {
                 base: BaseService<'a, $resource, $resource_for_create, $resource_for_update>,
             }
@@ -445,6 +622,26 @@ macro_rules! impl_service {
 
         }
 
+        impl $service_name<'static> {
+            doc_comment! {
+                concat!(
+                    "Creates a new instance of `", stringify!($service_name), "` that shares ownership of `client`.\n\n",
+                    "The service is `'static`, `Send` and `Sync`, and cheap to clone, so it can be stored in ",
+                    "application state or moved into spawned tasks.\n\n",
+                    "### Example\n",
+                    "```rust\n",
+                    "let client = Arc::new(Client::new().await?);\n",
+                    "let service = ", stringify!($service_name), "::shared(client);\n",
+                    "```\n"
+                ),
+                pub fn shared(client: std::sync::Arc<Client>) -> Self {
+                    Self {
+                        base: BaseService::shared(client),
+                    }
+                }
+            }
+        }
+
         $(
             $crate::impl_service_trait!(
                 $service_name,
@@ -456,5 +653,222 @@ macro_rules! impl_service {
                 $trait_name
             );
         )*
+
+        $crate::impl_blocking_service!(
+            $service_name,
+            $resource,
+            $resource_for_create,
+            $resource_for_update,
+            $resource_query_params,
+            $id_type,
+            [$($trait_name),*]
+        );
+    };
+}
+
+/// Adds the synchronous methods of `services::blocking::Service` for a service generated by
+/// `impl_service!`, one inherent `impl` block per trait.
+#[cfg(feature = "blocking")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_blocking_service {
+    (
+        $service_name:ident,
+        $resource:ty,
+        $resource_for_create:ty,
+        $resource_for_update:ty,
+        $resource_query_params:ty,
+        $id_type:ty,
+        [$($trait_name:ident),*]
+    ) => {
+        $(
+            $crate::impl_blocking_service!(
+                @trait $trait_name,
+                $service_name,
+                $resource,
+                $resource_for_create,
+                $resource_for_update,
+                $resource_query_params,
+                $id_type
+            );
+        )*
+    };
+
+    (@trait GetService, $service_name:ident, $resource:ty, $create:ty, $update:ty, $params:ty, $id_type:ty) => {
+        impl $crate::blocking::Service<$service_name<'static>> {
+            /// Fetches a single instance of the resource by ID, see [`GetService::get`].
+            pub fn get(&self, id: $id_type) -> Result<$resource> {
+                self.block_on(|client| async move { $service_name::new(client).get(id).await })
+            }
+
+            /// Like `get`, applying `options` to this call only.
+            pub fn get_with(&self, id: $id_type, options: &RequestOptions) -> Result<$resource> {
+                self.block_on(|client| async move {
+                    $service_name::new(client).get_with(id, options).await
+                })
+            }
+        }
+    };
+
+    (@trait PostService, $service_name:ident, $resource:ty, $create:ty, $update:ty, $params:ty, $id_type:ty) => {
+        impl $crate::blocking::Service<$service_name<'static>> {
+            /// Creates a new resource, see [`PostService::post`].
+            pub fn post(&self, resource_for_create: &$create) -> Result<$resource> {
+                self.block_on(|client| async move {
+                    $service_name::new(client).post(resource_for_create).await
+                })
+            }
+
+            /// Like `post`, applying `options` to this call only.
+            pub fn post_with(
+                &self,
+                resource_for_create: &$create,
+                options: &RequestOptions,
+            ) -> Result<$resource> {
+                self.block_on(|client| async move {
+                    $service_name::new(client)
+                        .post_with(resource_for_create, options)
+                        .await
+                })
+            }
+
+            /// Creates each of `resources` concurrently, see [`BulkPostService::post_many`].
+            pub fn post_many(
+                &self,
+                resources: &[$create],
+                options: BulkOptions,
+            ) -> BulkReport<$resource> {
+                let client = self.client();
+                client.block_on($service_name::new(client.as_async()).post_many(resources, options))
+            }
+        }
+    };
+
+    (@trait PatchService, $service_name:ident, $resource:ty, $create:ty, $update:ty, $params:ty, $id_type:ty) => {
+        impl $crate::blocking::Service<$service_name<'static>> {
+            /// Partially updates the resource with the given ID, see [`PatchService::patch`].
+            pub fn patch(&self, id: $id_type, params: &$update) -> Result<$resource> {
+                self.block_on(|client| async move {
+                    $service_name::new(client).patch(id, params).await
+                })
+            }
+
+            /// Like `patch`, applying `options` to this call only.
+            pub fn patch_with(
+                &self,
+                id: $id_type,
+                params: &$update,
+                options: &RequestOptions,
+            ) -> Result<$resource> {
+                self.block_on(|client| async move {
+                    $service_name::new(client)
+                        .patch_with(id, params, options)
+                        .await
+                })
+            }
+
+            /// Patches each resource concurrently, see [`BulkPatchService::patch_many`].
+            pub fn patch_many(
+                &self,
+                patches: &[($id_type, $update)],
+                options: BulkOptions,
+            ) -> BulkReport<$resource> {
+                let client = self.client();
+                client.block_on($service_name::new(client.as_async()).patch_many(patches, options))
+            }
+        }
+    };
+
+    (@trait PutService, $service_name:ident, $resource:ty, $create:ty, $update:ty, $params:ty, $id_type:ty) => {
+        impl $crate::blocking::Service<$service_name<'static>> {
+            /// Replaces a resource, see [`PutService::put`].
+            pub fn put(&self, resource_for_create: &$create) -> Result<$resource> {
+                self.block_on(|client| async move {
+                    $service_name::new(client).put(resource_for_create).await
+                })
+            }
+
+            /// Like `put`, applying `options` to this call only.
+            pub fn put_with(
+                &self,
+                resource_for_create: &$create,
+                options: &RequestOptions,
+            ) -> Result<$resource> {
+                self.block_on(|client| async move {
+                    $service_name::new(client)
+                        .put_with(resource_for_create, options)
+                        .await
+                })
+            }
+        }
+    };
+
+    (@trait DeleteService, $service_name:ident, $resource:ty, $create:ty, $update:ty, $params:ty, $id_type:ty) => {
+        impl $crate::blocking::Service<$service_name<'static>> {
+            /// Deletes the resource with the given ID, see [`DeleteService::delete`].
+            pub fn delete(&self, id: $id_type) -> Result<()> {
+                self.block_on(|client| async move { $service_name::new(client).delete(id).await })
+            }
+
+            /// Like `delete`, applying `options` to this call only.
+            pub fn delete_with(&self, id: $id_type, options: &RequestOptions) -> Result<()> {
+                self.block_on(|client| async move {
+                    $service_name::new(client).delete_with(id, options).await
+                })
+            }
+
+            /// Deletes each of `ids` concurrently, see [`BulkDeleteService::delete_many`].
+            pub fn delete_many(&self, ids: &[$id_type], options: BulkOptions) -> BulkReport<()> {
+                let client = self.client();
+                client.block_on($service_name::new(client.as_async()).delete_many(ids, options))
+            }
+        }
     };
+
+    (@trait FindService, $service_name:ident, $resource:ty, $create:ty, $update:ty, $params:ty, $id_type:ty) => {
+        impl $crate::blocking::Service<$service_name<'static>> {
+            /// Finds a page of resources matching `params`, see [`FindService::find`].
+            pub fn find(&self, params: $params) -> Result<PaginatedResponse<$resource>> {
+                self.block_on(|client| async move { $service_name::new(client).find(params).await })
+            }
+
+            /// Like `find`, applying `options` to this call only.
+            pub fn find_with(
+                &self,
+                params: $params,
+                options: &RequestOptions,
+            ) -> Result<PaginatedResponse<$resource>> {
+                self.block_on(|client| async move {
+                    $service_name::new(client).find_with(params, options).await
+                })
+            }
+
+            /// Iterates over every resource matching `params`, see [`FindService::find_stream`].
+            pub fn find_iter(&self, params: $params) -> client::blocking::Iter<'_, $resource, Error> {
+                let stream = $service_name::new(self.client().as_async()).find_stream(params);
+                self.client().iter(stream)
+            }
+
+            /// Fetches every resource matching `params`, see [`FindService::find_all_parallel`].
+            pub fn find_all_parallel(
+                &self,
+                params: $params,
+                options: ParallelPages,
+            ) -> Result<Vec<$resource>> {
+                self.block_on(|client| async move {
+                    $service_name::new(client)
+                        .find_all_parallel(params, options)
+                        .await
+                })
+            }
+        }
+    };
+}
+
+/// Without the `blocking` feature, `impl_service!` generates no synchronous methods.
+#[cfg(not(feature = "blocking"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_blocking_service {
+    ($($tokens:tt)*) => {};
 }
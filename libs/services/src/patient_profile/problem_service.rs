@@ -1,14 +1,12 @@
 use crate::prelude::*;
-use models::patient_profile::{
-    PatientProfileQueryParams, Problem, ProblemForCreate, ProblemForUpdate,
-};
+use models::patient_profile::{Problem, ProblemForCreate, ProblemForUpdate, ProblemQueryParams};
 
 impl_service!(
     ServiceName: ProblemService,
     Resource: Problem,
     ForCreate: ProblemForCreate,
     ForUpdate: ProblemForUpdate,
-    QueryParams: PatientProfileQueryParams,
+    QueryParams: ProblemQueryParams,
     IdType: i64,
     Traits: [
         GetService,
@@ -1,5 +1,21 @@
 pub use crate::base_service::BaseService;
+pub use crate::bulk::{
+    BulkDeleteService, BulkOptions, BulkPatchService, BulkPostService, BulkReport,
+};
+pub use crate::cache::CacheResources;
+pub use crate::chart::{ChartSection, ChartService, PatientChart, Section, SectionError};
 pub use crate::error::*;
+pub use crate::idempotency::{IdempotencyKey, IdempotencyStore, IdempotentPostService, Reconcile};
 pub use crate::impl_service;
+pub use crate::loader::{Loader, PatientScoped};
 pub use crate::resource_service::*;
-pub use client::{Client, PaginatedResponse};
+#[cfg(feature = "sqlite")]
+pub use crate::sync::SqliteCheckpointStore;
+pub use crate::sync::{
+    ChangeSet, CheckpointStore, FileCheckpointStore, MemoryCheckpointStore, SyncEngine, SyncReport,
+    Syncable,
+};
+pub use client::{
+    CancellationToken, Client, PageStream, Paged, PaginatedResponse, ParallelPages, RequestOptions,
+    ResponseCache,
+};
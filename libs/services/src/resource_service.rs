@@ -1,6 +1,6 @@
 use crate::error::Error;
 use async_trait::async_trait;
-use client::{Client, PaginatedResponse, Params};
+use client::{Client, PageStream, PaginatedResponse, ParallelPages, Params, RequestOptions};
 use models::resource::Resource;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
@@ -23,7 +23,19 @@ where
     T: Resource + DeserializeOwned + Send + Sync,
 {
     type Id: ToString + Send + Sync;
+
     async fn get(&self, id: Self::Id) -> Result<T, Error>;
+
+    /// Like `get`, applying `options` to this call only.
+    ///
+    /// The default ignores `options` and calls `get`.
+    async fn get_with(&self, id: Self::Id, _options: &RequestOptions) -> Result<T, Error>
+    where
+        'a: 'async_trait,
+        T: 'async_trait,
+    {
+        self.get(id).await
+    }
 }
 
 #[async_trait]
@@ -33,6 +45,22 @@ where
     C: Serialize + Send + Sync,
 {
     async fn post(&self, resource_for_create: &C) -> Result<T, Error>;
+
+    /// Like `post`, applying `options` to this call only.
+    ///
+    /// The default ignores `options` and calls `post`.
+    async fn post_with(
+        &self,
+        resource_for_create: &C,
+        _options: &RequestOptions,
+    ) -> Result<T, Error>
+    where
+        'a: 'async_trait,
+        T: 'async_trait,
+        C: 'async_trait,
+    {
+        self.post(resource_for_create).await
+    }
 }
 
 #[async_trait]
@@ -42,7 +70,25 @@ where
     U: Serialize + Send + Sync,
 {
     type Id: ToString + Send + Sync;
+
     async fn patch(&self, id: Self::Id, params: &U) -> Result<T, Error>;
+
+    /// Like `patch`, applying `options` to this call only.
+    ///
+    /// The default ignores `options` and calls `patch`.
+    async fn patch_with(
+        &self,
+        id: Self::Id,
+        params: &U,
+        _options: &RequestOptions,
+    ) -> Result<T, Error>
+    where
+        'a: 'async_trait,
+        T: 'async_trait,
+        U: 'async_trait,
+    {
+        self.patch(id, params).await
+    }
 }
 
 #[async_trait]
@@ -52,13 +98,37 @@ where
     C: Serialize + Send + Sync,
 {
     type Id: ToString + Send + Sync;
+
     async fn put(&self, resource_for_create: &C) -> Result<T, Error>;
+
+    /// Like `put`, applying `options` to this call only.
+    ///
+    /// The default ignores `options` and calls `put`.
+    async fn put_with(&self, resource_for_create: &C, _options: &RequestOptions) -> Result<T, Error>
+    where
+        'a: 'async_trait,
+        T: 'async_trait,
+        C: 'async_trait,
+    {
+        self.put(resource_for_create).await
+    }
 }
 
 #[async_trait]
 pub trait DeleteService<'a> {
     type Id: ToString + Send + Sync;
+
     async fn delete(&self, id: Self::Id) -> Result<(), Error>;
+
+    /// Like `delete`, applying `options` to this call only.
+    ///
+    /// The default ignores `options` and calls `delete`.
+    async fn delete_with(&self, id: Self::Id, _options: &RequestOptions) -> Result<(), Error>
+    where
+        'a: 'async_trait,
+    {
+        self.delete(id).await
+    }
 }
 
 #[async_trait]
@@ -68,4 +138,50 @@ where
     P: Params + Serialize + Send + Sync,
 {
     async fn find(&self, params: P) -> Result<PaginatedResponse<T>, Error>;
+
+    /// Like `find`, applying `options` to this call only.
+    ///
+    /// The default ignores `options` and calls `find`.
+    async fn find_with(
+        &self,
+        params: P,
+        _options: &RequestOptions,
+    ) -> Result<PaginatedResponse<T>, Error>
+    where
+        'a: 'async_trait,
+        T: 'async_trait,
+        P: 'async_trait,
+    {
+        self.find(params).await
+    }
+
+    /// Streams every resource matching `params`, fetching pages lazily and skipping duplicate ids.
+    fn find_stream(&self, params: P) -> PageStream<'a, T, Error>;
+
+    /// Like `find_stream`, applying `options` to every page request.
+    ///
+    /// The default ignores `options` and calls `find_stream`.
+    fn find_stream_with(&self, params: P, _options: &RequestOptions) -> PageStream<'a, T, Error> {
+        self.find_stream(params)
+    }
+
+    /// Fetches every resource matching `params`, requesting several `limit`/`offset` windows at a time.
+    async fn find_all_parallel(&self, params: P, options: ParallelPages) -> Result<Vec<T>, Error>;
+
+    /// Like `find_all_parallel`, applying `request_options` to every page request.
+    ///
+    /// The default ignores `request_options` and calls `find_all_parallel`.
+    async fn find_all_parallel_with(
+        &self,
+        params: P,
+        options: ParallelPages,
+        _request_options: &RequestOptions,
+    ) -> Result<Vec<T>, Error>
+    where
+        'a: 'async_trait,
+        T: 'async_trait,
+        P: 'async_trait,
+    {
+        self.find_all_parallel(params, options).await
+    }
 }
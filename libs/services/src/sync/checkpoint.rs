@@ -0,0 +1,215 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::error::{Error, Result};
+
+/// Remembers the high-water mark of every resource a [`SyncEngine`](super::SyncEngine) syncs.
+///
+/// A checkpoint is the time the last successful sync of a resource started; the next sync
+/// fetches the records modified since then.
+pub trait CheckpointStore: Send + Sync {
+    /// Returns the checkpoint saved for `key`, if any.
+    fn load(&self, key: &str) -> Result<Option<OffsetDateTime>>;
+
+    /// Saves `checkpoint` for `key`, replacing the previous one.
+    fn save(&self, key: &str, checkpoint: OffsetDateTime) -> Result<()>;
+}
+
+impl<S: CheckpointStore + ?Sized> CheckpointStore for Arc<S> {
+    fn load(&self, key: &str) -> Result<Option<OffsetDateTime>> {
+        (**self).load(key)
+    }
+
+    fn save(&self, key: &str, checkpoint: OffsetDateTime) -> Result<()> {
+        (**self).save(key, checkpoint)
+    }
+}
+
+/// Keeps checkpoints in memory, e.g. for tests or processes that sync in a loop.
+///
+/// Clones share their checkpoints.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoints: Arc<Mutex<HashMap<String, OffsetDateTime>>>,
+}
+
+impl MemoryCheckpointStore {
+    /// Creates a store without checkpoints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn checkpoints(&self) -> MutexGuard<'_, HashMap<String, OffsetDateTime>> {
+        self.checkpoints
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    fn load(&self, key: &str) -> Result<Option<OffsetDateTime>> {
+        Ok(self.checkpoints().get(key).copied())
+    }
+
+    fn save(&self, key: &str, checkpoint: OffsetDateTime) -> Result<()> {
+        self.checkpoints().insert(key.to_string(), checkpoint);
+        Ok(())
+    }
+}
+
+/// Keeps checkpoints in a JSON file, mapping each key to an RFC 3339 timestamp.
+///
+/// The file is created on the first save and replaced atomically on every save.
+#[derive(Debug)]
+pub struct FileCheckpointStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileCheckpointStore {
+    /// Creates a store that keeps its checkpoints in the file at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Returns the path of the checkpoint file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read(&self) -> Result<BTreeMap<String, String>> {
+        match fs::read(&self.path) {
+            Ok(content) => serde_json::from_slice(&content)
+                .map_err(|error| checkpoint_error(format!("{}: {error}", self.path.display()))),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(error) => Err(checkpoint_error(format!(
+                "{}: {error}",
+                self.path.display()
+            ))),
+        }
+    }
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn load(&self, key: &str) -> Result<Option<OffsetDateTime>> {
+        let _guard = self
+            .lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.read()?.get(key).map(|value| parse(value)).transpose()
+    }
+
+    fn save(&self, key: &str, checkpoint: OffsetDateTime) -> Result<()> {
+        let _guard = self
+            .lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut checkpoints = self.read()?;
+        checkpoints.insert(key.to_string(), format(checkpoint)?);
+
+        let content = serde_json::to_vec_pretty(&checkpoints)
+            .map_err(|error| checkpoint_error(error.to_string()))?;
+        let temporary = self.path.with_extension("tmp");
+        fs::write(&temporary, content)
+            .and_then(|_| fs::rename(&temporary, &self.path))
+            .map_err(|error| checkpoint_error(format!("{}: {error}", self.path.display())))
+    }
+}
+
+/// Keeps checkpoints in a table of a SQLite database.
+///
+/// Enabled by the `sqlite` feature. The `elation_sync_checkpoints` table is created when the
+/// store is opened.
+#[cfg(feature = "sqlite")]
+#[derive(Debug)]
+pub struct SqliteCheckpointStore {
+    connection: Mutex<rusqlite::Connection>,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteCheckpointStore {
+    /// Opens the database at `path`, creating it if needed.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_connection(rusqlite::Connection::open(path).map_err(sqlite_error)?)
+    }
+
+    /// Uses an open connection, e.g. to keep checkpoints next to the synced data.
+    pub fn from_connection(connection: rusqlite::Connection) -> Result<Self> {
+        connection
+            .execute(
+                "CREATE TABLE IF NOT EXISTS elation_sync_checkpoints (
+                    key TEXT PRIMARY KEY NOT NULL,
+                    checkpoint TEXT NOT NULL
+                )",
+                (),
+            )
+            .map_err(sqlite_error)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    fn connection(&self) -> MutexGuard<'_, rusqlite::Connection> {
+        self.connection
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl CheckpointStore for SqliteCheckpointStore {
+    fn load(&self, key: &str) -> Result<Option<OffsetDateTime>> {
+        use rusqlite::OptionalExtension;
+
+        let value = self
+            .connection()
+            .query_row(
+                "SELECT checkpoint FROM elation_sync_checkpoints WHERE key = ?1",
+                [key],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(sqlite_error)?;
+        value.as_deref().map(parse).transpose()
+    }
+
+    fn save(&self, key: &str, checkpoint: OffsetDateTime) -> Result<()> {
+        self.connection()
+            .execute(
+                "INSERT INTO elation_sync_checkpoints (key, checkpoint) VALUES (?1, ?2)
+                 ON CONFLICT (key) DO UPDATE SET checkpoint = excluded.checkpoint",
+                [key, &format(checkpoint)?],
+            )
+            .map_err(sqlite_error)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn sqlite_error(error: rusqlite::Error) -> Error {
+    checkpoint_error(error.to_string())
+}
+
+fn checkpoint_error(message: String) -> Error {
+    Error::Checkpoint(message)
+}
+
+fn format(checkpoint: OffsetDateTime) -> Result<String> {
+    checkpoint
+        .format(&Rfc3339)
+        .map_err(|error| checkpoint_error(error.to_string()))
+}
+
+fn parse(value: &str) -> Result<OffsetDateTime> {
+    OffsetDateTime::parse(value, &Rfc3339)
+        .map_err(|error| checkpoint_error(format!("invalid checkpoint {value}: {error}")))
+}
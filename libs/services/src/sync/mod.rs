@@ -0,0 +1,254 @@
+mod checkpoint;
+
+pub use checkpoint::*;
+
+use client::Params;
+use futures::TryStreamExt;
+use models::patient_profile::{Patient, PatientQueryParams, Problem, ProblemQueryParams};
+use models::resource::Resource;
+use serde::{de::DeserializeOwned, Serialize};
+use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+use tracing::{info, Instrument};
+
+use crate::error::{Error, Result};
+use crate::resource_service::FindService;
+
+/// A resource that can be found by the time it was last modified.
+pub trait Syncable: Resource + DeserializeOwned + Send + Sync + 'static {
+    /// The query parameters used to find the resource.
+    type QueryParams: Params + Serialize + Send + Sync;
+
+    /// Returns the query parameters for every record modified at or after `since`, or for
+    /// every record when `since` is `None`.
+    fn modified_since(since: Option<OffsetDateTime>) -> Self::QueryParams;
+
+    /// Returns when the record was created.
+    fn created_date(&self) -> Option<OffsetDateTime>;
+
+    /// Returns when the record was deleted, if it was.
+    fn deleted_date(&self) -> Option<OffsetDateTime>;
+
+    /// Returns the key the resource's checkpoint is saved under.
+    fn checkpoint_key() -> &'static str {
+        Self::endpoint().trim_matches('/')
+    }
+}
+
+impl Syncable for Patient {
+    type QueryParams = PatientQueryParams;
+
+    fn modified_since(since: Option<OffsetDateTime>) -> PatientQueryParams {
+        PatientQueryParams {
+            last_modified__gte: since.and_then(|since| since.format(&Rfc3339).ok()),
+            ..Default::default()
+        }
+    }
+
+    fn created_date(&self) -> Option<OffsetDateTime> {
+        self.created_date
+    }
+
+    fn deleted_date(&self) -> Option<OffsetDateTime> {
+        self.deleted_date
+    }
+}
+
+impl Syncable for Problem {
+    type QueryParams = ProblemQueryParams;
+
+    fn modified_since(since: Option<OffsetDateTime>) -> ProblemQueryParams {
+        ProblemQueryParams {
+            last_modified__gte: since.and_then(|since| since.format(&Rfc3339).ok()),
+            ..Default::default()
+        }
+    }
+
+    fn created_date(&self) -> Option<OffsetDateTime> {
+        self.created_date
+    }
+
+    fn deleted_date(&self) -> Option<OffsetDateTime> {
+        self.deleted_date
+    }
+}
+
+/// The records of one resource that changed since the previous sync.
+#[derive(Debug, Clone)]
+pub struct ChangeSet<T> {
+    /// Records created since the previous sync.
+    pub created: Vec<T>,
+    /// Records that existed before the previous sync and were modified since.
+    pub updated: Vec<T>,
+    /// Records deleted since the previous sync.
+    pub deleted: Vec<T>,
+}
+
+impl<T> ChangeSet<T> {
+    /// Returns the number of changed records.
+    pub fn len(&self) -> usize {
+        self.created.len() + self.updated.len() + self.deleted.len()
+    }
+
+    /// Returns `true` if no record changed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for ChangeSet<T> {
+    fn default() -> Self {
+        Self {
+            created: Vec::new(),
+            updated: Vec::new(),
+            deleted: Vec::new(),
+        }
+    }
+}
+
+/// The outcome of syncing one resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncReport {
+    /// The key the resource's checkpoint is saved under.
+    pub key: &'static str,
+    /// The checkpoint the sync started from, `None` for the first sync.
+    pub since: Option<OffsetDateTime>,
+    /// The checkpoint saved for the next sync.
+    pub checkpoint: OffsetDateTime,
+    /// The number of records created.
+    pub created: usize,
+    /// The number of records updated.
+    pub updated: usize,
+    /// The number of records deleted.
+    pub deleted: usize,
+}
+
+/// Fetches only the records modified since the previous sync of each resource.
+///
+/// The engine saves a checkpoint per resource in its [`CheckpointStore`] once every change
+/// was handed to the callback, so a failed sync is repeated in full by the next one and
+/// callbacks must tolerate seeing a change twice. Syncs also look back a short overlap
+/// before the checkpoint to cover clock skew between the client and the API.
+///
+/// ### Example:
+/// ```rust,ignore
+/// let engine = SyncEngine::new(FileCheckpointStore::new("checkpoints.json"));
+/// let report = engine
+///     .sync(&PatientService::new(&client), |changes: ChangeSet<Patient>| {
+///         database.apply(changes)
+///     })
+///     .await?;
+/// ```
+#[derive(Debug)]
+pub struct SyncEngine<S> {
+    store: S,
+    overlap: Duration,
+    batch_size: usize,
+}
+
+impl<S: CheckpointStore> SyncEngine<S> {
+    /// Creates an engine that keeps its checkpoints in `store`.
+    pub fn new(store: S) -> Self {
+        Self {
+            store,
+            overlap: Duration::seconds(60),
+            batch_size: 100,
+        }
+    }
+
+    /// Sets how far before the checkpoint each sync looks. Defaults to one minute.
+    pub fn overlap(mut self, overlap: Duration) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Sets the most records handed to the callback at once. Defaults to 100.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Returns the store the engine keeps its checkpoints in.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Syncs the resource of `service`, handing its changes to `on_changes` in batches.
+    ///
+    /// Returns [`Error::Callback`] if `on_changes` fails, in which case the checkpoint is
+    /// left as it was.
+    pub async fn sync<'a, T, F, E>(
+        &self,
+        service: &impl FindService<'a, T, T::QueryParams>,
+        mut on_changes: F,
+    ) -> Result<SyncReport>
+    where
+        T: Syncable,
+        F: FnMut(ChangeSet<T>) -> core::result::Result<(), E>,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let key = T::checkpoint_key();
+        let span = tracing::info_span!("elation.sync", resource = key);
+
+        async {
+            let started = OffsetDateTime::now_utc();
+            let since = self.store.load(key)?;
+            let mut report = SyncReport {
+                key,
+                since,
+                checkpoint: started,
+                created: 0,
+                updated: 0,
+                deleted: 0,
+            };
+
+            // Records are fetched, and told created or updated, from the same lower bound
+            let from = since.map(|since| since - self.overlap);
+            let mut pages = service
+                .find_stream(T::modified_since(from))
+                .try_chunks(self.batch_size);
+            while let Some(records) = pages.try_next().await.map_err(|error| error.1)? {
+                let changes = classify(records, from);
+                report.created += changes.created.len();
+                report.updated += changes.updated.len();
+                report.deleted += changes.deleted.len();
+                on_changes(changes).map_err(|error| Error::Callback(error.into()))?;
+            }
+
+            self.store.save(key, started)?;
+            info!(
+                created = report.created,
+                updated = report.updated,
+                deleted = report.deleted,
+                "sync completed"
+            );
+            Ok(report)
+        }
+        .instrument(span)
+        .await
+    }
+}
+
+/// Sorts `records` into created, updated and deleted ones, relative to the lower bound of the
+/// sync.
+fn classify<T: Syncable>(records: Vec<T>, since: Option<OffsetDateTime>) -> ChangeSet<T> {
+    let mut changes = ChangeSet::default();
+    for record in records {
+        if record.deleted_date().is_some() {
+            changes.deleted.push(record);
+        } else if is_created(&record, since) {
+            changes.created.push(record);
+        } else {
+            changes.updated.push(record);
+        }
+    }
+    changes
+}
+
+/// Every record of the first sync is new; later, records without a creation date are not.
+fn is_created<T: Syncable>(record: &T, since: Option<OffsetDateTime>) -> bool {
+    match (since, record.created_date()) {
+        (None, _) => true,
+        (Some(since), Some(created)) => created >= since,
+        (Some(_), None) => false,
+    }
+}
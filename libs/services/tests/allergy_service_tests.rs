@@ -6,7 +6,6 @@ mod tests {
     use models::patient_profile::{Allergy, AllergyForCreate, AllergyStatus};
     use patient_profile::AllergyService;
     use resource_service::*;
-    use serial_test::serial;
     use services::*;
     use time::{Date, OffsetDateTime};
 
@@ -26,16 +25,11 @@ mod tests {
         }
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_allergy_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let allergy_id = 123456;
         let allergy = get_mock_allergy(allergy_id);
 
@@ -47,7 +41,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let allergy_service = AllergyService::new(&client);
 
         let result = allergy_service.get(allergy_id).await;
@@ -62,14 +60,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_allergy_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let allergy_id = 789012;
         let allergy_for_create = AllergyForCreate {
             status: AllergyStatus::Active,
@@ -92,7 +86,11 @@ mod tests {
                 .body(serde_json::to_string(&get_mock_allergy(allergy_id)).unwrap());
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = AllergyService::new(&client);
 
         let result = service.post(&allergy_for_create).await;
@@ -106,14 +104,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_put_allergy_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let allergy_id = 123456;
         let mock_allergy = get_mock_allergy(allergy_id);
 
@@ -143,7 +137,11 @@ mod tests {
                 .body(serde_json::to_string(&updated_allergy).unwrap());
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let allergy_service = AllergyService::new(&client);
 
         let result = allergy_service.put(&allergy_fc).await;
@@ -157,14 +155,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_allergy_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let allergy_id = 123456;
         let mock = server.mock(|when, then| {
             when.method(DELETE)
@@ -172,7 +166,11 @@ mod tests {
             then.status(204); // No Content
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let allergy_service = AllergyService::new(&client);
 
         let result = allergy_service.delete(allergy_id).await;
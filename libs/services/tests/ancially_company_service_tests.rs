@@ -7,18 +7,11 @@ mod tests {
     use services::orders::AncillaryCompanyService;
     use services::prelude::*;
 
-    use serial_test::serial;
-
-    #[serial]
     #[tokio::test]
     async fn test_get_ancillary_company_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /ancillary_companies/{id}/ endpoint
         let company_id = 140756665106487;
         let ancillary_company = get_mock_ancillary_company(company_id);
@@ -32,7 +25,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = AncillaryCompanyService::new(&client);
 
         // Call the method under test
@@ -48,16 +45,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_ancillary_companies_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let company_type = AncillaryCompanyType::Imaging;
         let mock_company = get_mock_ancillary_company(140756665106487);
 
@@ -78,7 +70,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = AncillaryCompanyService::new(&client);
 
         // Prepare query parameters
@@ -101,16 +97,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_ancillary_company_not_found() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /ancillary_companies/{id}/ endpoint to return 404
         let company_id = 999999;
         let mock = server.mock(|when, then| {
@@ -126,7 +117,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = AncillaryCompanyService::new(&client);
 
         // Call the method under test
@@ -0,0 +1,183 @@
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use client::{Method, RequestOptions};
+    use httpmock::Method::{DELETE, GET, POST};
+    use httpmock::MockServer;
+    use models::patient_profile::{
+        Allergy, AllergyForCreate, AllergyStatus, PatientProfileQueryParams,
+    };
+    use serde_json::json;
+    use services::blocking::AllergyService;
+    use services::bulk::BulkOptions;
+    use time::Date;
+
+    fn get_mock_allergy(allergy_id: i64) -> Allergy {
+        Allergy {
+            id: allergy_id,
+            status: AllergyStatus::Active,
+            start_date: Date::from_calendar_date(1980, time::Month::January, 1).unwrap(),
+            reaction: Some("nausea and vomiting".to_string()),
+            name: "Erythromycin".to_string(),
+            severity: None,
+            medispanid: None,
+            medispandnid: None,
+            patient: 64072843265,
+            created_date: None,
+            deleted_date: None,
+        }
+    }
+
+    fn client_for(server: &MockServer) -> client::blocking::Client {
+        client::Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build_blocking()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_blocking_service_crud() {
+        // Start a local mock server, outside of any async runtime
+        let server = MockServer::start();
+
+        let get = server.mock(|when, then| {
+            when.method(GET)
+                .path("/allergies/1/")
+                .header("x-correlation-id", "batch-7");
+            then.status(200)
+                .body(serde_json::to_string(&get_mock_allergy(1)).unwrap());
+        });
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/allergies");
+            then.status(201)
+                .body(serde_json::to_string(&get_mock_allergy(2)).unwrap());
+        });
+        let delete = server.mock(|when, then| {
+            when.method(DELETE).path("/allergies/2/");
+            then.status(204);
+        });
+
+        let client = client_for(&server);
+        let service = AllergyService::new(&client);
+
+        let options = RequestOptions::new().header(
+            reqwest::header::HeaderName::from_static("x-correlation-id"),
+            reqwest::header::HeaderValue::from_static("batch-7"),
+        );
+        let allergy = service.get_with(1, &options).unwrap();
+        assert_eq!(allergy.name, "Erythromycin");
+
+        let created = service
+            .post(&AllergyForCreate {
+                status: AllergyStatus::Active,
+                start_date: Date::from_calendar_date(1980, time::Month::January, 1).unwrap(),
+                reaction: None,
+                name: "Erythromycin".to_string(),
+                severity: None,
+                medispanid: None,
+                medispandnid: None,
+                patient: 64072843265,
+            })
+            .unwrap();
+        assert_eq!(created.id, 2);
+
+        service.delete(created.id).unwrap();
+
+        get.assert();
+        post.assert();
+        delete.assert();
+    }
+
+    #[test]
+    fn test_blocking_find_iterates_over_every_page() {
+        let server = MockServer::start();
+
+        // The second page is matched first, since its query also holds the patient
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/allergies/")
+                .query_param("offset", "1");
+            then.status(200).json_body(json!({
+                "count": 2,
+                "next": null,
+                "previous": null,
+                "results": [get_mock_allergy(2)]
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/allergies/")
+                .query_param("patients", "64072843265");
+            then.status(200).json_body(json!({
+                "count": 2,
+                "next": server.url("/allergies/?patients=64072843265&offset=1"),
+                "previous": null,
+                "results": [get_mock_allergy(1)]
+            }));
+        });
+
+        let client = client_for(&server);
+        let service = AllergyService::new(&client);
+        let params = || PatientProfileQueryParams {
+            patients: vec![64072843265],
+        };
+
+        let page = service.find(params()).unwrap();
+        assert_eq!(page.results.len(), 1);
+
+        let ids = service
+            .find_iter(params())
+            .map(|allergy| allergy.map(|allergy| allergy.id))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_blocking_client_maps_errors() {
+        let server = MockServer::start();
+
+        server.mock(|when, then| {
+            when.method(GET).path("/care_gaps/9/");
+            then.status(404).json_body(json!({"detail": "Not found."}));
+        });
+
+        let client = client_for(&server);
+
+        let error = client
+            .request_value(Method::GET, "/care_gaps/9/", (), None::<&()>)
+            .unwrap_err();
+        assert!(matches!(error, client::Error::NotFound(_)));
+
+        let error = AllergyService::new(&client).get(9).unwrap_err();
+        assert!(matches!(
+            error,
+            services::Error::ClientError(client::Error::NotFound(_))
+        ));
+    }
+    #[test]
+    fn test_blocking_bulk_delete() {
+        let server = MockServer::start();
+
+        server.mock(|when, then| {
+            when.method(DELETE).path("/allergies/2/");
+            then.status(404).json_body(json!({"detail": "Not found."}));
+        });
+        let deleted = server.mock(|when, then| {
+            when.method(DELETE).path_contains("/allergies/");
+            then.status(204);
+        });
+
+        let client = client_for(&server);
+        let report = AllergyService::new(&client).delete_many(&[1, 2, 3], BulkOptions::default());
+
+        assert_eq!(
+            report
+                .failures()
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+            vec![1]
+        );
+        deleted.assert_hits(2);
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use client::Client;
+    use httpmock::Method::{DELETE, PATCH, POST};
+    use httpmock::MockServer;
+    use models::orders::{LabVendor, LabVendorForUpdate};
+    use models::patient_profile::{Allergy, AllergyForCreate, AllergyStatus};
+    use services::orders::LabVendorService;
+    use services::patient_profile::AllergyService;
+    use services::prelude::*;
+    use time::Date;
+
+    fn get_mock_allergy(allergy_id: i64, name: &str) -> Allergy {
+        Allergy {
+            id: allergy_id,
+            status: AllergyStatus::Active,
+            start_date: Date::from_calendar_date(1980, time::Month::January, 1).unwrap(),
+            reaction: None,
+            name: name.to_string(),
+            severity: None,
+            medispanid: None,
+            medispandnid: None,
+            patient: 64072843265,
+            created_date: None,
+            deleted_date: None,
+        }
+    }
+
+    fn allergy_for_create(name: &str) -> AllergyForCreate {
+        AllergyForCreate {
+            status: AllergyStatus::Active,
+            start_date: Date::from_calendar_date(1980, time::Month::January, 1).unwrap(),
+            reaction: None,
+            name: name.to_string(),
+            severity: None,
+            medispanid: None,
+            medispandnid: None,
+            patient: 64072843265,
+        }
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_post_many_reports_every_item_in_input_order() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        // The first item is answered last, yet still reported first
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/allergies")
+                .json_body_partial(r#"{"name": "Penicillin"}"#);
+            then.status(201)
+                .delay(Duration::from_millis(200))
+                .json_body_obj(&get_mock_allergy(1, "Penicillin"));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/allergies")
+                .json_body_partial(r#"{"name": ""}"#);
+            then.status(400)
+                .json_body(serde_json::json!({"name": ["This field may not be blank."]}));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/allergies")
+                .json_body_partial(r#"{"name": "Sulfa"}"#);
+            then.status(201)
+                .json_body_obj(&get_mock_allergy(3, "Sulfa"));
+        });
+
+        let client = client_for(&server);
+        let allergies = vec![
+            allergy_for_create("Penicillin"),
+            allergy_for_create(""),
+            allergy_for_create("Sulfa"),
+        ];
+
+        let report = AllergyService::new(&client)
+            .post_many(&allergies, BulkOptions { concurrency: 3 })
+            .await;
+
+        assert_eq!(report.len(), 3);
+        assert!(!report.is_success());
+        assert_eq!(
+            report
+                .successes()
+                .map(|(index, allergy)| (index, allergy.id))
+                .collect::<Vec<_>>(),
+            vec![(0, 1), (2, 3)]
+        );
+
+        let failures = report.failures().collect::<Vec<_>>();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, 1);
+        assert!(matches!(
+            failures[0].1,
+            Error::ClientError(client::Error::BadRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_patch_many_continues_past_failures() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        // Defined first, so it takes precedence over the catch-all below
+        let missing = server.mock(|when, then| {
+            when.method(PATCH).path("/lab_vendors/2/");
+            then.status(404)
+                .json_body(serde_json::json!({"detail": "Not found."}));
+        });
+
+        let patched = server.mock(|when, then| {
+            when.method(PATCH).path_contains("/lab_vendors/");
+            then.status(200).json_body_obj(&LabVendor {
+                id: 1,
+                practice_created: None,
+                name: "Quest".to_string(),
+                display_name: "Quest".to_string(),
+                has_order_compendium: false,
+                has_test_compendium: false,
+                results_integration_available: false,
+                orders_integration_available: false,
+                compendiums: vec![],
+                default_compendium: None,
+            });
+        });
+
+        let client = client_for(&server);
+        let update = LabVendorForUpdate {
+            display_name: Some("Quest".to_string()),
+            ..Default::default()
+        };
+        let patches = (1..=3).map(|id| (id, update.clone())).collect::<Vec<_>>();
+
+        let report = LabVendorService::new(&client)
+            .patch_many(&patches, BulkOptions::default())
+            .await;
+
+        assert_eq!(report.failure_count(), 1);
+        let results = report.into_results();
+        assert!(results[0].is_ok());
+        assert!(matches!(
+            results[1],
+            Err(Error::ClientError(client::Error::NotFound(_)))
+        ));
+        assert!(results[2].is_ok());
+        assert_eq!(patched.hits_async().await, 2);
+        assert_eq!(missing.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_sends_one_request_per_id() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE).path_contains("/allergies/");
+            then.status(204);
+        });
+
+        let client = client_for(&server);
+        let ids = (1..=10).collect::<Vec<i64>>();
+
+        let report = AllergyService::new(&client)
+            .delete_many(&ids, BulkOptions { concurrency: 2 })
+            .await;
+
+        assert!(report.is_success());
+        assert_eq!(report.len(), 10);
+        assert_eq!(mock.hits_async().await, 10);
+
+        let report = AllergyService::new(&client)
+            .delete_many(&[], BulkOptions::default())
+            .await;
+        assert!(report.is_empty());
+    }
+}
@@ -8,18 +8,11 @@ mod tests {
     use services::prelude::*;
     use time::{Date, OffsetDateTime};
 
-    use serial_test::serial;
-
-    #[serial]
     #[tokio::test]
     async fn test_get_cardiac_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /cardiac_orders/{id}/ endpoint
         let order_id = 140756377075740;
         let cardiac_order = get_mock_cardiac_order(order_id);
@@ -33,7 +26,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = CardiacOrderService::new(&client);
 
         // Call the method under test
@@ -51,15 +48,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_cardiac_order_success() {
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the cardiac order data to create
         let order_for_create = CardiacOrderForCreate {
             ancillary_company: 140755855605768,
@@ -105,7 +97,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = CardiacOrderService::new(&client);
 
         // Call the method under test
@@ -123,16 +119,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_patch_cardiac_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let order_id = 140756377075740;
 
         // Prepare the cardiac order data to update
@@ -171,7 +162,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = CardiacOrderService::new(&client);
 
         // Call the method under test
@@ -189,16 +184,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_put_cardiac_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let order_id = 140756377075740;
 
         // Prepare the cardiac order data to update using CardiacOrderForCreate
@@ -237,7 +227,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = CardiacOrderService::new(&client);
 
         // Call the method under test
@@ -254,16 +248,11 @@ mod tests {
         // Ensure the mock was called
         mock.assert_async().await;
     }
-    #[serial]
     #[tokio::test]
     async fn test_delete_cardiac_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the DELETE /cardiac_orders/{id}/ endpoint
         let order_id = 140756377075740;
         let mock = server.mock(|when, then| {
@@ -273,7 +262,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = CardiacOrderService::new(&client);
 
         // Call the method under test
@@ -8,18 +8,11 @@ mod tests {
     use services::prelude::*;
     use time::OffsetDateTime;
 
-    use serial_test::serial;
-
-    #[serial]
     #[tokio::test]
     async fn test_get_cardiac_order_test_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /cardiac_order_tests/{id}/ endpoint
         let test_id = 140756665106487;
         let cardiac_order_test = get_mock_cardiac_order_test(test_id);
@@ -33,7 +26,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = CardiacOrderTestService::new(&client);
 
         // Call the method under test
@@ -51,15 +48,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_cardiac_order_test_success() {
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Create the cardiac order test data for the mock
         let test_id = 140756665106488;
 
@@ -88,7 +80,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = CardiacOrderTestService::new(&client);
 
         // Call the method under test
@@ -108,16 +104,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_cardiac_order_tests_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let test1 = get_mock_cardiac_order_test(140756665106487);
         let mut test2 = get_mock_cardiac_order_test(140756665106488);
         test2.name = "another test".to_string();
@@ -138,7 +129,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = CardiacOrderTestService::new(&client);
 
         // Prepare query parameters
@@ -163,16 +158,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_cardiac_order_test_not_found() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /cardiac_order_tests/{id}/ endpoint to return 404
         let test_id = 999999;
         let mock = server.mock(|when, then| {
@@ -188,7 +178,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = CardiacOrderTestService::new(&client);
 
         // Call the method under test
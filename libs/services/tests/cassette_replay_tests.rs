@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use client::{Cassette, Client};
+    use models::orders::*;
+    use services::orders::LabVendorService;
+    use services::prelude::*;
+
+    fn cassette(name: &str) -> Cassette {
+        Cassette::replay(format!(
+            "{}/tests/cassettes/{name}.json",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_lab_vendors_replayed_from_cassette() {
+        // Replay recorded Elation responses; nothing listens on the discard port
+        let cassette = cassette("lab_vendors");
+        let client = Client::builder()
+            .base_url("http://127.0.0.1:9")
+            .token("12345")
+            .middleware(cassette.clone())
+            .build()
+            .unwrap();
+        let service = LabVendorService::new(&client);
+
+        // Call the methods under test
+        let vendors = service
+            .find(LabVendorQueryParams {
+                name: Some(vec!["Quest".to_string()]),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .results;
+        let vendor = service.get(vendors[0].id).await.unwrap();
+
+        // Assert the results
+        assert_eq!(vendors.len(), 1);
+        assert_eq!(vendors[0].display_name, "Quest Diagnostics");
+        assert!(vendors[0].has_order_compendium);
+        assert_eq!(vendor.id, 1966080);
+        assert!(vendor.compendiums.is_empty());
+        assert_eq!(cassette.unplayed(), 0);
+
+        // A request that was never recorded fails instead of reaching the network
+        let error = service.get(1).await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::ClientError(client::Error::UnmatchedRequest { .. })
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use client::Client;
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use models::patient_profile::{Allergy, AllergyStatus};
+    use serde_json::json;
+    use services::prelude::*;
+    use time::Date;
+
+    fn get_mock_allergy(allergy_id: i64) -> Allergy {
+        Allergy {
+            id: allergy_id,
+            status: AllergyStatus::Active,
+            start_date: Date::from_calendar_date(1980, time::Month::January, 1).unwrap(),
+            reaction: Some("nausea and vomiting".to_string()),
+            name: "Erythromycin".to_string(),
+            severity: None,
+            medispanid: None,
+            medispandnid: None,
+            patient: 7,
+            created_date: None,
+            deleted_date: None,
+        }
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_chart_sections_load_independently() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/7/");
+            then.status(404).json_body(json!({"detail": "Not found."}));
+        });
+        // The second page is matched first, since its query also holds the patient
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/allergies/")
+                .query_param("offset", "1");
+            then.status(200).json_body(json!({
+                "count": 2,
+                "next": null,
+                "previous": null,
+                "results": [get_mock_allergy(2)]
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/allergies/")
+                .query_param("patients", "7");
+            then.status(200).json_body(json!({
+                "count": 2,
+                "next": server.url("/allergies/?patients=7&offset=1"),
+                "previous": null,
+                "results": [get_mock_allergy(1)]
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/problems/")
+                .query_param("patients", "7");
+            then.status(400)
+                .json_body(json!({"patients": ["Invalid patient."]}));
+        });
+        let immunizations = server.mock(|when, then| {
+            when.method(GET).path("/immunizations/");
+            then.status(200);
+        });
+
+        let client = client_for(&server);
+        let chart = ChartService::new(&client)
+            .load(7, &[ChartSection::Allergies, ChartSection::Problems])
+            .await;
+
+        assert_eq!(chart.patient.as_ref().unwrap_err().kind, "NotFound");
+        let allergies = chart.allergies.as_ref().unwrap().as_ref().unwrap();
+        assert_eq!(
+            allergies
+                .iter()
+                .map(|allergy| allergy.id)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            chart.section_error(ChartSection::Problems).unwrap().kind,
+            "BadRequest"
+        );
+        assert_eq!(chart.failed_sections(), vec![ChartSection::Problems]);
+        assert!(!chart.is_complete());
+
+        // Sections that were not requested are not fetched
+        assert!(chart.immunizations.is_none());
+        assert_eq!(immunizations.hits_async().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_chart_round_trips_through_json() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/7/");
+            then.status(503);
+        });
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/allergies/")
+                .query_param("patients", "7");
+            then.status(200).json_body(json!({
+                "count": 1,
+                "next": null,
+                "previous": null,
+                "results": [get_mock_allergy(1)]
+            }));
+        });
+
+        let client = client_for(&server);
+        let chart = ChartService::shared(std::sync::Arc::new(client))
+            .load(7, &[ChartSection::Allergies])
+            .await;
+
+        let cached = serde_json::to_string(&chart).unwrap();
+        let restored: PatientChart = serde_json::from_str(&cached).unwrap();
+
+        assert_eq!(restored.patient_id, 7);
+        assert_eq!(restored.patient.unwrap_err(), chart.patient.unwrap_err());
+        assert_eq!(restored.allergies.unwrap().unwrap()[0].id, 1);
+        assert!(restored.problems.is_none());
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use client::{Client, Fault, FaultInjector, FaultRule, MemoryResponse, MemoryTransport};
+    use models::orders::*;
+    use reqwest::{Method, StatusCode};
+    use services::orders::LabVendorService;
+    use services::prelude::*;
+
+    fn client_with(faults: FaultInjector) -> Client {
+        let vendor = LabVendor {
+            id: 1,
+            practice_created: None,
+            name: "Quest".to_string(),
+            display_name: "Quest".to_string(),
+            has_order_compendium: false,
+            has_test_compendium: false,
+            results_integration_available: false,
+            orders_integration_available: false,
+            compendiums: vec![],
+            default_compendium: None,
+        };
+        let transport = MemoryTransport::new();
+        transport.respond(
+            Method::GET,
+            "/lab_vendors/1/",
+            MemoryResponse::json(StatusCode::OK, &vendor),
+        );
+
+        Client::builder()
+            .base_url("http://elation.test/")
+            .token("12345")
+            .transport(transport)
+            .faults(faults)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_propagate_through_services() {
+        let client = client_with(
+            FaultInjector::new()
+                .rule(FaultRule::new(Fault::Status(StatusCode::SERVICE_UNAVAILABLE)).nth(1))
+                .rule(FaultRule::new(Fault::Timeout(Duration::from_millis(20))).nth(2))
+                .rule(FaultRule::new(Fault::MalformedJson).nth(3)),
+        );
+        let service = LabVendorService::new(&client);
+
+        let error = service.get(1).await.unwrap_err();
+        assert!(matches!(
+            error,
+            services::Error::ClientError(client::Error::ServiceUnavailable(_))
+        ));
+
+        let error = service.get(1).await.unwrap_err();
+        assert!(matches!(
+            error,
+            services::Error::ClientError(client::Error::ReqwestError(ref e)) if e.is_timeout()
+        ));
+
+        // A body that does not parse fails in the service, not in the client
+        let error = service.get(1).await.unwrap_err();
+        assert!(matches!(
+            error,
+            services::Error::ClientError(client::Error::ReqwestError(ref e)) if e.is_decode()
+        ));
+
+        assert_eq!(service.get(1).await.unwrap().name, "Quest");
+    }
+}
@@ -0,0 +1,276 @@
+#[cfg(test)]
+mod tests {
+    use client::Client;
+    use futures::TryStreamExt;
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use models::orders::*;
+    use models::patient_profile::{InsuranceCard, PatientProfileQueryParams};
+    use services::orders::LabVendorService;
+    use services::patient_profile::InsuranceCardService;
+    use services::prelude::*;
+
+    fn get_mock_lab_vendor(id: i64) -> LabVendor {
+        LabVendor {
+            id,
+            practice_created: None,
+            name: format!("Vendor {id}"),
+            display_name: format!("Vendor {id}"),
+            has_order_compendium: false,
+            has_test_compendium: false,
+            results_integration_available: false,
+            orders_integration_available: false,
+            compendiums: vec![],
+            default_compendium: None,
+        }
+    }
+
+    fn page_body(ids: &[i64], next: Option<String>) -> String {
+        let vendors: Vec<LabVendor> = ids.iter().copied().map(get_mock_lab_vendor).collect();
+        serde_json::json!({
+            "count": 4,
+            "next": next,
+            "previous": null,
+            "results": vendors,
+        })
+        .to_string()
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_find_stream_follows_next_and_skips_duplicates() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        // The second page repeats vendor 2, as happens when a record is created mid-read
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/lab_vendors/")
+                .query_param("name", "DLS");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(page_body(&[1, 2], Some(server.url("/lab_vendors/page/2/"))));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/page/2/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(page_body(&[2, 3, 4], None));
+        });
+
+        let client = client_for(&server);
+        let service = LabVendorService::new(&client);
+        let params = LabVendorQueryParams {
+            name: Some(vec!["DLS".to_string()]),
+            ..Default::default()
+        };
+
+        // Call the method under test
+        let vendors: Vec<LabVendor> = service.find_stream(params).try_collect().await.unwrap();
+
+        // Assert the result
+        let ids: Vec<i64> = vendors.iter().map(|vendor| vendor.id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+
+        // Ensure each page was requested once
+        first.assert_async().await;
+        second.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_find_stream_is_lazy_and_honours_max_items() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let first = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(page_body(&[1, 2], Some(server.url("/lab_vendors/page/2/"))));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/page/2/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(page_body(&[3, 4], None));
+        });
+
+        let client = client_for(&server);
+        let service = LabVendorService::new(&client);
+
+        // Nothing is requested until the stream is polled
+        let stream = service
+            .find_stream(LabVendorQueryParams::default())
+            .max_items(2);
+        assert_eq!(first.hits_async().await, 0);
+
+        let vendors: Vec<LabVendor> = stream.try_collect().await.unwrap();
+
+        // Assert the result
+        assert_eq!(vendors.len(), 2);
+        first.assert_async().await;
+        assert_eq!(second.hits_async().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_find_stream_with_prefetch() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(page_body(&[1, 2], Some(server.url("/lab_vendors/page/2/"))));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/page/2/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(page_body(&[3, 4], None));
+        });
+
+        let client = client_for(&server);
+        let service = LabVendorService::new(&client);
+        let mut stream = service
+            .find_stream(LabVendorQueryParams::default())
+            .prefetch(true);
+
+        // Taking the first item starts fetching the second page in the background
+        let vendor = stream.try_next().await.unwrap().unwrap();
+        assert_eq!(vendor.id, 1);
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while second.hits_async().await == 0 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("the next page was not prefetched");
+
+        let rest: Vec<LabVendor> = stream.try_collect().await.unwrap();
+        assert_eq!(rest.len(), 3);
+        second.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_find_stream_ends_with_page_error() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(page_body(&[1, 2], Some(server.url("/lab_vendors/page/2/"))));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/page/2/");
+            then.status(500);
+        });
+
+        let client = client_for(&server);
+        let service = LabVendorService::new(&client);
+        let mut stream = service.find_stream(LabVendorQueryParams::default());
+
+        // The items of the first page are yielded before the error
+        assert_eq!(stream.try_next().await.unwrap().unwrap().id, 1);
+        assert_eq!(stream.try_next().await.unwrap().unwrap().id, 2);
+        let error = stream.try_next().await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::ClientError(client::Error::InternalServerError(_))
+        ));
+        assert!(stream.try_next().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_stream_only_remembers_the_previous_page() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(page_body(&[1, 2], Some(server.url("/lab_vendors/page/2/"))));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/page/2/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(page_body(&[2, 3], Some(server.url("/lab_vendors/page/3/"))));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/page/3/");
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(page_body(&[1, 4], None));
+        });
+
+        let client = client_for(&server);
+        let service = LabVendorService::new(&client);
+
+        // Call the method under test
+        let vendors: Vec<LabVendor> = service
+            .find_stream(LabVendorQueryParams::default())
+            .try_collect()
+            .await
+            .unwrap();
+
+        // Vendor 2 repeats on the next page and is skipped; vendor 1 is two pages apart
+        let ids: Vec<i64> = vendors.iter().map(|vendor| vendor.id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 1, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_find_stream_keeps_resources_without_unique_key() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        // Insurance cards of two patients share their ranks
+        let card = |rank: i32| InsuranceCard {
+            rank,
+            images: vec![],
+        };
+        server.mock(|when, then| {
+            when.method(GET).path("/insurance_cards/");
+            then.status(200).json_body(serde_json::json!({
+                "count": 4,
+                "next": server.url("/insurance_cards/page/2/"),
+                "previous": null,
+                "results": [card(1), card(2)],
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/insurance_cards/page/2/");
+            then.status(200).json_body(serde_json::json!({
+                "count": 4,
+                "next": null,
+                "previous": null,
+                "results": [card(1), card(2)],
+            }));
+        });
+
+        let client = client_for(&server);
+        let service = InsuranceCardService::new(&client);
+
+        // Call the method under test
+        let cards: Vec<InsuranceCard> = service
+            .find_stream(PatientProfileQueryParams {
+                patients: vec![1, 2],
+            })
+            .try_collect()
+            .await
+            .unwrap();
+
+        let ranks: Vec<i32> = cards.iter().map(|card| card.rank).collect();
+        assert_eq!(ranks, vec![1, 2, 1, 2]);
+    }
+}
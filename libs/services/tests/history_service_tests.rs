@@ -4,7 +4,6 @@ mod tests {
     use httpmock::Method::{DELETE, GET, POST};
     use httpmock::MockServer;
     use models::patient_profile::{History, HistoryForCreate, HistoryType};
-    use serial_test::serial;
     use services::patient_profile::HistoryService;
     use services::prelude::*;
     use time::OffsetDateTime;
@@ -21,14 +20,10 @@ mod tests {
         }
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_history_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let history_id = 123456;
         let history = get_mock_history(history_id);
 
@@ -39,7 +34,11 @@ mod tests {
                 .body(serde_json::to_string(&history).unwrap());
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let history_service = HistoryService::new(&client);
 
         let result = history_service.get(history_id).await;
@@ -54,14 +53,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_history_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let history_id = 789012;
         let history_for_create = HistoryForCreate {
             r#type: HistoryType::Diet,
@@ -80,7 +75,11 @@ mod tests {
                 .body(serde_json::to_string(&get_mock_history(history_id)).unwrap());
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = HistoryService::new(&client);
 
         let result = service.post(&history_for_create).await;
@@ -94,14 +93,10 @@ mod tests {
         mock.assert_async().await;
     }
     //
-    //#[serial]
     //#[tokio::test]
     //async fn test_update_patch_history_success() {
     //    let server = MockServer::start_async().await;
     //
-    //    std::env::set_var("TEST_ENV", "TRUE");
-    //    std::env::set_var("MOCK_SERVER_URL", server.base_url());
-    //
     //    let history_id = 123456;
     //    let mock_history = History {
     //        text: "Updated Yogurt daily".to_owned(),
@@ -122,7 +117,11 @@ mod tests {
     //            .body(serde_json::to_string(&mock_history).unwrap());
     //    });
     //
-    //    let client = Client::new().await.unwrap();
+    //    let client = Client::builder()
+    //        .base_url(server.base_url())
+    //        .token("12345")
+    //        .build()
+    //        .unwrap();
     //    let history_service = HistoryService::new(&client);
     //
     //    let history_fu = HistoryForUpdate {
@@ -141,14 +140,10 @@ mod tests {
     //    mock.assert_async().await;
     //}
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_history_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let history_id = 123456;
         let mock = server.mock(|when, then| {
             when.method(DELETE)
@@ -156,7 +151,11 @@ mod tests {
             then.status(204); // No Content
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let history_service = HistoryService::new(&client);
 
         let result = history_service.delete(history_id).await;
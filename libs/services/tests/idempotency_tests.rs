@@ -0,0 +1,346 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use client::{Client, Fault, FaultInjector, FaultRule};
+    use httpmock::Method::{GET, POST};
+    use httpmock::MockServer;
+    use models::patient_profile::*;
+    use reqwest::{Method, StatusCode};
+    use services::patient_profile::PatientService;
+    use services::prelude::*;
+    use time::{Date, Month};
+
+    fn new_patient() -> PatientForCreate {
+        PatientForCreate {
+            first_name: "Jane".to_string(),
+            last_name: "Smith".to_string(),
+            dob: Date::from_calendar_date(1985, Month::May, 5).unwrap(),
+            sex: Sex::Female,
+            primary_physician: 1,
+            caregiver_practice: 2,
+            address: None,
+            emails: None,
+            insurances: vec![],
+        }
+    }
+
+    fn created_patient(id: i64) -> Patient {
+        let new = new_patient();
+        Patient {
+            id,
+            first_name: new.first_name,
+            middle_name: None,
+            last_name: new.last_name,
+            actual_name: None,
+            gender_identity: None,
+            legal_gender_marker: None,
+            pronouns: None,
+            sex: new.sex,
+            sexual_orientation: None,
+            primary_physician: new.primary_physician,
+            caregiver_practice: new.caregiver_practice,
+            dob: new.dob,
+            ssn: None,
+            race: None,
+            ethnicity: None,
+            preferred_language: None,
+            notes: None,
+            vip: false,
+            tags: vec![],
+            sms_opt_in_status: None,
+            address: None,
+            phones: None,
+            emails: None,
+            guarantor: None,
+            insurances: None,
+            deleted_insurances: None,
+            preference: None,
+            emergency_contact: None,
+            previous_name: None,
+            master_patient: None,
+            employer: None,
+            consents: None,
+            metadata: None,
+            merged_into_chart: None,
+            primary_care_provider: None,
+            primary_care_provider_npi: None,
+            patient_status: PatientStatus {
+                deceased_date: None,
+                inactive_reason: None,
+                last_status_change: None,
+                notes: None,
+                status: PatientStatusEnum::Active,
+            },
+            created_date: None,
+            deleted_date: None,
+        }
+    }
+
+    fn page(patients: &[Patient]) -> String {
+        serde_json::json!({
+            "count": patients.len(),
+            "next": null,
+            "previous": null,
+            "results": patients,
+        })
+        .to_string()
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_remembered_key_returns_the_created_record() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let post = server.mock(|when, then| {
+            when.method(POST)
+                .path("/patients")
+                .header_exists("Idempotency-Key");
+            then.status(201)
+                .body(serde_json::to_string(&created_patient(11)).unwrap());
+        });
+        let get = server.mock(|when, then| {
+            when.method(GET).path("/patients/11/");
+            then.status(200)
+                .body(serde_json::to_string(&created_patient(11)).unwrap());
+        });
+
+        let client = client_for(&server);
+        let service = PatientService::new(&client);
+        let store = IdempotencyStore::new();
+
+        let first = service
+            .post_idempotent(&new_patient(), &store)
+            .await
+            .unwrap();
+        let second = service
+            .post_idempotent(&new_patient(), &store)
+            .await
+            .unwrap();
+
+        assert_eq!((first.id, second.id), (11, 11));
+        assert_eq!(store.len(), 1);
+        assert_eq!(post.hits_async().await, 1);
+        assert_eq!(get.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_create_is_reconciled_instead_of_reposted() {
+        let server = MockServer::start_async().await;
+
+        // The patient is created, but the response arrives too late
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/patients");
+            then.status(201)
+                .delay(Duration::from_secs(1))
+                .body(serde_json::to_string(&created_patient(12)).unwrap());
+        });
+        let search = server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/")
+                .query_param("first_name", "Jane")
+                .query_param("last_name", "Smith")
+                .query_param("dob", "1985-05-05");
+            then.status(200).body(page(&[created_patient(12)]));
+        });
+
+        let client = client_for(&server);
+        let service = PatientService::new(&client);
+        let store = IdempotencyStore::new();
+        let key = IdempotencyKey::new("intake-form-7");
+
+        let patient = service
+            .post_idempotent_with(
+                &new_patient(),
+                key.clone(),
+                &store,
+                &RequestOptions::new().timeout(Duration::from_millis(100)),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(patient.id, 12);
+        assert_eq!(store.get(&key).as_deref(), Some("12"));
+        assert_eq!(post.hits_async().await, 1);
+        assert_eq!(search.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_that_never_arrived_is_reposted() {
+        let server = MockServer::start_async().await;
+
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/patients");
+            then.status(201)
+                .body(serde_json::to_string(&created_patient(13)).unwrap());
+        });
+        // Another patient with the same name is not mistaken for ours
+        let mut other = created_patient(5);
+        other.primary_physician = 99;
+        let search = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(200).body(page(&[other]));
+        });
+
+        // The connection of the first attempt is reset before an answer arrives
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .faults(
+                FaultInjector::new().rule(
+                    FaultRule::new(Fault::ConnectionReset)
+                        .method(Method::POST)
+                        .nth(1),
+                ),
+            )
+            .build()
+            .unwrap();
+        let service = PatientService::new(&client);
+
+        let patient = service
+            .post_idempotent(&new_patient(), &IdempotencyStore::new())
+            .await
+            .unwrap();
+
+        assert_eq!(patient.id, 13);
+        assert_eq!(search.hits_async().await, 1);
+        assert_eq!(post.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_searches_every_page() {
+        let server = MockServer::start_async().await;
+
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/patients");
+            then.status(201)
+                .delay(Duration::from_secs(1))
+                .body(serde_json::to_string(&created_patient(14)).unwrap());
+        });
+        let second_page = server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/")
+                .query_param("offset", "1");
+            then.status(200).body(page(&[created_patient(14)]));
+        });
+        let mut other = created_patient(5);
+        other.primary_physician = 99;
+        let first_page = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(200).body(
+                serde_json::json!({
+                    "count": 2,
+                    "next": server.url("/patients/?offset=1"),
+                    "previous": null,
+                    "results": [other],
+                })
+                .to_string(),
+            );
+        });
+
+        let client = client_for(&server);
+        let service = PatientService::new(&client);
+
+        let patient = service
+            .post_idempotent_with(
+                &new_patient(),
+                IdempotencyKey::new("intake-form-8"),
+                &IdempotencyStore::new(),
+                &RequestOptions::new().timeout(Duration::from_millis(100)),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(patient.id, 14);
+        assert_eq!(post.hits_async().await, 1);
+        assert_eq!(first_page.hits_async().await, 1);
+        assert_eq!(second_page.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_several_matches_return_the_ambiguous_error() {
+        let server = MockServer::start_async().await;
+
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/patients");
+            then.status(StatusCode::GATEWAY_TIMEOUT.as_u16())
+                .body(r#"{"detail": "Gateway timeout."}"#);
+        });
+        // Either patient may be the one the timed out create made
+        let search = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(200)
+                .body(page(&[created_patient(15), created_patient(16)]));
+        });
+
+        let client = client_for(&server);
+        let service = PatientService::new(&client);
+        let store = IdempotencyStore::new();
+
+        let error = service
+            .post_idempotent(&new_patient(), &store)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            services::Error::ClientError(client::Error::GatewayTimeout(_))
+        ));
+        assert!(store.is_empty());
+        assert_eq!(post.hits_async().await, 1);
+        assert_eq!(search.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_definite_failures_are_not_reconciled() {
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(POST).path("/patients");
+            then.status(StatusCode::BAD_REQUEST.as_u16())
+                .body(r#"{"dob": ["Date has wrong format."]}"#);
+        });
+        let search = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(200).body(page(&[]));
+        });
+
+        let client = client_for(&server);
+        let service = PatientService::new(&client);
+        let store = IdempotencyStore::new();
+
+        let error = service
+            .post_idempotent(&new_patient(), &store)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            services::Error::ClientError(client::Error::BadRequest(_))
+        ));
+        assert!(store.is_empty());
+        assert_eq!(search.hits_async().await, 0);
+    }
+
+    #[test]
+    fn test_derived_keys_follow_content() {
+        let key = IdempotencyKey::derive::<Patient, _>(&new_patient()).unwrap();
+        assert_eq!(
+            key,
+            IdempotencyKey::derive::<Patient, _>(&new_patient()).unwrap()
+        );
+        assert!(key.as_str().starts_with("patients-"));
+
+        let mut other = new_patient();
+        other.first_name = "Janet".to_string();
+        assert_ne!(key, IdempotencyKey::derive::<Patient, _>(&other).unwrap());
+    }
+}
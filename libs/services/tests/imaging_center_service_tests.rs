@@ -8,18 +8,11 @@ mod tests {
     use services::prelude::*;
     use time::OffsetDateTime;
 
-    use serial_test::serial;
-
-    #[serial]
     #[tokio::test]
     async fn test_get_imaging_center_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /imaging_centers/{id}/ endpoint
         let center_id = 140755855671306;
         let imaging_center = get_mock_imaging_center(center_id);
@@ -33,7 +26,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = ImagingCenterService::new(&client);
 
         // Call the method under test
@@ -51,16 +48,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_imaging_centers_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let center1 = get_mock_imaging_center(140755855671306);
         let mut center2 = get_mock_imaging_center(140755855671307);
         center2.location_name = "Another Imaging Center".to_string();
@@ -82,7 +74,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = ImagingCenterService::new(&client);
 
         // Prepare query parameters
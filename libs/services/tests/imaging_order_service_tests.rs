@@ -8,18 +8,11 @@ mod tests {
     use services::prelude::*;
     use time::{Date, OffsetDateTime};
 
-    use serial_test::serial;
-
-    #[serial]
     #[tokio::test]
     async fn test_get_imaging_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /imaging_orders/{id}/ endpoint
         let order_id = 140756377075740;
         let imaging_order = get_mock_imaging_order(order_id);
@@ -33,7 +26,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = ImagingOrderService::new(&client);
 
         // Call the method under test
@@ -51,15 +48,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_imaging_order_success() {
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the imaging order data to create
         let order_for_create = ImagingOrderForCreate {
             ancillary_company: 140755855605768,
@@ -98,7 +90,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = ImagingOrderService::new(&client);
 
         // Call the method under test
@@ -116,16 +112,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_put_imaging_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let order_id = 140756377075740;
 
         // Prepare the imaging order data to update using ImagingOrderForCreate
@@ -164,7 +155,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = ImagingOrderService::new(&client);
 
         // Call the method under test
@@ -183,16 +178,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_imaging_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the DELETE /imaging_orders/{id}/ endpoint
         let order_id = 140756377075740;
         let mock = server.mock(|when, then| {
@@ -202,7 +192,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = ImagingOrderService::new(&client);
 
         // Call the method under test
@@ -8,18 +8,11 @@ mod tests {
     use services::prelude::*;
     use time::OffsetDateTime;
 
-    use serial_test::serial;
-
-    #[serial]
     #[tokio::test]
     async fn test_get_imaging_order_test_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /imaging_order_tests/{id}/ endpoint
         let test_id = 140756665106487;
         let imaging_order_test = get_mock_imaging_order_test(test_id);
@@ -33,7 +26,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = ImagingOrderTestService::new(&client);
 
         // Call the method under test
@@ -51,15 +48,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_imaging_order_test_success() {
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the imaging order test data to create
         let test_for_create = ImagingOrderTestForCreate {
             code: Some("IM123".to_string()),
@@ -85,7 +77,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = ImagingOrderTestService::new(&client);
 
         // Call the method under test
@@ -104,16 +100,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_imaging_order_tests_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let test1 = get_mock_imaging_order_test(140756665106487);
         let mut test2 = get_mock_imaging_order_test(140756665106488);
         test2.name = "another test".to_string();
@@ -134,7 +125,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = ImagingOrderTestService::new(&client);
 
         // Prepare query parameters
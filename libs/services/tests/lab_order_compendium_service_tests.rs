@@ -7,19 +7,13 @@ mod tests {
     use services::orders::LabOrderCompendiumService;
     use services::prelude::*;
 
-    use serial_test::serial;
     use time::OffsetDateTime;
 
-    #[serial]
     #[tokio::test]
     async fn test_get_lab_order_compendium_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /lab_order_compendiums/{id}/ endpoint
         let compendium_id = 140745672294843;
         let lab_order_compendium = get_mock_lab_order_compendium(compendium_id);
@@ -33,7 +27,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderCompendiumService::new(&client);
 
         // Call the method under test
@@ -49,16 +47,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_lab_order_compendiums_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let mock_compendium = get_mock_lab_order_compendium(140745672294843);
         let compendiums = vec![mock_compendium.clone()];
         let compendiums_json = serde_json::to_string(&compendiums).unwrap();
@@ -81,7 +74,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderCompendiumService::new(&client);
 
         // Call the method under test
@@ -98,16 +95,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_lab_order_compendium_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the compendium data to create
         let compendium_for_create = LabOrderCompendiumForCreate {
             lab_vendor: 67186196726,
@@ -137,7 +129,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderCompendiumService::new(&client);
 
         // Call the method under test
@@ -155,16 +151,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    //#[serial]
     //#[tokio::test]
     //async fn test_update_lab_order_compendium_success_put() {
     //    // Start a local mock server
     //    let server = MockServer::start_async().await;
     //
-    //    // Set the mock environment variables
-    //    std::env::set_var("TEST_ENV", "TRUE");
-    //    std::env::set_var("MOCK_SERVER_URL", server.base_url());
-    //
     //    // Prepare the compendium data to update (PUT)
     //    let compendium_for_update = LabOrderCompendiumForUpdate {
     //        lab_vendor: Some(67186196726),
@@ -194,7 +185,11 @@ mod tests {
     //    });
     //
     //    // Create a client pointing to the mock server
-    //    let client = Client::new().await.unwrap();
+    //    let client = Client::builder()
+    //        .base_url(server.base_url())
+    //        .token("12345")
+    //        .build()
+    //        .unwrap();
     //    let service = LabOrderCompendiumService::new(&client);
     //
     //    // Call the method under test
@@ -212,16 +207,11 @@ mod tests {
     //    mock.assert_async().await;
     //}
 
-    #[serial]
     #[tokio::test]
     async fn test_update_lab_order_compendium_success_patch() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the compendium data to update (PATCH)
         let compendium_for_update = LabOrderCompendiumForUpdate {
             name: Some("Partially Updated Compendium".to_string()),
@@ -250,7 +240,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderCompendiumService::new(&client);
 
         // Call the method under test
@@ -268,16 +262,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_lab_order_compendium_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the DELETE /lab_order_compendiums/{id}/ endpoint
         let compendium_id = 140745672294843;
         let mock = server.mock(|when, then| {
@@ -287,7 +276,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderCompendiumService::new(&client);
 
         // Call the method under test
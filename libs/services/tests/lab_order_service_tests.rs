@@ -8,18 +8,11 @@ mod tests {
     use services::prelude::*;
     use time::{Date, OffsetDateTime};
 
-    use serial_test::serial;
-
-    #[serial]
     #[tokio::test]
     async fn test_get_lab_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /lab_orders/{id}/ endpoint
         let order_id = 140754512183329;
         let lab_order = get_mock_lab_order(order_id);
@@ -32,7 +25,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderService::new(&client);
 
         // Call the method under test
@@ -50,15 +47,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_lab_order_success() {
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the lab order data to create
         let order_for_create = LabOrderForCreate {
             patient: 140754511659009,
@@ -113,7 +105,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderService::new(&client);
 
         // Call the method under test
@@ -131,16 +127,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_lab_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let order_id = 140754512183329;
 
         // Prepare the lab order data to update
@@ -170,7 +161,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderService::new(&client);
 
         // Call the method under test
@@ -189,16 +184,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_lab_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the DELETE /lab_orders/{id}/ endpoint
         let order_id = 140754512183329;
         let mock = server.mock(|when, then| {
@@ -208,7 +198,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderService::new(&client);
 
         // Call the method under test
@@ -223,16 +217,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_lab_orders_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let order1 = get_mock_lab_order(140754512183329);
         let mut order2 = get_mock_lab_order(140754512183330);
         order2.patient = 140754511659010; // Different patient
@@ -254,7 +243,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderService::new(&client);
 
         // Prepare query parameters
@@ -280,6 +273,106 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_find_all_lab_orders_in_parallel() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        // Mock one GET /lab_orders/ window per offset, five orders in total
+        let windows: Vec<_> = [(0, vec![1, 2]), (2, vec![3, 4]), (4, vec![5])]
+            .into_iter()
+            .map(|(offset, ids)| {
+                let orders: Vec<LabOrder> = ids.into_iter().map(get_mock_lab_order).collect();
+                let next = (offset < 4).then(|| server.url("/lab_orders/?page=next"));
+                let body = serde_json::json!({
+                    "results": orders,
+                    "next": next,
+                    "previous": null,
+                    "count": 5,
+                });
+                server.mock(|when, then| {
+                    when.method(GET)
+                        .path("/lab_orders/")
+                        .query_param("patient", "140754511659009")
+                        .query_param("limit", "2")
+                        .query_param("offset", offset.to_string());
+                    then.status(200)
+                        .header("Content-Type", "application/json")
+                        .body(body.to_string());
+                })
+            })
+            .collect();
+        // Create a client pointing to the mock server
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
+        let service = LabOrderService::new(&client);
+
+        // Prepare query parameters
+        let query_params = LabOrderQueryParams {
+            patient: Some(140754511659009),
+            ..Default::default()
+        };
+        let options = ParallelPages {
+            page_size: 2,
+            concurrency: 2,
+        };
+
+        // Call the method under test
+        let result = service.find_all_parallel(query_params, options).await;
+
+        // Assert the results come back in order
+        let ids: Vec<i64> = result.unwrap().iter().map(|order| order.id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+
+        // Ensure every window was requested once
+        for window in windows {
+            window.assert_async().await;
+        }
+    }
+
+    #[test]
+    fn test_reconcile_matches_only_the_order_with_the_same_tests() {
+        let order = LabOrderForCreate {
+            patient: 140754511659009,
+            practice: 140754506678276,
+            ordering_physician: 140754510217218,
+            chart_date: None,
+            document_date: None,
+            confidential: None,
+            follow_up_method: None,
+            resolution: None,
+            test_date: None,
+            vendor: None,
+            content: Some(LabOrderContentForCreate {
+                tests: vec![140748306251838],
+                fasting_method: None,
+                patient_instructions: Some("".to_string()),
+                test_center_notes: Some("".to_string()),
+                stat_method: None,
+                icd10_codes: None,
+                standing_order_frequency: None,
+                standing_order_end_date: None,
+                collection_datetime: None,
+            }),
+            ccs: None,
+            bill_type: None,
+            answers: None,
+            site: None,
+            tags: None,
+        };
+        assert!(order.matches(&get_mock_lab_order(1)));
+
+        // Another order of the same patient, practice and physician is not ours
+        let mut other = order.clone();
+        other.content.as_mut().unwrap().tests = vec![140748306251839];
+        assert!(!other.matches(&get_mock_lab_order(1)));
+        other.content = None;
+        assert!(!other.matches(&get_mock_lab_order(1)));
+    }
+
     // Helper function to create a mock lab order
     fn get_mock_lab_order(order_id: i64) -> LabOrder {
         LabOrder {
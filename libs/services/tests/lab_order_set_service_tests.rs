@@ -7,19 +7,13 @@ mod tests {
     use services::orders::LabOrderSetService;
     use services::prelude::*;
 
-    use serial_test::serial;
     use time::OffsetDateTime;
 
-    #[serial]
     #[tokio::test]
     async fn test_get_lab_order_set_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /lab_order_sets/{id}/ endpoint
         let set_id = 24507383904;
         let lab_order_set = get_mock_lab_order_set(set_id);
@@ -34,7 +28,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabOrderSetService::new(&client);
 
         // Call the method under test
@@ -7,19 +7,15 @@ mod tests {
     use services::orders::LabVendorService;
     use services::prelude::*;
 
-    use serial_test::serial;
+    use models::resource::Resource;
+    use std::time::Duration;
     use time::OffsetDateTime;
 
-    #[serial]
     #[tokio::test]
     async fn test_get_lab_vendor_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /lab_vendors/{id}/ endpoint
         let vendor_id = 63929778422;
         let lab_vendor = get_mock_lab_vendor(vendor_id);
@@ -33,7 +29,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabVendorService::new(&client);
 
         // Call the method under test
@@ -49,16 +49,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_lab_vendors_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let mock_vendor = get_mock_lab_vendor(63929778422);
         let vendors = vec![mock_vendor.clone()];
         let vendors_json = serde_json::to_string(&vendors).unwrap();
@@ -77,7 +72,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabVendorService::new(&client);
 
         // Prepare query parameters
@@ -100,16 +99,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_lab_vendor_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the vendor data to create
         let vendor_for_create = LabVendorForCreate {
             name: "Lab".to_string(),
@@ -136,7 +130,7 @@ mod tests {
         // Mock the POST /lab_vendors/ endpoint
         let mock = server.mock(|when, then| {
             when.method(POST)
-                .path("/lab_vendors/")
+                .path("/lab_vendors")
                 .header("Content-Type", "application/json")
                 .body(serde_json::to_string(&vendor_for_create).unwrap());
             then.status(201)
@@ -145,7 +139,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabVendorService::new(&client);
 
         // Call the method under test
@@ -161,16 +159,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    //#[serial]
     //#[tokio::test]
     //async fn test_update_lab_vendor_success_put() {
     //    // Start a local mock server
     //    let server = MockServer::start_async().await;
     //
-    //    // Set the mock environment variables
-    //    std::env::set_var("TEST_ENV", "TRUE");
-    //    std::env::set_var("MOCK_SERVER_URL", server.base_url());
-    //
     //    // Prepare the vendor data to update (PUT)
     //    let vendor_for_update = LabVendorForUpdate {
     //        name: Some("Lab".to_string()),
@@ -206,7 +199,11 @@ mod tests {
     //    });
     //
     //    // Create a client pointing to the mock server
-    //    let client = Client::new().await.unwrap();
+    //    let client = Client::builder()
+    //        .base_url(server.base_url())
+    //        .token("12345")
+    //        .build()
+    //        .unwrap();
     //    let service = LabVendorService::new(&client);
     //
     //    // Call the method under test
@@ -222,16 +219,11 @@ mod tests {
     //    mock.assert_async().await;
     //}
 
-    #[serial]
     #[tokio::test]
     async fn test_update_lab_vendor_success_patch() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the vendor data to update (PATCH)
         let vendor_for_update = LabVendorForUpdate {
             display_name: Some("Partially Updated Lab Vendor".to_string()),
@@ -263,7 +255,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabVendorService::new(&client);
 
         // Call the method under test
@@ -279,16 +275,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_lab_vendor_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the DELETE /lab_vendors/{id}/ endpoint
         let vendor_id = 63929778422;
         let mock = server.mock(|when, then| {
@@ -298,7 +289,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = LabVendorService::new(&client);
 
         // Call the method under test
@@ -311,6 +306,45 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_get_lab_vendor_from_reference_data_cache() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let vendor_id = 63929778422;
+        let lab_vendor = get_mock_lab_vendor(vendor_id);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path(format!("/lab_vendors/{}/", vendor_id));
+            then.status(200)
+                .header("Content-Type", "application/json")
+                .body(serde_json::to_string(&lab_vendor).unwrap());
+        });
+
+        // Create a client that caches reference data for an hour
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .cache(ResponseCache::new().reference_data(Duration::from_secs(60 * 60)))
+            .build()
+            .unwrap();
+        let service = LabVendorService::new(&client);
+
+        // Call the method under test twice
+        let first = service.get(vendor_id).await.unwrap();
+        let second = service.get(vendor_id).await.unwrap();
+
+        // Assert the second call was served from the cache
+        assert_eq!(first.name, second.name);
+        assert_eq!(mock.hits_async().await, 1);
+
+        // Invalidating the resource sends the next call to the API again
+        client.cache().unwrap().invalidate(LabVendor::endpoint());
+        service.get(vendor_id).await.unwrap();
+        assert_eq!(mock.hits_async().await, 2);
+    }
+
     // Helper function to create a mock LabVendor
     fn get_mock_lab_vendor(vendor_id: i64) -> LabVendor {
         LabVendor {
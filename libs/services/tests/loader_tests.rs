@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use client::Client;
+    use futures::future::join_all;
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use models::orders::LabVendor;
+    use models::patient_profile::{Allergy, AllergyStatus};
+    use serde_json::json;
+    use services::orders::LabVendorService;
+    use services::patient_profile::AllergyService;
+    use services::prelude::*;
+    use time::Date;
+
+    fn vendor(id: i64) -> LabVendor {
+        LabVendor {
+            id,
+            practice_created: None,
+            name: format!("Vendor {id}"),
+            display_name: format!("Vendor {id}"),
+            has_order_compendium: false,
+            has_test_compendium: false,
+            results_integration_available: false,
+            orders_integration_available: false,
+            compendiums: vec![],
+            default_compendium: None,
+        }
+    }
+
+    fn get_mock_allergy(allergy_id: i64, patient: i64) -> Allergy {
+        Allergy {
+            id: allergy_id,
+            status: AllergyStatus::Active,
+            start_date: Date::from_calendar_date(1980, time::Month::January, 1).unwrap(),
+            reaction: None,
+            name: "Erythromycin".to_string(),
+            severity: None,
+            medispanid: None,
+            medispandnid: None,
+            patient,
+            created_date: None,
+            deleted_date: None,
+        }
+    }
+
+    fn page(allergies: Vec<Allergy>) -> serde_json::Value {
+        json!({
+            "count": allergies.len(),
+            "next": null,
+            "previous": null,
+            "results": allergies
+        })
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_load_coalesces_identical_gets() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let first = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/1/");
+            then.status(200)
+                .delay(Duration::from_millis(100))
+                .json_body_obj(&vendor(1));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/2/");
+            then.status(200).json_body_obj(&vendor(2));
+        });
+
+        let client = client_for(&server);
+        let loader = Loader::new(LabVendorService::new(&client));
+
+        let vendors = loader.load_many([1, 2, 1, 1, 2]).await;
+        assert_eq!(
+            vendors
+                .into_iter()
+                .map(|vendor| vendor.unwrap().id)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 1, 1, 2]
+        );
+        assert_eq!(loader.load(1).await.unwrap().id, 1);
+
+        assert_eq!(first.hits_async().await, 1);
+        assert_eq!(second.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_loads_are_not_remembered() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let missing = server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/3/");
+            then.status(404).json_body(json!({"detail": "Not found."}));
+        });
+
+        let client = client_for(&server);
+        let loader = Loader::new(LabVendorService::new(&client));
+
+        let error = loader.load(3).await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::ClientError(client::Error::NotFound(_))
+        ));
+
+        missing.delete_async().await;
+        server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/3/");
+            then.status(200).json_body_obj(&vendor(3));
+        });
+        assert_eq!(loader.load(3).await.unwrap().id, 3);
+
+        // Primed resources need no request at all
+        loader.prime(4, vendor(4));
+        assert_eq!(loader.load(4).await.unwrap().id, 4);
+    }
+
+    #[tokio::test]
+    async fn test_patient_loads_are_batched_into_one_find() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let find = server.mock(|when, then| {
+            when.method(GET)
+                .path("/allergies/")
+                .query_param("patients", "1")
+                .query_param("patients", "2")
+                .query_param("patients", "3");
+            then.status(200).json_body(page(vec![
+                get_mock_allergy(10, 1),
+                get_mock_allergy(11, 3),
+                get_mock_allergy(12, 1),
+            ]));
+        });
+        let get = server.mock(|when, then| {
+            when.method(GET).path("/allergies/10/");
+            then.status(200).json_body_obj(&get_mock_allergy(10, 1));
+        });
+
+        let client = client_for(&server);
+        let loader = Loader::new(AllergyService::new(&client));
+
+        let allergies =
+            join_all([1, 2, 3, 1].map(|patient| loader.load_for_patient(patient))).await;
+        let ids = allergies
+            .into_iter()
+            .map(|allergies| {
+                allergies
+                    .unwrap()
+                    .iter()
+                    .map(|allergy| allergy.id)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![vec![10, 12], vec![], vec![11], vec![10, 12]]);
+
+        // Both the patients and the records found are remembered
+        assert!(loader.load_for_patient(2).await.unwrap().is_empty());
+        assert_eq!(loader.load(10).await.unwrap().patient, 1);
+
+        assert_eq!(find.hits_async().await, 1);
+        assert_eq!(get.hits_async().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_batches_fall_back_to_one_find_per_patient() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        // Defined first, so it also answers the batch holding patient 1
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/allergies/")
+                .query_param("patients", "1");
+            then.status(400)
+                .json_body(json!({"patients": ["Invalid patient."]}));
+        });
+        let single = server.mock(|when, then| {
+            when.method(GET)
+                .path("/allergies/")
+                .query_param("patients", "2");
+            then.status(200)
+                .json_body(page(vec![get_mock_allergy(20, 2)]));
+        });
+
+        let client = client_for(&server);
+        let loader = Loader::new(AllergyService::new(&client));
+
+        let (first, second) =
+            futures::join!(loader.load_for_patient(1), loader.load_for_patient(2));
+
+        assert!(matches!(
+            first,
+            Err(Error::ClientError(client::Error::BadRequest(_)))
+        ));
+        assert_eq!(second.unwrap()[0].id, 20);
+        assert_eq!(single.hits_async().await, 1);
+    }
+}
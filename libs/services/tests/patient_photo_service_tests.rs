@@ -4,7 +4,6 @@ mod tests {
     use httpmock::Method::{DELETE, GET, POST};
     use httpmock::MockServer;
     use models::patient_profile::{PatientPhoto, PatientPhotoForCreate};
-    use serial_test::serial;
     use services::patient_profile::PatientPhotoService;
     use services::prelude::*;
     use time::OffsetDateTime;
@@ -25,16 +24,11 @@ mod tests {
         }
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_patient_photo_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let photo_id = 123456;
         let patient_photo = get_mock_patient_photo(photo_id);
 
@@ -47,7 +41,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_photo_service = PatientPhotoService::new(&client);
 
         // Call the method under test
@@ -65,14 +63,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_patient_photo_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let photo_id = 789012;
         let photo_for_create = PatientPhotoForCreate {
             patient: 140754479349761,
@@ -94,7 +88,11 @@ mod tests {
                 .body(serde_json::to_string(&get_mock_patient_photo(photo_id)).unwrap());
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PatientPhotoService::new(&client);
 
         let result = service.post(&photo_for_create).await;
@@ -108,14 +106,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    //#[serial]
     //#[tokio::test]
     //async fn test_update_patch_patient_photo_success() {
     //    let server = MockServer::start_async().await;
     //
-    //    std::env::set_var("TEST_ENV", "TRUE");
-    //    std::env::set_var("MOCK_SERVER_URL", server.base_url());
-    //
     //    let photo_id = 123456;
     //    let mock_photo = PatientPhoto {
     //        original_filename: "updated_profile_image".to_owned(),
@@ -136,7 +130,11 @@ mod tests {
     //            .body(serde_json::to_string(&mock_photo).unwrap());
     //    });
     //
-    //    let client = Client::new().await.unwrap();
+    //    let client = Client::builder()
+    //        .base_url(server.base_url())
+    //        .token("12345")
+    //        .build()
+    //        .unwrap();
     //    let patient_photo_service = PatientPhotoService::new(&client);
     //
     //    let photo_fu = PatientPhotoForUpdate {
@@ -155,14 +153,10 @@ mod tests {
     //    mock.assert_async().await;
     //}
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_patient_photo_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let photo_id = 123456;
         let mock = server.mock(|when, then| {
             when.method(DELETE)
@@ -170,7 +164,11 @@ mod tests {
             then.status(204); // No Content
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_photo_service = PatientPhotoService::new(&client);
 
         let result = patient_photo_service.delete(photo_id).await;
@@ -8,7 +8,6 @@ mod tests {
         PatientProviderTeamMember, PatientProviderTeamMemberForCreate,
         PatientProviderTeamMemberForUpdate,
     };
-    use serial_test::serial;
     use services::patient_profile::PatientProviderTeamService;
     use services::prelude::*;
     use time::OffsetDateTime;
@@ -32,16 +31,11 @@ mod tests {
         }
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_patient_provider_team_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let team_id = 123456;
         let patient_provider_team = get_mock_patient_provider_team(team_id);
 
@@ -54,7 +48,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_provider_team_service = PatientProviderTeamService::new(&client);
 
         // Call the method under test
@@ -72,14 +70,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_patient_provider_team_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let team_id = 789012;
         let team_for_create = PatientProviderTeamForCreate {
             patient_id: 237422977,
@@ -101,7 +95,11 @@ mod tests {
                 .body(serde_json::to_string(&get_mock_patient_provider_team(team_id)).unwrap());
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PatientProviderTeamService::new(&client);
 
         let result = service.post(&team_for_create).await;
@@ -115,14 +113,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_patch_patient_provider_team_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let team_id = 123456;
         let mock_team = PatientProviderTeam {
             patient_provider_team_id: team_id,
@@ -160,7 +154,11 @@ mod tests {
                 .body(serde_json::to_string(&mock_team).unwrap());
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_provider_team_service = PatientProviderTeamService::new(&client);
 
         let team_fu = PatientProviderTeamForUpdate {
@@ -182,14 +180,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_patient_provider_team_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let team_id = 123456;
         let mock = server.mock(|when, then| {
             when.method(DELETE)
@@ -197,7 +191,11 @@ mod tests {
             then.status(204); // No Content
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_provider_team_service = PatientProviderTeamService::new(&client);
 
         let result = patient_provider_team_service.delete(team_id).await;
@@ -1,25 +1,19 @@
 #[cfg(test)]
 mod tests {
-    use client::Client;
+    use client::{Client, MemoryResponse, MemoryTransport};
     use httpmock::Method::{GET, POST};
     use httpmock::MockServer;
     use models::patient_profile::*;
+    use reqwest::{Method, StatusCode};
     use services::patient_profile::PatientService;
     use services::prelude::*;
     use time::{Date, OffsetDateTime};
 
-    use serial_test::serial;
-
-    #[serial]
     #[tokio::test]
     async fn test_get_patient_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /patients/{id}/ endpoint
         let patient_id = 123456;
         let patient = get_mock_patient(patient_id);
@@ -32,7 +26,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_service = PatientService::new(&client);
 
         // Call the method under test
@@ -51,15 +49,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_patient_success() {
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Create the patient data for the mock
         let patient_id = 789012;
 
@@ -88,7 +81,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PatientService::new(&client);
 
         // Call the method under test
@@ -105,16 +102,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_patch_patient_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         tokio::time::sleep(std::time::Duration::from_millis(500)).await;
 
         // Mock the PUT /patients/{id}/ endpoint
@@ -140,7 +132,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_service = PatientService::new(&client);
 
         let patient_fu = PatientForUpdate {
@@ -163,16 +159,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_put_patient_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         tokio::time::sleep(std::time::Duration::from_millis(500)).await;
 
         // Mock the PUT /patients/{id}/ endpoint
@@ -203,7 +194,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_service = PatientService::new(&client);
 
         // Call the method under test
@@ -221,16 +216,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_patient_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the DELETE /patients/{id}/ endpoint
         let patient_id = 123456;
         let mock = server.mock(|when, then| {
@@ -240,7 +230,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_service = PatientService::new(&client);
 
         // Call the method under test
@@ -255,16 +249,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_patients_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let mock_patient = get_mock_patient(123456);
         let mut mock_patient_two = get_mock_patient(78910);
 
@@ -287,7 +276,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_service = PatientService::new(&client);
 
         // Prepare query parameters
@@ -312,16 +305,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_patient_not_found() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /patients/{id}/ endpoint to return 404
         let patient_id = 999999;
         let mock = server.mock(|when, then| {
@@ -336,7 +324,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let patient_service = PatientService::new(&client);
 
         // Call the method under test
@@ -360,6 +352,75 @@ mod tests {
         // Ensure the mock was called
         mock.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn test_patient_service_over_memory_transport() {
+        // Program an in-memory transport instead of starting a mock server
+        let patient_id = 123456;
+        let transport = MemoryTransport::new();
+        transport.respond(
+            Method::GET,
+            format!("/patients/{}/", patient_id),
+            MemoryResponse::json(StatusCode::OK, &get_mock_patient(patient_id)),
+        );
+
+        let client = Client::builder()
+            .base_url("http://elation.test/")
+            .token("12345")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let patient_service = PatientService::new(&client);
+
+        // Call the method under test
+        let patient = patient_service.get(patient_id).await.unwrap();
+        let error = patient_service.get(999999).await.unwrap_err();
+
+        // Assert the result
+        assert_eq!(patient.id, patient_id);
+        assert_eq!(patient.last_name, "Doe");
+        assert!(matches!(
+            error,
+            Error::ClientError(client::Error::NotFound(_))
+        ));
+
+        // Assert what was sent
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].url.path(), "/patients/123456/");
+        assert_eq!(requests[0].headers["Authorization"], "Bearer 12345");
+    }
+
+    #[test]
+    fn test_redaction_masks_every_phi_field_of_a_patient() {
+        let mut patient = get_mock_patient(123456);
+        patient.patient_status.deceased_date = Some("2024-02-03".to_string());
+
+        let masked = client::Redaction::default().mask(&patient);
+
+        for phi in [
+            "John",
+            "Doe",
+            "1990",
+            "123-45-6789",
+            "123 Main St",
+            "456 Elm St",
+            "Example City",
+            "90210",
+            "555-",
+            "example.com",
+            "INS123456",
+            "No known allergies.",
+            "2024-02-03",
+            "Example Employer",
+        ] {
+            assert!(!masked.contains(phi), "{phi} in {masked}");
+        }
+        // Fields that are not PHI are kept
+        assert!(masked.contains("EMP123"));
+        assert!(masked.contains("Pharmacy A"));
+    }
+
     fn get_mock_patient(patient_id: i64) -> Patient {
         Patient {
             id: patient_id,
@@ -4,21 +4,15 @@ mod tests {
     use httpmock::Method::{DELETE, GET, PATCH, POST};
     use httpmock::MockServer;
     use models::patient_profile::*;
-    use serial_test::serial;
     use services::patient_profile::ProblemService;
     use services::prelude::*;
     use time::Date;
 
-    #[serial]
     #[tokio::test]
     async fn test_get_problem_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /problems/{id}/ endpoint
         let problem_id = 123456;
         let problem = get_mock_problem(problem_id);
@@ -31,7 +25,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let problem_service = ProblemService::new(&client);
 
         // Call the method under test
@@ -49,15 +47,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_problem_success() {
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Create the problem data for the mock
         let problem_id = 789012;
 
@@ -84,7 +77,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = ProblemService::new(&client);
 
         // Call the method under test
@@ -101,15 +98,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_patch_problem_success() {
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         tokio::time::sleep(std::time::Duration::from_millis(500)).await;
 
         let problem_id = 123456;
@@ -130,7 +122,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let problem_service = ProblemService::new(&client);
 
         let problem_fu = ProblemForUpdate {
@@ -152,15 +148,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_problem_success() {
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let problem_id = 123456;
         let mock = server.mock(|when, then| {
             when.method(DELETE)
@@ -169,7 +160,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let problem_service = ProblemService::new(&client);
 
         let result = problem_service.delete(problem_id).await;
@@ -181,14 +176,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_problem_not_found() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let problem_id = 999999;
         let mock = server.mock(|when, then| {
             when.method(GET).path(format!("/problems/{}/", problem_id));
@@ -197,7 +188,11 @@ mod tests {
                 .body(r#"{"detail": "Not found."}"#);
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let problem_service = ProblemService::new(&client);
 
         let result = problem_service.get(problem_id).await;
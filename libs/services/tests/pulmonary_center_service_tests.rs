@@ -7,19 +7,13 @@ mod tests {
     use services::orders::PulmonaryCenterService;
     use services::prelude::*;
 
-    use serial_test::serial;
     use time::OffsetDateTime;
 
-    #[serial]
     #[tokio::test]
     async fn test_get_pulmonary_center_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /pulmonary_centers/{id}/ endpoint
         let center_id = 140755855671306;
         let pulmonary_center = get_mock_pulmonary_center(center_id);
@@ -33,7 +27,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryCenterService::new(&client);
 
         // Call the method under test
@@ -49,16 +47,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_pulmonary_centers_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let mock_center = get_mock_pulmonary_center(140755855671306);
 
         let centers = vec![mock_center.clone()];
@@ -78,7 +71,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryCenterService::new(&client);
 
         // Prepare query parameters
@@ -101,16 +98,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_pulmonary_center_not_found() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /pulmonary_centers/{id}/ endpoint to return 404
         let center_id = 999999;
         let mock = server.mock(|when, then| {
@@ -126,7 +118,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryCenterService::new(&client);
 
         // Call the method under test
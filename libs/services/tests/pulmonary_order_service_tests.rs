@@ -7,19 +7,13 @@ mod tests {
     use services::orders::PulmonaryOrderService;
     use services::prelude::*;
 
-    use serial_test::serial;
     use time::{Date, Month, OffsetDateTime};
 
-    #[serial]
     #[tokio::test]
     async fn test_get_pulmonary_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /pulmonary_orders/{id}/ endpoint
         let order_id = 140756377075740;
         let pulmonary_order = get_mock_pulmonary_order(order_id);
@@ -33,7 +27,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderService::new(&client);
 
         // Call the method under test
@@ -49,16 +47,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_pulmonary_orders_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let mock_order = get_mock_pulmonary_order(140756377075740);
 
         let orders = vec![mock_order.clone()];
@@ -78,7 +71,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderService::new(&client);
 
         // Prepare query parameters
@@ -101,16 +98,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_pulmonary_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the order data to create
         let order_for_create = PulmonaryOrderForCreate {
             allergies: Some("Penicillin".to_string()),
@@ -150,7 +142,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderService::new(&client);
 
         // Call the method under test
@@ -166,16 +162,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_pulmonary_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the order data to update
         let order_for_update = PulmonaryOrderForUpdate {
             clinical_reason: Some("Updated reason".to_string()),
@@ -196,7 +187,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderService::new(&client);
 
         // Call the method under test
@@ -211,16 +206,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_pulmonary_order_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the DELETE /pulmonary_orders/{id}/ endpoint
         let order_id = 140756377075740;
         let mock = server.mock(|when, then| {
@@ -230,7 +220,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderService::new(&client);
 
         // Call the method under test
@@ -243,16 +237,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_pulmonary_order_not_found() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /pulmonary_orders/{id}/ endpoint to return 404
         let order_id = 999999;
         let mock = server.mock(|when, then| {
@@ -268,7 +257,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderService::new(&client);
 
         // Call the method under test
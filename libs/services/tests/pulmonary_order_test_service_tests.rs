@@ -7,19 +7,13 @@ mod tests {
     use services::orders::PulmonaryOrderTestService;
     use services::prelude::*;
 
-    use serial_test::serial;
     use time::OffsetDateTime;
 
-    #[serial]
     #[tokio::test]
     async fn test_get_pulmonary_order_test_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /pulmonary_order_tests/{id}/ endpoint
         let test_id = 140756665106487;
         let pulmonary_order_test = get_mock_pulmonary_order_test(test_id);
@@ -33,7 +27,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderTestService::new(&client);
 
         // Call the method under test
@@ -49,16 +47,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_find_pulmonary_order_tests_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let mock_test = get_mock_pulmonary_order_test(140756665106487);
 
         let tests = vec![mock_test.clone()];
@@ -78,7 +71,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderTestService::new(&client);
 
         // Prepare query parameters
@@ -101,16 +98,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_pulmonary_order_test_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Prepare the test data to create
         let test_for_create = PulmonaryOrderTestForCreate {
             code: Some("POT123".to_string()),
@@ -139,7 +131,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderTestService::new(&client);
 
         // Call the method under test
@@ -157,16 +153,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_pulmonary_order_test_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the DELETE /pulmonary_order_tests/{id}/ endpoint
         let test_id = 140756665106487;
         let mock = server.mock(|when, then| {
@@ -176,7 +167,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderTestService::new(&client);
 
         // Call the method under test
@@ -189,16 +184,11 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_pulmonary_order_test_not_found() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock environment variables
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         // Mock the GET /pulmonary_order_tests/{id}/ endpoint to return 404
         let test_id = 999999;
         let mock = server.mock(|when, then| {
@@ -214,7 +204,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = PulmonaryOrderTestService::new(&client);
 
         // Call the method under test
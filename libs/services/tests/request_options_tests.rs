@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use client::{Client, MemoryResponse, MemoryTransport};
+    use futures::TryStreamExt;
+    use models::orders::*;
+    use reqwest::header::{HeaderName, HeaderValue};
+    use reqwest::{Method, StatusCode};
+    use services::orders::LabVendorService;
+    use services::prelude::*;
+
+    fn get_mock_lab_vendor(id: i64) -> LabVendor {
+        LabVendor {
+            id,
+            practice_created: None,
+            name: format!("Vendor {id}"),
+            display_name: format!("Vendor {id}"),
+            has_order_compendium: false,
+            has_test_compendium: false,
+            results_integration_available: false,
+            orders_integration_available: false,
+            compendiums: vec![],
+            default_compendium: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_options_apply_to_every_page_of_a_stream() {
+        let transport = MemoryTransport::new();
+        transport.respond(
+            Method::GET,
+            "/lab_vendors/",
+            MemoryResponse::json(
+                StatusCode::OK,
+                &serde_json::json!({
+                    "count": 2,
+                    "next": "http://elation.test/lab_vendors/?offset=1",
+                    "previous": null,
+                    "results": [get_mock_lab_vendor(1)],
+                }),
+            ),
+        );
+        transport.respond(
+            Method::GET,
+            "/lab_vendors/?offset=1",
+            MemoryResponse::json(
+                StatusCode::OK,
+                &serde_json::json!({
+                    "count": 2,
+                    "next": null,
+                    "previous": null,
+                    "results": [get_mock_lab_vendor(2)],
+                }),
+            ),
+        );
+
+        let client = Client::builder()
+            .base_url("http://elation.test/")
+            .token("12345")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let service = LabVendorService::new(&client);
+
+        let options = RequestOptions::new().header(
+            HeaderName::from_static("x-correlation-id"),
+            HeaderValue::from_static("sync-7"),
+        );
+        let vendors: Vec<LabVendor> = service
+            .find_stream_with(LabVendorQueryParams::default(), &options)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(vendors.len(), 2);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests
+            .iter()
+            .all(|request| request.headers["x-correlation-id"] == "sync-7"));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_service_call_returns_client_error() {
+        let transport = MemoryTransport::new();
+        let client = Client::builder()
+            .base_url("http://elation.test/")
+            .token("12345")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let service = LabVendorService::new(&client);
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let error = service
+            .get_with(1, &RequestOptions::new().cancellation(token))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            services::Error::ClientError(client::Error::Cancelled)
+        ));
+        assert!(transport.requests().is_empty());
+    }
+
+    struct FixedLabVendors;
+
+    #[async_trait]
+    impl GetService<'static, LabVendor> for FixedLabVendors {
+        type Id = i64;
+
+        async fn get(&self, id: i64) -> Result<LabVendor> {
+            Ok(get_mock_lab_vendor(id))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_with_defaults_to_get() {
+        let vendor = FixedLabVendors
+            .get_with(3, &RequestOptions::new())
+            .await
+            .unwrap();
+
+        assert_eq!(vendor.id, 3);
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use client::Client;
+    use futures::TryStreamExt;
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use models::orders::{LabVendor, LabVendorQueryParams};
+    use serde_json::json;
+    use services::orders::LabVendorService;
+    use services::patient_profile::PatientService;
+    use services::prelude::*;
+
+    fn assert_task_handle<S: Clone + Send + Sync + 'static>(_: &S) {}
+
+    fn vendor(id: i64) -> serde_json::Value {
+        serde_json::to_value(LabVendor {
+            id,
+            practice_created: None,
+            name: format!("Vendor {id}"),
+            display_name: format!("Vendor {id}"),
+            has_order_compendium: false,
+            has_test_compendium: false,
+            results_integration_available: false,
+            orders_integration_available: false,
+            compendiums: vec![],
+            default_compendium: None,
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_shared_services_can_be_moved_into_tasks() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path_contains("/lab_vendors/");
+            then.status(200).json_body(vendor(1));
+        });
+
+        let client = Arc::new(
+            Client::builder()
+                .base_url(server.base_url())
+                .token("12345")
+                .build()
+                .unwrap(),
+        );
+        let service = LabVendorService::shared(client.clone());
+        assert_task_handle(&service);
+        assert_task_handle(&PatientService::shared(client));
+
+        let tasks = (1..=3)
+            .map(|id| {
+                let service = service.clone();
+                tokio::spawn(async move { service.get(id).await })
+            })
+            .collect::<Vec<_>>();
+        for task in tasks {
+            assert_eq!(task.await.unwrap().unwrap().id, 1);
+        }
+
+        assert_eq!(mock.hits_async().await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_shared_service_streams_outlive_the_service() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/lab_vendors/");
+            then.status(200).json_body(json!({
+                "count": 2,
+                "next": null,
+                "previous": null,
+                "results": [vendor(1), vendor(2)]
+            }));
+        });
+
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
+
+        // The stream keeps the shared client alive after the service is dropped
+        let stream =
+            LabVendorService::shared(Arc::new(client)).find_stream(LabVendorQueryParams::default());
+        let vendors = tokio::spawn(async move { stream.try_collect::<Vec<_>>().await })
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            vendors.iter().map(|vendor| vendor.id).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+}
@@ -0,0 +1,374 @@
+#[cfg(test)]
+mod tests {
+    use client::Client;
+    use httpmock::Method::GET;
+    use httpmock::MockServer;
+    use models::patient_profile::*;
+    use serde_json::json;
+    use services::patient_profile::{PatientService, ProblemService};
+    use services::prelude::*;
+    use time::{Date, Duration, OffsetDateTime};
+
+    fn checkpoint() -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap()
+    }
+
+    fn page(patients: Vec<Patient>) -> serde_json::Value {
+        json!({
+            "count": patients.len(),
+            "next": null,
+            "previous": null,
+            "results": patients
+        })
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap()
+    }
+
+    fn ids(patients: &[Patient]) -> Vec<i64> {
+        patients.iter().map(|patient| patient.id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_first_sync_fetches_everything() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let find = server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(200).json_body(page(vec![
+                get_mock_patient(1, checkpoint(), None),
+                get_mock_patient(2, checkpoint(), Some(checkpoint())),
+            ]));
+        });
+
+        let client = client_for(&server);
+        let store = MemoryCheckpointStore::new();
+        let engine = SyncEngine::new(store.clone());
+
+        let mut received = Vec::new();
+        let report = engine
+            .sync(
+                &PatientService::new(&client),
+                |changes: ChangeSet<Patient>| {
+                    received.push(changes);
+                    Ok::<_, Error>(())
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(received.len(), 1);
+        assert_eq!(ids(&received[0].created), vec![1]);
+        assert!(received[0].updated.is_empty());
+        assert_eq!(ids(&received[0].deleted), vec![2]);
+
+        assert_eq!(report.key, "patients");
+        assert_eq!(report.since, None);
+        assert_eq!((report.created, report.updated, report.deleted), (1, 0, 1));
+        assert_eq!(store.load("patients").unwrap(), Some(report.checkpoint));
+
+        assert_eq!(find.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_sync_fetches_records_modified_since_checkpoint() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let since = checkpoint() - Duration::seconds(60);
+        let find = server.mock(|when, then| {
+            when.method(GET)
+                .path("/patients/")
+                .query_param("last_modified__gte", "2023-11-14T22:12:20Z");
+            then.status(200).json_body(page(vec![
+                get_mock_patient(1, since - Duration::days(30), None),
+                get_mock_patient(2, checkpoint() + Duration::minutes(5), None),
+                get_mock_patient(3, since - Duration::days(1), Some(checkpoint())),
+            ]));
+        });
+
+        let client = client_for(&server);
+        let store = MemoryCheckpointStore::new();
+        store.save("patients", checkpoint()).unwrap();
+        let engine = SyncEngine::new(store.clone()).batch_size(2);
+
+        let mut received = Vec::new();
+        let report = engine
+            .sync(
+                &PatientService::new(&client),
+                |changes: ChangeSet<Patient>| {
+                    received.push(changes);
+                    Ok::<_, Error>(())
+                },
+            )
+            .await
+            .unwrap();
+
+        // Changes are handed over in batches of at most two records
+        assert_eq!(
+            received.iter().map(ChangeSet::len).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        assert_eq!(ids(&received[0].updated), vec![1]);
+        assert_eq!(ids(&received[0].created), vec![2]);
+        assert_eq!(ids(&received[1].deleted), vec![3]);
+
+        assert_eq!(report.since, Some(checkpoint()));
+        assert!(report.checkpoint > checkpoint());
+        assert_eq!(find.hits_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_callback_keeps_checkpoint() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/patients/");
+            then.status(200)
+                .json_body(page(vec![get_mock_patient(1, checkpoint(), None)]));
+        });
+
+        let client = client_for(&server);
+        let store = MemoryCheckpointStore::new();
+        store.save("patients", checkpoint()).unwrap();
+        let engine = SyncEngine::new(store.clone());
+
+        let error = engine
+            .sync(&PatientService::new(&client), |_: ChangeSet<Patient>| {
+                Err("database is locked")
+            })
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(&error, Error::Callback(error) if error.to_string() == "database is locked")
+        );
+        assert_eq!(store.load("patients").unwrap(), Some(checkpoint()));
+    }
+
+    #[tokio::test]
+    async fn test_records_created_within_the_overlap_are_created() {
+        // Start a local mock server
+        let server = MockServer::start_async().await;
+
+        let find = server.mock(|when, then| {
+            when.method(GET)
+                .path("/problems/")
+                .query_param("last_modified__gte", "2023-11-14T22:12:20Z");
+            then.status(200).json_body(json!({
+                "count": 2,
+                "next": null,
+                "previous": null,
+                "results": [
+                    get_mock_problem(1, checkpoint() - Duration::days(1)),
+                    get_mock_problem(2, checkpoint() - Duration::seconds(30)),
+                ]
+            }));
+        });
+
+        let client = client_for(&server);
+        let store = MemoryCheckpointStore::new();
+        store.save("problems", checkpoint()).unwrap();
+        let engine = SyncEngine::new(store.clone());
+
+        let mut received = Vec::new();
+        let report = engine
+            .sync(
+                &ProblemService::new(&client),
+                |changes: ChangeSet<Problem>| {
+                    received.push(changes);
+                    Ok::<_, Error>(())
+                },
+            )
+            .await
+            .unwrap();
+
+        // A record fetched because of the overlap is not mistaken for an updated one
+        let problems = |problems: &[Problem]| {
+            problems
+                .iter()
+                .map(|problem| problem.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(problems(&received[0].updated), vec![1]);
+        assert_eq!(problems(&received[0].created), vec![2]);
+
+        assert_eq!(report.key, "problems");
+        assert_eq!(store.load("problems").unwrap(), Some(report.checkpoint));
+        assert_eq!(find.hits_async().await, 1);
+    }
+
+    #[test]
+    fn test_file_checkpoint_store_persists_checkpoints() {
+        let path = std::env::temp_dir().join(format!(
+            "elation-sync-checkpoints-{}.json",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let store = FileCheckpointStore::new(&path);
+        assert_eq!(store.load("patients").unwrap(), None);
+        store.save("patients", checkpoint()).unwrap();
+        store
+            .save("allergies", checkpoint() + Duration::hours(1))
+            .unwrap();
+
+        let reopened = FileCheckpointStore::new(&path);
+        assert_eq!(reopened.load("patients").unwrap(), Some(checkpoint()));
+        assert_eq!(
+            reopened.load("allergies").unwrap(),
+            Some(checkpoint() + Duration::hours(1))
+        );
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(matches!(
+            reopened.load("patients"),
+            Err(Error::Checkpoint(_))
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_checkpoint_store_persists_checkpoints() {
+        let store = SqliteCheckpointStore::open(":memory:").unwrap();
+        assert_eq!(store.load("patients").unwrap(), None);
+
+        store.save("patients", checkpoint()).unwrap();
+        store
+            .save("patients", checkpoint() + Duration::hours(1))
+            .unwrap();
+        assert_eq!(
+            store.load("patients").unwrap(),
+            Some(checkpoint() + Duration::hours(1))
+        );
+    }
+
+    fn get_mock_patient(
+        patient_id: i64,
+        created_date: OffsetDateTime,
+        deleted_date: Option<OffsetDateTime>,
+    ) -> Patient {
+        Patient {
+            id: patient_id,
+            first_name: "John".to_string(),
+            middle_name: Some("Middle".to_string()),
+            last_name: "Doe".to_string(),
+            actual_name: Some("Johnathan Doe".to_string()),
+            gender_identity: Some(GenderIdentity::Man),
+            legal_gender_marker: Some(LegalGenderMarker::M),
+            pronouns: Some(Pronouns::HeHimHis),
+            sex: Sex::Male,
+            sexual_orientation: Some(SexualOrientation::Straight),
+            primary_physician: 123,  // Example physician ID
+            caregiver_practice: 456, // Example caregiver practice ID
+            dob: Date::from_calendar_date(1990, time::Month::January, 1).unwrap(), // Example DOB
+            ssn: Some("123-45-6789".to_string()), // Example SSN
+            race: Some(Race::White),
+            ethnicity: Some(Ethnicity::NotHispanicOrLatino),
+            preferred_language: Some("English".to_string()),
+            notes: Some("No known allergies.".to_string()),
+            vip: false,
+            tags: vec!["example_tag".to_string()],
+            sms_opt_in_status: Some(true),
+            address: Some(Address {
+                address_line1: "123 Main St".to_string(),
+                address_line2: None,
+                city: Some("Example City".to_string()),
+                state: Some("CA".to_string()),
+                zip: Some("90210".to_string()),
+                phones: vec![Phone {
+                    phone: "555-1234".to_string(),
+                    phone_type: "mobile".to_string(),
+                }],
+            }),
+            phones: Some(vec![Phone {
+                phone: "555-5678".to_string(),
+                phone_type: "home".to_string(),
+            }]),
+            emails: Some(vec![Email {
+                email: "john.doe@example.com".to_string(),
+            }]),
+            guarantor: Some(Guarantor {
+                id: Some(789),
+                address: Some("456 Elm St".to_string()),
+                city: Some("Another City".to_string()),
+                state: Some("NY".to_string()),
+                zip: Some("10001".to_string()),
+                phone: Some("555-8765".to_string()),
+                email: Some("guarantor@example.com".to_string()),
+                relationship: Some(GuarantorRelationship::Spouse),
+                first_name: Some("Jane".to_string()),
+                last_name: Some("Doe".to_string()),
+                middle_name: None,
+            }),
+            insurances: Some(vec![Insurance {
+                member_id: "INS123456".to_string(),
+                rank: "Primary".to_string(),
+            }]),
+            deleted_insurances: None,
+            preference: Some(Preference {
+                preferred_pharmacy_1: Some("Pharmacy A".to_string()),
+                preferred_pharmacy_2: Some("Pharmacy B".to_string()),
+            }),
+            emergency_contact: Some(EmergencyContact {
+                first_name: Some("Jane".to_string()),
+                last_name: Some("Doe".to_string()),
+                relationship: Some(EmergencyContactRelationship::Spouse),
+                phone: Some("555-8765".to_string()),
+                address_line1: Some("123 Main St".to_string()),
+                address_line2: None,
+                city: Some("Example City".to_string()),
+                state: Some("CA".to_string()),
+                zip: Some("90210".to_string()),
+            }),
+            previous_name: None,
+            master_patient: None,
+            employer: Some(Employer {
+                code: Some("EMP123".to_string()),
+                name: Some("Example Employer".to_string()),
+                description: Some("Description of employer".to_string()),
+            }),
+            consents: Some(vec![Consent {
+                consented: true,
+                last_modified_date: Some(OffsetDateTime::now_utc()),
+                application: Some("App".to_string()),
+            }]),
+            metadata: None,
+            merged_into_chart: None,
+            primary_care_provider: Some(987),
+            primary_care_provider_npi: Some("1234567890".to_string()),
+            patient_status: PatientStatus {
+                deceased_date: None,
+                inactive_reason: None,
+                last_status_change: Some("2024-01-01".to_string()),
+                notes: Some("Active patient.".to_string()),
+                status: PatientStatusEnum::Active,
+            },
+            created_date: Some(created_date),
+            deleted_date,
+        }
+    }
+
+    fn get_mock_problem(problem_id: i64, created_date: OffsetDateTime) -> Problem {
+        Problem {
+            id: problem_id,
+            description: "Hypertension".to_string(),
+            status: ProblemStatus::Active,
+            synopsis: None,
+            start_date: Date::from_calendar_date(2023, time::Month::January, 1).unwrap(),
+            resolved_date: None,
+            dx: vec![],
+            patient: 1,
+            created_date: Some(created_date),
+            deleted_date: None,
+        }
+    }
+}
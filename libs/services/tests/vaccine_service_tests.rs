@@ -4,7 +4,6 @@ mod tests {
     use httpmock::Method::{DELETE, GET, PATCH, POST};
     use httpmock::MockServer;
     use models::patient_profile::{Vaccine, VaccineForCreate, VaccineForUpdate};
-    use serial_test::serial;
     use services::patient_profile::VaccineService;
     use services::prelude::*;
     use time::OffsetDateTime;
@@ -25,16 +24,11 @@ mod tests {
         }
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_get_vaccine_success() {
         // Start a local mock server
         let server = MockServer::start_async().await;
 
-        // Set the mock env
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let vaccine_id = 123456;
         let vaccine = get_mock_vaccine(vaccine_id);
 
@@ -46,7 +40,11 @@ mod tests {
         });
 
         // Create a client pointing to the mock server
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let vaccine_service = VaccineService::new(&client);
 
         // Call the method under test
@@ -67,14 +65,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_create_vaccine_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let vaccine_id = 789012;
         let vaccine_for_create = VaccineForCreate {
             description: "Td(adult) unspecified formulation (Td)".to_string(),
@@ -97,7 +91,11 @@ mod tests {
                 .body(serde_json::to_string(&get_mock_vaccine(vaccine_id)).unwrap());
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let service = VaccineService::new(&client);
 
         let result = service.post(&vaccine_for_create).await;
@@ -111,14 +109,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_update_patch_vaccine_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let vaccine_id = 123456;
         let mock_vaccine = Vaccine {
             name: Some("Updated Td(adult)".to_owned()),
@@ -139,7 +133,11 @@ mod tests {
                 .body(serde_json::to_string(&mock_vaccine).unwrap());
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let vaccine_service = VaccineService::new(&client);
 
         let vaccine_fu = VaccineForUpdate {
@@ -158,14 +156,10 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[serial]
     #[tokio::test]
     async fn test_delete_vaccine_success() {
         let server = MockServer::start_async().await;
 
-        std::env::set_var("TEST_ENV", "TRUE");
-        std::env::set_var("MOCK_SERVER_URL", server.base_url());
-
         let vaccine_id = 123456;
         let mock = server.mock(|when, then| {
             when.method(DELETE)
@@ -173,7 +167,11 @@ mod tests {
             then.status(204); // No Content
         });
 
-        let client = Client::new().await.unwrap();
+        let client = Client::builder()
+            .base_url(server.base_url())
+            .token("12345")
+            .build()
+            .unwrap();
         let vaccine_service = VaccineService::new(&client);
 
         let result = vaccine_service.delete(vaccine_id).await;
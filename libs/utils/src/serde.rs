@@ -1,5 +1,5 @@
 use std::fmt::{self, Display};
-use time::{format_description, Date, Time};
+use time::{macros::format_description, Date, Time};
 
 use serde::{
     de::{self, Visitor},
@@ -30,7 +30,7 @@ pub fn serialize_time<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    let format = format_description::parse("[hour]:[minute]").unwrap();
+    let format = format_description!("[hour]:[minute]");
     let time_str = time.format(&format).unwrap();
     serializer.serialize_str(&time_str)
 }
@@ -48,7 +48,7 @@ impl<'de> Visitor<'de> for TimeVisitor {
     where
         E: de::Error,
     {
-        let format = format_description::parse("[hour]:[minute]").unwrap();
+        let format = format_description!("[hour]:[minute]");
         Time::parse(v, &format).map_err(de::Error::custom)
     }
 }
@@ -73,7 +73,7 @@ where
     match opt_s {
         Some(s) if s.is_empty() => Ok(None),
         Some(s) => {
-            let format = format_description::parse("[year]-[month]-[day]").unwrap();
+            let format = format_description!("[year]-[month]-[day]");
             Date::parse(&s, &format)
                 .map(Some)
                 .map_err(serde::de::Error::custom)
@@ -87,7 +87,7 @@ where
     D: Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?;
-    let format = format_description::parse("[year]-[month]-[day]").unwrap();
+    let format = format_description!("[year]-[month]-[day]");
     Date::parse(&s, &format).map_err(serde::de::Error::custom)
 }
 
@@ -96,7 +96,7 @@ where
     S: Serializer,
 {
     // Define the date format
-    let format = format_description::parse("[year]-[month]-[day]").unwrap();
+    let format = format_description!("[year]-[month]-[day]");
     // Format the date object into a string
     let date_str = date.format(&format).unwrap();
     // Use the serializer to serialize the string